@@ -69,33 +69,41 @@ impl ArrayIterator {
     /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
     pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
         if let Value::Object(ref object) = this {
-            let mut object = object.borrow_mut();
-            if let Some(array_iterator) = object.as_array_iterator_mut() {
-                let index = array_iterator.next_index;
-                if array_iterator.array.is_undefined() {
+            // The iterator is not borrowed while the array is read, as getters can run.
+            let iterator = object.borrow().as_array_iterator().map(|iterator| {
+                (
+                    iterator.array.clone(),
+                    iterator.next_index,
+                    iterator.kind.clone(),
+                )
+            });
+            if let Some((array, index, kind)) = iterator {
+                if array.is_undefined() {
                     return Ok(create_iter_result_object(context, Value::undefined(), true));
                 }
-                let len = array_iterator
-                    .array
-                    .get_field("length")
-                    .as_number()
-                    .ok_or_else(|| context.construct_type_error("Not an array"))?
-                    as u32;
-                if array_iterator.next_index >= len {
+                let len = array
+                    .get_field_with_accessors("length", context)?
+                    .to_length(context)?;
+                let mut object = object.borrow_mut();
+                let array_iterator = object
+                    .as_array_iterator_mut()
+                    .expect("object is an array iterator");
+                if index as usize >= len {
                     array_iterator.array = Value::undefined();
                     return Ok(create_iter_result_object(context, Value::undefined(), true));
                 }
                 array_iterator.next_index = index + 1;
-                match array_iterator.kind {
+                drop(object);
+                match kind {
                     ArrayIterationKind::Key => {
                         Ok(create_iter_result_object(context, index.into(), false))
                     }
                     ArrayIterationKind::Value => {
-                        let element_value = array_iterator.array.get_field(index);
+                        let element_value = array.get_field_with_accessors(index, context)?;
                         Ok(create_iter_result_object(context, element_value, false))
                     }
                     ArrayIterationKind::KeyAndValue => {
-                        let element_value = array_iterator.array.get_field(index);
+                        let element_value = array.get_field_with_accessors(index, context)?;
                         let result = Array::constructor(
                             &Value::new_object(Some(context.global_object())),
                             &[index.into(), element_value],
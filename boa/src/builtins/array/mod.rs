@@ -15,14 +15,14 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
-    builtins::BuiltIn,
+    builtins::{iterable::get_iterator, BuiltIn},
     gc::GcObject,
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
     value::{same_value_zero, Value},
     BoaProfiler, Context, Result,
 };
-use std::cmp::{max, min};
+use std::cmp::{max, min, Ordering};
 
 /// JavaScript `Array` built-in implementation.
 #[derive(Debug, Clone, Copy)]
@@ -72,18 +72,23 @@ impl BuiltIn for Array {
         .method(Self::includes_value, "includes", 1)
         .method(Self::map, "map", 1)
         .method(Self::fill, "fill", 1)
+        .method(Self::copy_within, "copyWithin", 2)
         .method(Self::for_each, "forEach", 1)
         .method(Self::filter, "filter", 1)
+        .method(Self::flat, "flat", 0)
+        .method(Self::flat_map, "flatMap", 1)
         .method(Self::pop, "pop", 0)
         .method(Self::join, "join", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::reverse, "reverse", 0)
+        .method(Self::sort, "sort", 1)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
         .method(Self::every, "every", 1)
         .method(Self::find, "find", 1)
         .method(Self::find_index, "findIndex", 1)
         .method(Self::slice, "slice", 2)
+        .method(Self::splice, "splice", 2)
         .method(Self::some, "some", 2)
         .method(Self::reduce, "reduce", 2)
         .method(Self::reduce_right, "reduceRight", 2)
@@ -91,6 +96,7 @@ impl BuiltIn for Array {
         .method(Self::entries, "entries", 0)
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
+        .static_method(Self::from, "from", 1)
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -282,6 +288,66 @@ impl Array {
         }
     }
 
+    /// `Array.from( items [, mapFn [, thisArg ] ] )`
+    ///
+    /// The from function creates a new array from an iterable object, or from an array-like
+    /// object with a `length` property. If `mapFn` is given, it is called with each value and its
+    /// index, and the array contains the results instead.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let map_fn = match args.get(1) {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(map_fn)) if map_fn.is_callable() => Some(map_fn.clone()),
+            Some(_) => return context.throw_type_error("Array.from: mapFn is not a function"),
+        };
+        let this_arg = args.get(2).cloned().unwrap_or_else(Value::undefined);
+
+        if items.is_null_or_undefined() {
+            return context
+                .throw_type_error("Array.from: cannot convert undefined or null to object");
+        }
+        let items = items.to_object(context)?;
+
+        let map_value = |value: Value, index: usize, context: &mut Context| match map_fn {
+            Some(ref map_fn) => map_fn.call(&this_arg, &[value, index.into()], context),
+            None => Ok(value),
+        };
+
+        let mut values = Vec::new();
+        let iterator_symbol = context.well_known_symbols().iterator_symbol();
+        if items.get_method(context, iterator_symbol)?.is_some() {
+            let iterator = get_iterator(context, items.into())?;
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+                match map_value(next.value(), values.len(), context) {
+                    Ok(value) => values.push(value),
+                    Err(error) => return iterator.close(Err(error), context),
+                }
+            }
+        } else {
+            let array_like = Value::from(items);
+            let length = array_like
+                .get_field_with_accessors("length", context)?
+                .to_length(context)?;
+            for index in 0..length {
+                let value = array_like.get_field_with_accessors(index, context)?;
+                values.push(map_value(value, index, context)?);
+            }
+        }
+
+        Self::construct_array(&Self::new_array(context)?, &values)
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
@@ -294,7 +360,7 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.concat
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat
-    pub(crate) fn concat(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn concat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         if args.is_empty() {
             // If concat is called with no arguments, it returns the original array
             return Ok(this.clone());
@@ -304,15 +370,19 @@ impl Array {
         // one)
         let mut new_values: Vec<Value> = Vec::new();
 
-        let this_length = this.get_field("length").as_number().unwrap() as i32;
+        let this_length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
         for n in 0..this_length {
-            new_values.push(this.get_field(n));
+            new_values.push(this.get_field_with_accessors(n, context)?);
         }
 
         for concat_array in args {
-            let concat_length = concat_array.get_field("length").as_number().unwrap() as i32;
+            let concat_length = concat_array
+                .get_field_with_accessors("length", context)?
+                .to_length(context)? as i32;
             for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n));
+                new_values.push(concat_array.get_field_with_accessors(n, context)?);
             }
         }
 
@@ -331,9 +401,17 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
-    pub(crate) fn push(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args)?;
-        Ok(new_array.get_field("length"))
+    pub(crate) fn push(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        for (n, value) in args.iter().enumerate() {
+            this.set_field_with_accessors(length + n, value.clone(), context)?;
+        }
+
+        let new_length = Value::from(length + args.len());
+        this.set_field_with_accessors("length", new_length.clone(), context)?;
+        Ok(new_length)
     }
 
     /// `Array.prototype.pop()`
@@ -346,16 +424,18 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
-    pub(crate) fn pop(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn pop(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let curr_length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         if curr_length < 1 {
             return Ok(Value::undefined());
         }
         let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string());
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index));
+        let pop_value: Value = this.get_field_with_accessors(pop_index.to_string(), context)?;
+        context.delete_property(this, &pop_index.into())?;
+        this.set_field_with_accessors("length", Value::from(pop_index), context)?;
         Ok(pop_value)
     }
 
@@ -377,10 +457,12 @@ impl Array {
         let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field_with_accessors(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             context.call(callback_arg, &this_arg, &arguments)?;
@@ -412,9 +494,14 @@ impl Array {
         };
 
         let mut elem_strs = Vec::new();
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
         for n in 0..length {
-            let elem_str = this.get_field(n).to_string(context)?.to_string();
+            let elem_str = this
+                .get_field_with_accessors(n, context)?
+                .to_string(context)?
+                .to_string();
             elem_strs.push(elem_str);
         }
 
@@ -438,7 +525,7 @@ impl Array {
         let method_name = "join";
         let mut arguments = vec![Value::from(",")];
         // 2.
-        let mut method = this.get_field(method_name);
+        let mut method = this.get_field_with_accessors(method_name, context)?;
         // 3.
         if !method.is_function() {
             method = context
@@ -473,35 +560,159 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reverse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
-    pub(crate) fn reverse(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         let middle: i32 = len.wrapping_div(2);
 
         for lower in 0..middle {
             let upper = len.wrapping_sub(lower).wrapping_sub(1);
 
-            let upper_exists = this.has_field(upper);
-            let lower_exists = this.has_field(lower);
+            let upper_exists = context.has_property(this, &upper.into())?;
+            let lower_exists = context.has_property(this, &lower.into())?;
 
-            let upper_value = this.get_field(upper);
-            let lower_value = this.get_field(lower);
+            let upper_value = this.get_field_with_accessors(upper, context)?;
+            let lower_value = this.get_field_with_accessors(lower, context)?;
 
             if upper_exists && lower_exists {
-                this.set_field(upper, lower_value);
-                this.set_field(lower, upper_value);
+                this.set_field_with_accessors(upper, lower_value, context)?;
+                this.set_field_with_accessors(lower, upper_value, context)?;
             } else if upper_exists {
-                this.set_field(lower, upper_value);
-                this.remove_property(upper);
+                this.set_field_with_accessors(lower, upper_value, context)?;
+                context.delete_property(this, &upper.into())?;
             } else if lower_exists {
-                this.set_field(upper, lower_value);
-                this.remove_property(lower);
+                this.set_field_with_accessors(upper, lower_value, context)?;
+                context.delete_property(this, &lower.into())?;
             }
         }
 
         Ok(this.clone())
     }
 
+    /// `Array.prototype.sort( [ compareFn ] )`
+    ///
+    /// The elements of the array are sorted in place, and the array is returned. The sort is
+    /// stable: elements that compare equal keep their relative order.
+    ///
+    /// Without a comparison function, elements are sorted by their string values. `undefined`
+    /// elements always come after the other elements, followed by the holes of the array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let compare_fn = match args.get(0) {
+            None | Some(Value::Undefined) => None,
+            Some(Value::Object(compare_fn)) if compare_fn.is_callable() => Some(compare_fn.clone()),
+            Some(_) => {
+                return context.throw_type_error(
+                    "The comparison function must be either a function or undefined",
+                )
+            }
+        };
+
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        // The length of an array-like can be far larger than its elements, so the items are
+        // not allocated up front.
+        let mut items = Vec::new();
+        for index in 0..len {
+            if context.has_property(this, &index.into())? {
+                items.push(this.get_field_with_accessors(index, context)?);
+            }
+        }
+
+        let items = Self::merge_sort(items, &mut |x, y| {
+            Self::sort_compare(x, y, compare_fn.as_ref(), context)
+        })?;
+
+        let item_count = items.len();
+        for (index, item) in items.into_iter().enumerate() {
+            this.set_field_with_accessors(index, item, context)?;
+        }
+        for index in item_count..len {
+            context.delete_property(this, &index.into())?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Sorts the items with a stable merge sort, stopping at the first comparison that fails.
+    ///
+    /// Unlike the sorts of the standard library, this does not panic if the comparison is not a
+    /// total order, e.g. a comparison function returning random results. The order of the items
+    /// is then implementation-defined, but they are all kept.
+    fn merge_sort<F>(mut items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>>
+    where
+        F: FnMut(&Value, &Value) -> Result<Ordering>,
+    {
+        let mut buffer = Vec::with_capacity(items.len());
+        let mut width = 1;
+        while width < items.len() {
+            for start in (0..items.len()).step_by(2 * width) {
+                let middle = (start + width).min(items.len());
+                let end = (start + 2 * width).min(items.len());
+
+                let (mut left, mut right) = (start, middle);
+                while left < middle && right < end {
+                    // Taking the left item unless the right one is smaller keeps the sort stable.
+                    if compare(&items[left], &items[right])? == Ordering::Greater {
+                        buffer.push(items[right].clone());
+                        right += 1;
+                    } else {
+                        buffer.push(items[left].clone());
+                        left += 1;
+                    }
+                }
+                buffer.extend_from_slice(&items[left..middle]);
+                buffer.extend_from_slice(&items[right..end]);
+            }
+            std::mem::swap(&mut items, &mut buffer);
+            buffer.clear();
+            width *= 2;
+        }
+
+        Ok(items)
+    }
+
+    /// The abstract operation `SortCompare`, ordering two elements of an array being sorted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortcompare
+    fn sort_compare(
+        x: &Value,
+        y: &Value,
+        compare_fn: Option<&GcObject>,
+        context: &mut Context,
+    ) -> Result<Ordering> {
+        match (x.is_undefined(), y.is_undefined()) {
+            (true, true) => return Ok(Ordering::Equal),
+            (true, false) => return Ok(Ordering::Greater),
+            (false, true) => return Ok(Ordering::Less),
+            (false, false) => {}
+        }
+
+        if let Some(compare_fn) = compare_fn {
+            let order = compare_fn
+                .call(&Value::undefined(), &[x.clone(), y.clone()], context)?
+                .to_number(context)?;
+            return Ok(order.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+
+        let x = x.to_string(context)?;
+        let y = y.to_string(context)?;
+        Ok(x.encode_utf16().cmp(y.encode_utf16()))
+    }
+
     /// `Array.prototype.shift()`
     ///
     /// The first element of the array is removed from the array and returned.
@@ -512,31 +723,33 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
-    pub(crate) fn shift(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn shift(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         if len == 0 {
-            this.set_field("length", 0);
+            this.set_field_with_accessors("length", 0, context)?;
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0);
+        let first: Value = this.get_field_with_accessors(0, context)?;
 
         for k in 1..len {
             let from = k;
             let to = k.wrapping_sub(1);
 
-            let from_value = this.get_field(from);
+            let from_value = this.get_field_with_accessors(from, context)?;
             if from_value.is_undefined() {
-                this.remove_property(to);
+                context.delete_property(this, &to.into())?;
             } else {
-                this.set_field(to, from_value);
+                this.set_field_with_accessors(to, from_value, context)?;
             }
         }
 
         let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index));
+        context.delete_property(this, &final_index.into())?;
+        this.set_field_with_accessors("length", Value::from(final_index), context)?;
 
         Ok(first)
     }
@@ -553,8 +766,10 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
-    pub(crate) fn unshift(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
-        let len = this.get_field("length").as_number().unwrap() as i32;
+    pub(crate) fn unshift(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         let arg_c: i32 = args.len() as i32;
 
@@ -563,25 +778,26 @@ impl Array {
                 let from = k.wrapping_sub(1);
                 let to = k.wrapping_add(arg_c).wrapping_sub(1);
 
-                let from_value = this.get_field(from);
+                let from_value = this.get_field_with_accessors(from, context)?;
                 if from_value.is_undefined() {
-                    this.remove_property(to);
+                    context.delete_property(this, &to.into())?;
                 } else {
-                    this.set_field(to, from_value);
+                    this.set_field_with_accessors(to, from_value, context)?;
                 }
             }
             for j in 0..arg_c {
-                this.set_field(
+                this.set_field_with_accessors(
                     j,
                     args.get(j as usize)
                         .expect("Could not get argument")
                         .clone(),
-                );
+                    context,
+                )?;
             }
         }
 
         let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp));
+        this.set_field_with_accessors("length", Value::from(temp), context)?;
         Ok(Value::from(temp))
     }
 
@@ -611,10 +827,12 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field_with_accessors(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = context.call(callback, &this_arg, &arguments)?;
             if !result.to_boolean() {
@@ -622,7 +840,8 @@ impl Array {
             }
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field_with_accessors("length", context)?
+                    .to_length(context)? as i32,
             );
             i += 1;
         }
@@ -650,7 +869,9 @@ impl Array {
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
         let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").to_length(context)?;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
 
         if length > 2usize.pow(32) - 1 {
             return context.throw_range_error("Invalid array length");
@@ -658,16 +879,17 @@ impl Array {
 
         let new = Self::new_array(context)?;
 
-        let values: Vec<Value> = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx);
-                let args = [element, Value::from(idx), new.clone()];
+        let mut values = Vec::new();
+        for idx in 0..length {
+            let element = this.get_field_with_accessors(idx, context)?;
+            let args = [element, Value::from(idx), new.clone()];
 
+            values.push(
                 context
                     .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined())
-            })
-            .collect();
+                    .unwrap_or_else(|_| Value::undefined()),
+            );
+        }
 
         Self::construct_array(&new, &values)
     }
@@ -691,14 +913,16 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.indexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/indexOf
-    pub(crate) fn index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn index_of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
         }
 
         let search_element = args[0].clone();
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         let mut idx = match args.get(1) {
             Some(from_idx_ptr) => {
@@ -714,7 +938,7 @@ impl Array {
         };
 
         while idx < len {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field_with_accessors(idx, context)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -744,7 +968,11 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.lastindexof
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/lastIndexOf
-    pub(crate) fn last_index_of(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn last_index_of(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         // If no arguments, return -1. Not described in spec, but is what chrome does.
         if args.is_empty() {
             return Ok(Value::from(-1));
@@ -770,7 +998,7 @@ impl Array {
         };
 
         while idx >= 0 {
-            let check_element = this.get_field(idx).clone();
+            let check_element = this.get_field_with_accessors(idx, context)?.clone();
 
             if check_element.strict_equals(&search_element) {
                 return Ok(Value::from(idx));
@@ -802,9 +1030,11 @@ impl Array {
         }
         let callback = &args[0];
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
         for i in 0..len {
-            let element = this.get_field(i);
+            let element = this.get_field_with_accessors(i, context)?;
             let arguments = [element.clone(), Value::from(i), this.clone()];
             let result = context.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -837,10 +1067,12 @@ impl Array {
 
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         for i in 0..length {
-            let element = this.get_field(i);
+            let element = this.get_field_with_accessors(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
 
             let result = context.call(predicate_arg, &this_arg, &arguments)?;
@@ -865,33 +1097,92 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let len: i32 = this.get_field("length").as_number().unwrap() as i32;
-
-        let default_value = Value::undefined();
-        let value = args.get(0).unwrap_or(&default_value);
-        let relative_start = args.get(1).unwrap_or(&default_value).to_number(context)? as i32;
-        let relative_end_val = args.get(2).unwrap_or(&default_value);
-        let relative_end = if relative_end_val.is_undefined() {
-            len
-        } else {
-            relative_end_val.to_number(context)? as i32
+        let this: Value = this.to_object(context)?.into();
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        let value = args.get(0).cloned().unwrap_or_default();
+        let start = Self::relative_index(args.get(1), 0, len, context)?;
+        let end = Self::relative_index(args.get(2), len, len, context)?;
+
+        for k in start..end {
+            this.set_field_with_accessors(k, value.clone(), context)?;
+        }
+
+        Ok(this)
+    }
+
+    /// `Array.prototype.copyWithin( target, start[, end] )`
+    ///
+    /// The method copies the elements from index start (default 0) to end (default array length)
+    /// to the index target of the same array, without changing its length. Negative indices count
+    /// back from the end of the array. It returns the modified array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+    pub(crate) fn copy_within(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let this: Value = this.to_object(context)?.into();
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        let to = Self::relative_index(args.get(0), 0, len, context)?;
+        let from = Self::relative_index(args.get(1), 0, len, context)?;
+        let end = Self::relative_index(args.get(2), len, len, context)?;
+        let count = end.saturating_sub(from).min(len - to);
+
+        // Holes in the copied range leave holes at their destination.
+        let copy_element = |from: usize, to: usize, context: &mut Context| -> Result<()> {
+            if context.has_property(&this, &from.into())? {
+                let value = this.get_field_with_accessors(from, context)?;
+                this.set_field_with_accessors(to, value, context)?;
+            } else {
+                context.delete_property(&this, &to.into())?;
+            }
+            Ok(())
         };
-        let start = if relative_start < 0 {
-            max(len + relative_start, 0)
+        // When the target overlaps the end of the source range, copying back to front keeps
+        // the source elements from being overwritten before they are read.
+        if from < to && to < from + count {
+            for k in (0..count).rev() {
+                copy_element(from + k, to + k, context)?;
+            }
         } else {
-            min(relative_start, len)
+            for k in 0..count {
+                copy_element(from + k, to + k, context)?;
+            }
+        }
+
+        Ok(this)
+    }
+
+    /// Converts a relative index argument of an array method into an index in `0..=len`.
+    ///
+    /// Negative values count back from `len`, and an `undefined` argument is `default`.
+    fn relative_index(
+        arg: Option<&Value>,
+        default: usize,
+        len: usize,
+        context: &mut Context,
+    ) -> Result<usize> {
+        let relative = match arg {
+            Some(value) if !value.is_undefined() => value.to_integer(context)?,
+            _ => return Ok(default),
         };
-        let fin = if relative_end < 0 {
-            max(len + relative_end, 0)
+        if relative < 0.0 {
+            Ok((len as f64 + relative).max(0.0) as usize)
         } else {
-            min(relative_end, len)
-        };
-
-        for i in start..fin {
-            this.set_field(i, value.clone());
+            Ok(relative.min(len as f64) as usize)
         }
-
-        Ok(this.clone())
     }
 
     /// `Array.prototype.includes( valueToFind [, fromIndex] )`
@@ -904,13 +1195,34 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/includes
-    pub(crate) fn includes_value(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn includes_value(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        let from_index = match args.get(1) {
+            Some(from_index) => from_index.to_integer(context)?,
+            None => 0.0,
+        };
 
-        for idx in 0..length {
-            let check_element = this.get_field(idx).clone();
+        // A negative index is an offset from the end of the array.
+        let start = if from_index >= 0.0 {
+            from_index
+        } else {
+            (length as f64 + from_index).max(0.0)
+        };
+        if start >= length as f64 {
+            return Ok(Value::from(false));
+        }
+
+        // Holes are read as `undefined`, so they match a search for `undefined`.
+        for idx in start as usize..length {
+            let check_element = this.get_field_with_accessors(idx, context)?;
 
             if same_value_zero(&check_element, &search_element) {
                 return Ok(Value::from(true));
@@ -936,7 +1248,9 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/slice
     pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let new_array = Self::new_array(context)?;
-        let len = this.get_field("length").as_number().unwrap() as i32;
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         let start = match args.get(0) {
             Some(v) => v.as_number().unwrap() as i32,
@@ -961,13 +1275,97 @@ impl Array {
         let span = max(to.wrapping_sub(from), 0);
         let mut new_array_len: i32 = 0;
         for i in from..from.wrapping_add(span) {
-            new_array.set_field(new_array_len, this.get_field(i));
+            new_array.set_field(new_array_len, this.get_field_with_accessors(i, context)?);
             new_array_len = new_array_len.wrapping_add(1);
         }
         new_array.set_field("length", Value::from(new_array_len));
         Ok(new_array)
     }
 
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// The splice method removes `deleteCount` elements of the array from index `start` and
+    /// inserts the given items in their place, moving the elements after them as needed. If start
+    /// is negative, it is treated as length + start. It returns the removed elements in a new array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this: Value = this.to_object(context)?.into();
+        let len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        let relative_start = match args.get(0) {
+            Some(start) => start.to_integer(context)?,
+            None => 0.0,
+        };
+        let start = if relative_start < 0.0 {
+            (len as f64 + relative_start).max(0.0) as usize
+        } else {
+            relative_start.min(len as f64) as usize
+        };
+
+        // Without a delete count, everything from `start` is removed.
+        let delete_count = match args.get(1) {
+            Some(delete_count) => delete_count
+                .to_integer(context)?
+                .max(0.0)
+                .min((len - start) as f64) as usize,
+            None if args.is_empty() => 0,
+            None => len - start,
+        };
+        let items = args.get(2..).unwrap_or_default();
+
+        // Holes among the removed elements stay holes in the returned array.
+        let removed = Self::new_array(context)?;
+        for k in 0..delete_count {
+            let from = start + k;
+            if context.has_property(&this, &from.into())? {
+                removed.set_field(k, this.get_field_with_accessors(from, context)?);
+            }
+        }
+        removed.set_field("length", Value::from(delete_count));
+
+        // Move the elements after the removed ones, keeping their holes.
+        let move_element = |from: usize, to: usize, context: &mut Context| -> Result<()> {
+            if context.has_property(&this, &from.into())? {
+                let value = this.get_field_with_accessors(from, context)?;
+                this.set_field_with_accessors(to, value, context)?;
+            } else {
+                context.delete_property(&this, &to.into())?;
+            }
+            Ok(())
+        };
+        if items.len() < delete_count {
+            for k in start..len - delete_count {
+                move_element(k + delete_count, k + items.len(), context)?;
+            }
+            for k in (len - delete_count + items.len()..len).rev() {
+                context.delete_property(&this, &k.into())?;
+            }
+        } else if items.len() > delete_count {
+            for k in (start..len - delete_count).rev() {
+                move_element(k + delete_count, k + items.len(), context)?;
+            }
+        }
+
+        for (k, item) in items.iter().enumerate() {
+            this.set_field_with_accessors(start + k, item.clone(), context)?;
+        }
+        this.set_field_with_accessors(
+            "length",
+            Value::from(len - delete_count + items.len()),
+            context,
+        )?;
+
+        Ok(removed)
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -989,31 +1387,155 @@ impl Array {
         let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
         let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
-        let length = this.get_field("length").as_number().unwrap() as i32;
+        let length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
 
         let new = Self::new_array(context)?;
 
-        let values = (0..length)
-            .filter_map(|idx| {
-                let element = this.get_field(idx);
+        let mut values = Vec::new();
+        for idx in 0..length {
+            let element = this.get_field_with_accessors(idx, context)?;
 
-                let args = [element.clone(), Value::from(idx), new.clone()];
+            let args = [element.clone(), Value::from(idx), new.clone()];
 
-                let callback_result = context
-                    .call(&callback, &this_val, &args)
-                    .unwrap_or_else(|_| Value::undefined());
+            let callback_result = context
+                .call(&callback, &this_val, &args)
+                .unwrap_or_else(|_| Value::undefined());
 
-                if callback_result.to_boolean() {
-                    Some(element)
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<Value>>();
+            if callback_result.to_boolean() {
+                values.push(element);
+            }
+        }
 
         Self::construct_array(&new, &values)
     }
 
+    /// `Array.prototype.flat( [depth] )`
+    ///
+    /// The flat method creates a new array with all sub-array elements concatenated into it,
+    /// recursively up to the specified depth, which defaults to 1.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flat
+    pub(crate) fn flat(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let source = Value::from(this.to_object(context)?);
+        let source_len = source
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        // A negative or NaN depth does not flatten at all.
+        let depth = match args.get(0) {
+            Some(depth) if !depth.is_undefined() => depth.to_integer(context)?.max(0.0),
+            _ => 1.0,
+        };
+
+        let new = Self::new_array(context)?;
+        Self::flatten_into_array(&new, &source, source_len, 0, depth, None, context)?;
+
+        Ok(new)
+    }
+
+    /// `Array.prototype.flatMap( callback, [ thisArg ] )`
+    ///
+    /// For each element in the array the callback function is called, and the results
+    /// are flattened by one level into a new array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flatMap
+    pub(crate) fn flat_map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let source = Value::from(this.to_object(context)?);
+        let source_len = source
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+
+        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        if !callback.is_function() {
+            return context.throw_type_error("flatMap callback is not a function");
+        }
+        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        let new = Self::new_array(context)?;
+        Self::flatten_into_array(
+            &new,
+            &source,
+            source_len,
+            0,
+            1.0,
+            Some((&callback, &this_val)),
+            context,
+        )?;
+
+        Ok(new)
+    }
+
+    /// Copies the elements of `source` into `target` starting at index `start`, flattening
+    /// nested arrays up to `depth` levels and skipping holes.
+    ///
+    /// If a `mapper` function and its `this` value are given, every element of `source` is first
+    /// replaced by the result of calling the mapper on it.
+    ///
+    /// Returns the index after the last element written to `target`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-flattenintoarray
+    fn flatten_into_array(
+        target: &Value,
+        source: &Value,
+        source_len: usize,
+        start: usize,
+        depth: f64,
+        mapper: Option<(&Value, &Value)>,
+        context: &mut Context,
+    ) -> Result<usize> {
+        let mut target_index = start;
+
+        for source_index in 0..source_len {
+            if !context.has_property(source, &source_index.into())? {
+                continue;
+            }
+
+            let mut element = source.get_field_with_accessors(source_index, context)?;
+            if let Some((mapper, this_val)) = mapper {
+                let args = [element, Value::from(source_index), source.clone()];
+                element = context.call(mapper, this_val, &args)?;
+            }
+
+            let is_array = element
+                .as_object()
+                .map(|object| object.borrow().is_array())
+                .unwrap_or(false);
+
+            if depth > 0.0 && is_array {
+                let element_len = element.get_field("length").to_length(context)?;
+                target_index = Self::flatten_into_array(
+                    target,
+                    &element,
+                    element_len,
+                    target_index,
+                    depth - 1.0,
+                    None,
+                    context,
+                )?;
+            } else {
+                target.set_field(target_index, element);
+                target_index += 1;
+            }
+        }
+
+        Ok(target_index)
+    }
+
     /// Array.prototype.some ( callbackfn [ , thisArg ] )
     ///
     /// The some method tests whether at least one element in the array passes
@@ -1042,10 +1564,12 @@ impl Array {
             Value::undefined()
         };
         let mut i = 0;
-        let max_len = this.get_field("length").as_number().unwrap() as i32;
+        let max_len = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)? as i32;
         let mut len = max_len;
         while i < len {
-            let element = this.get_field(i);
+            let element = this.get_field_with_accessors(i, context)?;
             let arguments = [element, Value::from(i), this.clone()];
             let result = context.call(callback, &this_arg, &arguments)?;
             if result.to_boolean() {
@@ -1054,7 +1578,8 @@ impl Array {
             // the length of the array must be updated because the callback can mutate it.
             len = min(
                 max_len,
-                this.get_field("length").as_number().unwrap() as i32,
+                this.get_field_with_accessors("length", context)?
+                    .to_length(context)? as i32,
             );
             i += 1;
         }
@@ -1078,17 +1603,22 @@ impl Array {
             Some(value) if value.is_function() => value,
             _ => return context.throw_type_error("Reduce was called without a callback"),
         };
-        let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(context)?;
-        if length == 0 && initial_value.is_undefined() {
+        // An explicit `undefined` is an initial value too.
+        let initial_value = args.get(1).cloned();
+        let mut length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        if length == 0 && initial_value.is_none() {
             return context
                 .throw_type_error("Reduce was called on an empty array and with no initial value");
         }
         let mut k = 0;
-        let mut accumulator = if initial_value.is_undefined() {
+        let mut accumulator = if let Some(initial_value) = initial_value {
+            initial_value
+        } else {
             let mut k_present = false;
             while k < length {
-                if this.has_field(k) {
+                if context.has_property(&this, &k.into())? {
                     k_present = true;
                     break;
                 }
@@ -1099,20 +1629,27 @@ impl Array {
                     "Reduce was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field_with_accessors(k, context)?;
             k += 1;
             result
-        } else {
-            initial_value
         };
         while k < length {
-            if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+            if context.has_property(&this, &k.into())? {
+                let arguments = [
+                    accumulator,
+                    this.get_field_with_accessors(k, context)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = context.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(context)?);
+                length = min(
+                    length,
+                    this.get_field_with_accessors("length", context)?
+                        .to_length(context)?,
+                );
             }
             k += 1;
         }
@@ -1140,23 +1677,27 @@ impl Array {
             Some(value) if value.is_function() => value,
             _ => return context.throw_type_error("reduceRight was called without a callback"),
         };
-        let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length").to_length(context)?;
+        // An explicit `undefined` is an initial value too.
+        let initial_value = args.get(1).cloned();
+        let mut length = this
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
         if length == 0 {
-            return if initial_value.is_undefined() {
-                context.throw_type_error(
-                    "reduceRight was called on an empty array and with no initial value",
-                )
-            } else {
+            return match initial_value {
                 // early return to prevent usize subtraction errors
-                Ok(initial_value)
+                Some(initial_value) => Ok(initial_value),
+                None => context.throw_type_error(
+                    "reduceRight was called on an empty array and with no initial value",
+                ),
             };
         }
         let mut k = length - 1;
-        let mut accumulator = if initial_value.is_undefined() {
+        let mut accumulator = if let Some(initial_value) = initial_value {
+            initial_value
+        } else {
             let mut k_present = false;
             loop {
-                if this.has_field(k) {
+                if context.has_property(&this, &k.into())? {
                     k_present = true;
                     break;
                 }
@@ -1171,21 +1712,28 @@ impl Array {
                     "reduceRight was called on an empty array and with no initial value",
                 );
             }
-            let result = this.get_field(k);
+            let result = this.get_field_with_accessors(k, context)?;
             k = k.overflowing_sub(1).0;
             result
-        } else {
-            initial_value
         };
         // usize::MAX is bigger than the maximum array size so we can use it check for integer undeflow
         while k != usize::MAX {
-            if this.has_field(k) {
-                let arguments = [accumulator, this.get_field(k), Value::from(k), this.clone()];
+            if context.has_property(&this, &k.into())? {
+                let arguments = [
+                    accumulator,
+                    this.get_field_with_accessors(k, context)?,
+                    Value::from(k),
+                    this.clone(),
+                ];
                 accumulator = context.call(&callback, &Value::undefined(), &arguments)?;
                 /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
                 It may also be necessary to do this since shortening the array length does not
                 delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(length, this.get_field("length").to_length(context)?);
+                length = min(
+                    length,
+                    this.get_field_with_accessors("length", context)?
+                        .to_length(context)?,
+                );
 
                 // move k to the last defined element if necessary or return if the length was set to 0
                 if k >= length {
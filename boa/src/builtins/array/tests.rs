@@ -544,6 +544,94 @@ fn fill() {
     assert_eq!(forward(&mut context, "a[0].hi"), String::from("\"hi\""));
 }
 
+#[test]
+fn fill_holes() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = [1, , , 4, ];");
+    assert_eq!(forward(&mut context, "1 in a"), "false");
+    assert_eq!(forward(&mut context, "a.fill(0, 1, -1) === a"), "true");
+    assert_eq!(forward(&mut context, "1 in a && 2 in a"), "true");
+    assert_eq!(forward(&mut context, "a.join()"), "\"1,0,0,4\"");
+    assert_eq!(forward(&mut context, "a.length"), "4");
+}
+
+#[test]
+fn copy_within() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(0, 3).join()"),
+        "\"4,5,3,4,5\""
+    );
+    // make sure the array is modified
+    assert_eq!(forward(&mut context, "a.join()"), "\"4,5,3,4,5\"");
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(1, 3, 4).join()"),
+        "\"1,4,3,4,5\""
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(3, 0).join()"),
+        "\"1,2,3,1,2\""
+    );
+    assert_eq!(forward(&mut context, "a.length"), "5");
+}
+
+#[test]
+fn copy_within_overlapping() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(1, 0).join()"),
+        "\"1,1,2,3,4\""
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(0, 1).join()"),
+        "\"2,3,4,5,5\""
+    );
+
+    forward(&mut context, "a = [1, , 3, 4];");
+    assert_eq!(forward(&mut context, "a.copyWithin(2, 0, 2) === a"), "true");
+    assert_eq!(
+        forward(&mut context, "a"),
+        "[ 1, <1 empty item>, 1, <1 empty item> ]"
+    );
+    assert_eq!(forward(&mut context, "3 in a"), "false");
+    assert_eq!(forward(&mut context, "a[2]"), "1");
+}
+
+#[test]
+fn copy_within_negative_indices() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(-2, -3, -1).join()"),
+        "\"1,2,3,3,4\""
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(-10, -2).join()"),
+        "\"4,5,3,4,5\""
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(0, 10).join()"),
+        "\"1,2,3,4,5\""
+    );
+    assert_eq!(forward(&mut context, "a.length"), "5");
+}
+
 #[test]
 fn includes_value() {
     let mut context = Context::new();
@@ -582,6 +670,45 @@ fn includes_value() {
     assert_eq!(second_in_many, String::from("false"));
 }
 
+#[test]
+fn includes_value_same_value_zero() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[NaN].includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "[NaN].indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[-0].includes(+0)"), "true");
+    assert_eq!(forward(&mut context, "[+0].includes(-0)"), "true");
+}
+
+#[test]
+fn includes_value_from_index() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = ["a", "b", "c"];
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "arr.includes('a', 1)"), "false");
+    assert_eq!(forward(&mut context, "arr.includes('c', 2)"), "true");
+    assert_eq!(forward(&mut context, "arr.includes('c', 3)"), "false");
+    assert_eq!(forward(&mut context, "arr.includes('b', -2)"), "true");
+    assert_eq!(forward(&mut context, "arr.includes('a', -2)"), "false");
+    assert_eq!(forward(&mut context, "arr.includes('a', -100)"), "true");
+}
+
+#[test]
+fn includes_value_sparse() {
+    let mut context = Context::new();
+    let init = r#"
+        var sparse = [1, 2, 3];
+        delete sparse[1];
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "sparse.includes(undefined)"), "true");
+    assert_eq!(forward(&mut context, "sparse.includes(2)"), "false");
+}
+
 #[test]
 fn map() {
     let mut context = Context::new();
@@ -671,6 +798,83 @@ fn slice() {
     assert_eq!(forward(&mut context, "many3.length"), "0");
 }
 
+#[test]
+fn splice() {
+    let mut context = Context::new();
+
+    // Insertion only.
+    let init = r#"
+        var insert = [1, 2, 3];
+        var insertRemoved = insert.splice(1, 0, 'a', 'b');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "insertRemoved.length"), "0");
+    assert_eq!(forward(&mut context, "insert.join()"), "\"1,a,b,2,3\"");
+
+    // Deletion past the end is clamped to the remaining elements.
+    let init = r#"
+        var past = [1, 2, 3, 4];
+        var pastRemoved = past.splice(2, 10);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "pastRemoved.join()"), "\"3,4\"");
+    assert_eq!(forward(&mut context, "past.join()"), "\"1,2\"");
+
+    // A negative start counts from the end.
+    let init = r#"
+        var negative = [1, 2, 3, 4, 5];
+        var negativeRemoved = negative.splice(-3, 2, 'x');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "negativeRemoved.join()"), "\"3,4\"");
+    assert_eq!(forward(&mut context, "negative.join()"), "\"1,2,x,5\"");
+
+    // Without a delete count, everything from start is removed.
+    let init = r#"
+        var rest = [1, 2, 3];
+        var restRemoved = rest.splice(1);
+        var none = [1, 2, 3];
+        var noneRemoved = none.splice();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "restRemoved.join()"), "\"2,3\"");
+    assert_eq!(forward(&mut context, "rest.join()"), "\"1\"");
+    assert_eq!(forward(&mut context, "noneRemoved.length"), "0");
+    assert_eq!(forward(&mut context, "none.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn splice_holes() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var removing = [1, 2, 3, 4, 5];
+        delete removing[1];
+        delete removing[3];
+        var removed = removing.splice(0, 2);
+
+        var inserting = [1, 2, 3, 4];
+        delete inserting[2];
+        inserting.splice(1, 1, 'a', 'b');
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "removed.length"), "2");
+    assert_eq!(forward(&mut context, "removed.hasOwnProperty(0)"), "true");
+    assert_eq!(forward(&mut context, "removed.hasOwnProperty(1)"), "false");
+    assert_eq!(forward(&mut context, "removing.length"), "3");
+    assert_eq!(forward(&mut context, "removing.hasOwnProperty(1)"), "false");
+    assert_eq!(forward(&mut context, "removing.hasOwnProperty(3)"), "false");
+    assert_eq!(forward(&mut context, "removing.hasOwnProperty(4)"), "false");
+
+    assert_eq!(forward(&mut context, "inserting.length"), "5");
+    assert_eq!(
+        forward(&mut context, "inserting.hasOwnProperty(3)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "inserting[4]"), "4");
+}
+
 #[test]
 fn for_each() {
     let mut context = Context::new();
@@ -939,6 +1143,54 @@ fn reduce() {
     assert_eq!(result, "\"Reduce was called without a callback\"");
 }
 
+#[test]
+fn reduce_undefined_initial_value() {
+    let mut context = Context::new();
+
+    // An explicit `undefined` is used as the initial value.
+    assert_eq!(
+        forward(&mut context, "[].reduce((acc, x) => acc + x, undefined)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].reduce((acc, x) => acc + x, undefined)"
+        ),
+        "NaN"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[].reduceRight((acc, x) => acc + x, undefined)"
+        ),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].reduceRight((acc, x) => acc + x, undefined)"
+        ),
+        "NaN"
+    );
+
+    // Reducing an empty array without an initial value throws a `TypeError`.
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].reduce((acc, x) => acc + x); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].reduceRight((acc, x) => acc + x); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
 #[test]
 fn reduce_right() {
     let mut context = Context::new();
@@ -1061,6 +1313,36 @@ fn reduce_right() {
     assert_eq!(result, "\"reduceRight was called without a callback\"");
 }
 
+#[test]
+fn reduce_right_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var holes = ["a", "b", "c", "d", "e"];
+        delete holes[1];
+        delete holes[4];
+        function concat(acc, x, idx) {
+            return acc + x + idx;
+        }
+    "#;
+    forward(&mut context, init);
+
+    // The last present element is the accumulator, and holes are skipped.
+    assert_eq!(
+        forward(&mut context, "holes.reduceRight(concat)"),
+        "\"dc2a0\""
+    );
+    assert_eq!(
+        forward(&mut context, "holes.reduceRight(concat, '')"),
+        "\"d3c2a0\""
+    );
+    assert_eq!(forward(&mut context, "holes.reduce(concat)"), "\"ac2d3\"");
+    assert_eq!(
+        forward(&mut context, "holes.reduce(concat, '')"),
+        "\"a0c2d3\""
+    );
+}
+
 #[test]
 fn call_array_constructor_with_one_argument() {
     let mut context = Context::new();
@@ -1237,3 +1519,262 @@ fn array_spread_non_iterable() {
     "#;
     assert_eq!(forward(&mut context, init), "true");
 }
+
+#[test]
+fn array_spread_set() {
+    let mut context = Context::new();
+    let init = r#"
+        var set = new Set([1, 2, 2, 3]);
+        var array = [...set];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "array.length"), "3");
+    assert_eq!(forward(&mut context, "array.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn array_spread_with_elements() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = [1, 2];
+        var b = "xy";
+        var array = [0, ...a, , 3, ...b, ...[]];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "array.length"), "7");
+    assert_eq!(forward(&mut context, "array[2]"), "2");
+    assert_eq!(forward(&mut context, "array[3]"), "undefined");
+    assert_eq!(forward(&mut context, "array[4]"), "3");
+    assert_eq!(forward(&mut context, "array[6]"), "\"y\"");
+    assert_eq!(forward(&mut context, "3 in array"), "false");
+    assert_eq!(forward(&mut context, "4 in array"), "true");
+    assert_eq!(forward(&mut context, "[1, , ].length"), "2");
+}
+
+#[test]
+fn array_spread_non_iterable_object() {
+    let mut context = Context::new();
+    let init = r#"
+        var result = [];
+        for (const value of [undefined, null, {}]) {
+            try {
+                [1, ...value];
+            } catch (err) {
+                result.push(err instanceof TypeError);
+            }
+        }
+        result.join();
+    "#;
+    assert_eq!(forward(&mut context, init), "\"true,true,true\"");
+}
+
+#[test]
+fn flat() {
+    let mut context = Context::new();
+    let init = r#"
+        var nested = [1, [2, [3, [4]]]];
+        var default_depth = nested.flat();
+        var depth_two = nested.flat(2);
+        var infinite = nested.flat(Infinity);
+        var negative = nested.flat(-1);
+        var with_holes = [1, 2, [3, 4, 5]];
+        delete with_holes[1];
+        delete with_holes[2][1];
+        var holes = with_holes.flat();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "default_depth.length"), "3");
+    assert_eq!(forward(&mut context, "default_depth[1]"), "2");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(default_depth[2])"),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "depth_two.length"), "4");
+    assert_eq!(forward(&mut context, "depth_two[2]"), "3");
+    assert_eq!(forward(&mut context, "Array.isArray(depth_two[3])"), "true");
+
+    assert_eq!(forward(&mut context, "infinite.length"), "4");
+    assert_eq!(forward(&mut context, "infinite.join()"), "\"1,2,3,4\"");
+
+    assert_eq!(forward(&mut context, "negative.length"), "2");
+    assert_eq!(forward(&mut context, "negative[1] === nested[1]"), "true");
+
+    assert_eq!(forward(&mut context, "holes.length"), "3");
+    assert_eq!(forward(&mut context, "holes.join()"), "\"1,3,5\"");
+}
+
+#[test]
+fn flat_map() {
+    let mut context = Context::new();
+    let init = r#"
+        var doubled = [1, 2, 3].flatMap(function (x) { return [x, x * 2]; });
+        var one_level = [1, 2].flatMap(function (x) { return [[x]]; });
+        var this_arg = [1].flatMap(function (x) { return this.value; }, { value: 5 });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "doubled.join()"), "\"1,2,2,4,3,6\"");
+    assert_eq!(forward(&mut context, "one_level.length"), "2");
+    assert_eq!(forward(&mut context, "Array.isArray(one_level[0])"), "true");
+    assert_eq!(forward(&mut context, "this_arg[0]"), "5");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [1].flatMap(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn from() {
+    let mut context = Context::new();
+    let init = r#"
+        var from_string = Array.from("abc");
+        var from_array_like = Array.from({ length: 3, 0: "x", 2: "z" });
+        var from_set = Array.from(new Set([1, 2, 2, 3]));
+        var doubled = Array.from([1, 2, 3], function (x) { return x * 2; });
+        var with_index = Array.from({ length: 2 }, function (x, i) { return i + this.offset; }, { offset: 10 });
+        var args = (function () { return Array.from(arguments); })(4, 5);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "from_string.join()"), "\"a,b,c\"");
+    assert_eq!(forward(&mut context, "Array.isArray(from_string)"), "true");
+    assert_eq!(forward(&mut context, "from_array_like.length"), "3");
+    assert_eq!(forward(&mut context, "from_array_like[1]"), "undefined");
+    assert_eq!(forward(&mut context, "from_array_like[2]"), "\"z\"");
+    assert_eq!(forward(&mut context, "from_set.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "doubled.join()"), "\"2,4,6\"");
+    assert_eq!(forward(&mut context, "with_index.join()"), "\"10,11\"");
+    assert_eq!(forward(&mut context, "args.join()"), "\"4,5\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from(null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from([1], 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn sort() {
+    let mut context = Context::new();
+    let init = r#"
+        var numbers = [10, 9, 1].sort();
+        var compared = [10, 9, 1].sort(function (a, b) { return a - b; });
+        var coerced = [3, 1, 2].sort(function (a, b) { return a > b ? "1" : "-1"; });
+        var same = [1, 2];
+        var returned = same.sort() === same;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "numbers.join()"), "\"1,10,9\"");
+    assert_eq!(forward(&mut context, "compared.join()"), "\"1,9,10\"");
+    assert_eq!(forward(&mut context, "coerced.join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "returned"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [1].sort(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn sort_is_stable() {
+    let mut context = Context::new();
+    let init = r#"
+        var people = [
+            { name: "a", age: 30 },
+            { name: "b", age: 20 },
+            { name: "c", age: 30 },
+            { name: "d", age: 20 },
+            { name: "e", age: 30 },
+        ];
+        people.sort(function (x, y) { return x.age - y.age; });
+        var names = people.map(function (person) { return person.name; });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "names.join()"), "\"b,d,a,c,e\"");
+}
+
+#[test]
+fn sort_inconsistent_comparator() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [];
+        for (var i = 0; i < 100; i++) { array.push(i); }
+        array.sort(function () { return Math.random() - 0.5; });
+        var sum = array.reduce(function (a, b) { return a + b; }, 0);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.length"), "100");
+    assert_eq!(forward(&mut context, "sum"), "4950");
+}
+
+#[test]
+fn sort_stops_at_error() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        var array = [3, 2, 1];
+        var thrown = false;
+        try {
+            array.sort(function () { calls++; throw new Error("stop"); });
+        } catch (e) {
+            thrown = e.message === "stop";
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "thrown"), "true");
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(forward(&mut context, "array.join()"), "\"3,2,1\"");
+}
+
+#[test]
+fn sort_array_like() {
+    let mut context = Context::new();
+    let init = r#"
+        var object = { length: 3, 0: "b", 2: "a" };
+        Array.prototype.sort.call(object);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "object[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "object[1]"), "\"b\"");
+    assert_eq!(forward(&mut context, "2 in object"), "false");
+}
+
+#[test]
+fn sort_undefined_and_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [3, undefined, 1];
+        array[5] = 2;
+        var returned = array.sort() === array;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.length"), "6");
+    assert_eq!(forward(&mut context, "array[0]"), "1");
+    assert_eq!(forward(&mut context, "array[1]"), "2");
+    assert_eq!(forward(&mut context, "array[2]"), "3");
+    assert_eq!(forward(&mut context, "array[3]"), "undefined");
+    assert_eq!(forward(&mut context, "3 in array"), "true");
+    assert_eq!(forward(&mut context, "4 in array"), "false");
+    assert_eq!(forward(&mut context, "5 in array"), "false");
+}
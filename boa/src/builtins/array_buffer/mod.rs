@@ -0,0 +1,141 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! An `ArrayBuffer` is a fixed-length block of raw bytes, which is read and written through typed
+//! array views.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal representation of an `ArrayBuffer` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBuffer {
+    data: Vec<u8>,
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let array_buffer_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::is_view, "isView", 1)
+            .callable(false)
+            .build();
+
+        (Self::NAME, array_buffer_object.into(), Self::attribute())
+    }
+}
+
+impl ArrayBuffer {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// The largest byte length of an array buffer. Larger buffers throw a `RangeError` instead of
+    /// trying to allocate them.
+    pub(crate) const MAX_BYTE_LENGTH: usize = u32::MAX as usize;
+
+    /// Create a new array buffer
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let byte_length = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_index(context)?;
+
+        Self::initialize(this, byte_length, context)?;
+
+        Ok(this.clone())
+    }
+
+    /// Turns the object into an array buffer of the given length, filled with zeros.
+    ///
+    /// Throws a `RangeError` if the data block can not be allocated.
+    fn initialize(object: &Value, byte_length: usize, context: &mut Context) -> Result<()> {
+        let mut data = Vec::new();
+        if byte_length > Self::MAX_BYTE_LENGTH || data.try_reserve_exact(byte_length).is_err() {
+            return Err(context.construct_range_error("Array buffer allocation failed"));
+        }
+        data.resize(byte_length, 0);
+        object.set_data(ObjectData::ArrayBuffer(Self { data }));
+
+        let byte_length = DataDescriptor::new(
+            byte_length,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        object.set_property("byteLength", byte_length);
+        Ok(())
+    }
+
+    /// Creates a new array buffer object of the given length, filled with zeros.
+    pub(crate) fn create(byte_length: usize, context: &mut Context) -> Result<GcObject> {
+        let prototype = context
+            .global_object()
+            .get_field(Self::NAME)
+            .get_field(PROTOTYPE);
+
+        let object = GcObject::new(Object::create(prototype));
+        Self::initialize(&object.clone().into(), byte_length, context)?;
+        Ok(object)
+    }
+
+    /// Returns the length of the buffer in bytes.
+    #[inline]
+    pub(crate) fn byte_length(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the bytes of the buffer.
+    #[inline]
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the bytes of the buffer, for writing.
+    #[inline]
+    pub(crate) fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// This method returns whether the argument is a view over an array buffer, such as a typed
+    /// array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(match args.get(0) {
+            Some(Value::Object(object)) => object.borrow().is_typed_array().into(),
+            _ => false.into(),
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+    forward(&mut context, "let buffer = new ArrayBuffer(8);");
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "8");
+    assert_eq!(forward(&mut context, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(-1); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn is_view() {
+    let mut context = Context::new();
+    forward(&mut context, "let buffer = new ArrayBuffer(8);");
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView(buffer)"), "false");
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new Uint8Array(buffer))"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView([])"), "false");
+}
+
+#[test]
+fn huge_length() {
+    let mut context = Context::new();
+    for js in &[
+        "new ArrayBuffer(2 ** 53 - 1)",
+        "new ArrayBuffer(2 ** 40)",
+        "new Float64Array(2 ** 53 - 1)",
+        "new Uint8Array(2 ** 40)",
+    ] {
+        let js = format!(
+            "try {{ {}; false }} catch (e) {{ e instanceof RangeError }}",
+            js
+        );
+        assert_eq!(forward(&mut context, &js), "true", "{}", js);
+    }
+}
@@ -171,7 +171,7 @@ impl BuiltIn for Date {
             .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 1)
             .method(getter_method!(to_date_string), "toDateString", 0)
             .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(getter_method!(to_iso_string), "toISOString", 0)
+            .method(Self::to_iso_string_method, "toISOString", 0)
             .method(getter_method!(to_json), "toJSON", 0)
             // Locale strings
             .method(getter_method!(to_string), "toString", 0)
@@ -388,15 +388,18 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                Value::String(ref str) => match chrono::DateTime::parse_from_rfc3339(&str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                Value::String(ref str) => Self::parse_date_string(str),
                 tv => {
+                    // https://tc39.es/ecma262/#sec-timeclip
                     let tv = tv.to_number(context)?;
-                    let secs = (tv / 1_000f64) as i64;
-                    let nsecs = ((tv % 1_000f64) * 1_000_000f64) as u32;
-                    NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                    if !tv.is_finite() || tv.abs() > 8.64e15 {
+                        None
+                    } else {
+                        let tv = tv.trunc() as i64;
+                        let secs = tv.div_euclid(1_000);
+                        let nsecs = (tv.rem_euclid(1_000) * 1_000_000) as u32;
+                        NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                    }
                 }
             },
         };
@@ -1177,11 +1180,32 @@ impl Date {
     /// [iso8601]: http://en.wikipedia.org/wiki/ISO_8601
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString
-    pub fn to_iso_string(&self) -> String {
-        self.to_utc()
+    pub fn to_iso_string(&self) -> Option<String> {
+        self.to_utc().map(|dt| {
+            // Years outside of 0 to 9999 use the expanded format, with a sign and six digits.
+            let year = match dt.year() {
+                year @ 0..=9999 => format!("{:04}", year),
+                year if year < 0 => format!("-{:06}", -year),
+                year => format!("+{:06}", year),
+            };
             // RFC 3389 uses +0.00 for UTC, where JS expects Z, so we can't use the built-in chrono function.
-            .map(|f| f.format("%Y-%m-%dT%H:%M:%S.%3fZ").to_string())
-            .unwrap_or_else(|| "Invalid Date".to_string())
+            format!("{}{}", year, dt.format("-%m-%dT%H:%M:%S.%3fZ"))
+        })
+    }
+
+    /// `Date.prototype.toISOString()`
+    ///
+    /// Calls [`to_iso_string`](#method.to_iso_string), throwing a `RangeError` if the date is invalid.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
+    fn to_iso_string_method(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        match this_time_value(this, context)?.to_iso_string() {
+            Some(iso_string) => Ok(Value::from(iso_string)),
+            None => context.throw_range_error("Invalid time value"),
+        }
     }
 
     /// `Date.prototype.toJSON()`
@@ -1194,7 +1218,7 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tojson
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toJSON
-    pub fn to_json(&self) -> String {
+    pub fn to_json(&self) -> Option<String> {
         self.to_iso_string()
     }
 
@@ -1279,10 +1303,107 @@ impl Date {
             return Ok(Value::number(f64::NAN));
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(Value::number(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(Value::number(f64::NAN)),
+        let date = Date(Self::parse_date_string(&args[0].to_string(context)?));
+        Ok(Value::number(date.get_time()))
+    }
+
+    /// Parses a string in the [date time string format][spec], a simplification of ISO 8601, falling back to RFC 3339.
+    ///
+    /// Date-only forms are read as UTC, and date-time forms without an offset as local time.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+    fn parse_date_string(string: &str) -> Option<NaiveDateTime> {
+        fn digits(bytes: &[u8], start: usize, count: usize) -> Option<u32> {
+            let digits = bytes.get(start..start + count)?;
+            digits.iter().try_fold(0, |acc, digit| match digit {
+                b'0'..=b'9' => Some(acc * 10 + u32::from(digit - b'0')),
+                _ => None,
+            })
         }
+
+        fn parse_date_time_format(bytes: &[u8]) -> Option<NaiveDateTime> {
+            // YYYY or ±YYYYYY, where -000000 is not allowed.
+            let (year, mut pos) = match bytes.first()? {
+                sign @ b'+' | sign @ b'-' => {
+                    let year = digits(bytes, 1, 6)? as i32;
+                    if *sign == b'-' && year == 0 {
+                        return None;
+                    }
+                    (if *sign == b'-' { -year } else { year }, 7)
+                }
+                _ => (digits(bytes, 0, 4)? as i32, 4),
+            };
+
+            // -MM and -DD are optional, and both default to 01.
+            let mut month = 1;
+            let mut day = 1;
+            if bytes.get(pos) == Some(&b'-') {
+                month = digits(bytes, pos + 1, 2)?;
+                pos += 3;
+                if bytes.get(pos) == Some(&b'-') {
+                    day = digits(bytes, pos + 1, 2)?;
+                    pos += 3;
+                }
+            }
+            let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+            if pos == bytes.len() {
+                return Some(date.and_hms(0, 0, 0));
+            }
+
+            // THH:mm, followed by optional :ss and .sss
+            if bytes.get(pos) != Some(&b'T') || bytes.get(pos + 3) != Some(&b':') {
+                return None;
+            }
+            let hour = digits(bytes, pos + 1, 2)?;
+            let minute = digits(bytes, pos + 4, 2)?;
+            pos += 6;
+            let mut second = 0;
+            let mut millisecond = 0;
+            if bytes.get(pos) == Some(&b':') {
+                second = digits(bytes, pos + 1, 2)?;
+                pos += 3;
+                if bytes.get(pos) == Some(&b'.') {
+                    millisecond = digits(bytes, pos + 1, 3)?;
+                    pos += 4;
+                }
+            }
+
+            // 24:00 is the end of the day.
+            let time = if (hour, minute, second, millisecond) == (24, 0, 0, 0) {
+                date.succ_opt()?.and_hms(0, 0, 0)
+            } else {
+                date.and_hms_milli_opt(hour, minute, second, millisecond)?
+            };
+
+            // Z, ±HH:mm, or no offset for local time.
+            match bytes.get(pos) {
+                None => ignore_ambiguity(Local.from_local_datetime(&time)).map(|dt| dt.naive_utc()),
+                Some(b'Z') if pos + 1 == bytes.len() => Some(time),
+                Some(sign @ b'+') | Some(sign @ b'-')
+                    if pos + 6 == bytes.len() && bytes[pos + 3] == b':' =>
+                {
+                    let hours = digits(bytes, pos + 1, 2)?;
+                    let minutes = digits(bytes, pos + 4, 2)?;
+                    if hours > 23 || minutes > 59 {
+                        return None;
+                    }
+                    let offset = Duration::minutes(i64::from(hours * 60 + minutes));
+                    if *sign == b'+' {
+                        time.checked_sub_signed(offset)
+                    } else {
+                        time.checked_add_signed(offset)
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        parse_date_time_format(string.as_bytes()).or_else(|| {
+            DateTime::parse_from_rfc3339(string)
+                .ok()
+                .map(|dt| dt.naive_utc())
+        })
     }
 
     /// `Date.UTC()`
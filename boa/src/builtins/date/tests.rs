@@ -121,6 +121,67 @@ fn date_ctor_call_string_invalid() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_call_string_date_only() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    // Date-only forms are UTC
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-06-08')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 06, 08).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-06')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 06, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('+002020')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 01, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_local() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    // Date-time forms without an offset are local time
+    let date_time = forward_dt_local(&mut context, "new Date('2020-06-08T09:16')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 06, 08).and_hms(09, 16, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-06-08T09:16:15Z')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 06, 08).and_hms(09, 16, 15)),
+        date_time
+    );
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_out_of_range() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for invalid in &[
+        "new Date('2020-13-01')",
+        "new Date('2020-02-30')",
+        "new Date('2020-06-08T25:00Z')",
+        "new Date('2020-06-08T09:16:15.779+24:00')",
+        "new Date('-000000-01-01')",
+        "new Date('2020-06-08T')",
+    ] {
+        assert_eq!(None, forward_dt_utc(&mut context, invalid), "{}", invalid);
+    }
+    Ok(())
+}
+
 #[test]
 fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -133,6 +194,23 @@ fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_call_number_time_clip() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "new Date(NaN).getTime()"), "NaN");
+    assert_eq!(
+        forward(&mut context, "new Date(8.64e15 + 1).getTime()"),
+        "NaN"
+    );
+    assert_eq!(forward(&mut context, "new Date(-1.5).getTime()"), "-1");
+    assert_eq!(
+        forward(&mut context, "new Date(-1).toISOString()"),
+        "\"1969-12-31T23:59:59.999Z\""
+    );
+    Ok(())
+}
+
 #[test]
 fn date_ctor_call_date() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -217,6 +295,16 @@ fn date_ctor_parse_call() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_parse_call_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Date.parse('nope')"), "NaN");
+    assert_eq!(forward(&mut context, "Date.parse('2020-06-08T')"), "NaN");
+    assert_eq!(forward(&mut context, "isNaN(new Date('nope'))"), "true");
+    Ok(())
+}
+
 #[test]
 fn date_ctor_utc_call() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -1250,6 +1338,48 @@ fn date_proto_to_iso_string() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_proto_to_iso_string_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for iso_string in &[
+        "2020-07-08T09:16:15.779Z",
+        "1969-12-31T23:59:59.999Z",
+        "+010000-01-01T00:00:00.000Z",
+        "-000001-01-01T00:00:00.000Z",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("new Date('{}').toISOString()", iso_string)
+            ),
+            format!("\"{}\"", iso_string)
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "Date.parse('2020-07-08T09:16:15.779Z') === Date.UTC(2020, 06, 08, 09, 16, 15, 779)"
+        ),
+        "true"
+    );
+    Ok(())
+}
+
+#[test]
+fn date_proto_to_iso_string_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Date(NaN).toISOString() } catch (e) { e.toString() }"
+        ),
+        "\"RangeError: Invalid time value\""
+    );
+    Ok(())
+}
+
 #[test]
 fn date_proto_to_json() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -1264,6 +1394,14 @@ fn date_proto_to_json() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_proto_to_json_invalid() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "new Date(NaN).toJSON()"), "null");
+    Ok(())
+}
+
 #[test]
 fn date_proto_to_string() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
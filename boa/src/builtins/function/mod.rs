@@ -12,8 +12,9 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function
 
 use crate::{
-    builtins::{Array, BuiltIn},
+    builtins::{Array, BuiltIn, Reflect},
     environment::lexical_environment::Environment,
+    exec::Executable,
     gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
     property::{Attribute, DataDescriptor},
@@ -48,6 +49,27 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, captures, context) -> ResultValue_ - The signature of a built-in closure
+pub type ClosureFunction = fn(&Value, &[Value], &Value, &mut Context) -> Result<Value>;
+
+/// A built-in function which is called with the values it captured.
+///
+/// The captures are stored in the function object next to it, so that they are traced by the
+/// garbage collector. State that is shared between closures, or that changes between calls, can
+/// be captured as an object holding a native Rust value.
+#[derive(Clone, Copy, Finalize)]
+pub struct BuiltInClosure(pub(crate) ClosureFunction);
+
+unsafe impl Trace for BuiltInClosure {
+    empty_trace!();
+}
+
+impl Debug for BuiltInClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native closure]")
+    }
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
@@ -99,62 +121,84 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    Closure {
+        function: BuiltInClosure,
+        captures: Value,
+        flags: FunctionFlags,
+    },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
     },
+    /// A bound function, created by `Function.prototype.bind`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-bound-function-exotic-objects>
+    Bound {
+        target: GcObject,
+        this: Value,
+        args: Box<[Value]>,
+    },
 }
 
 impl Function {
-    // Adds the final rest parameters to the Environment as an array
-    pub(crate) fn add_rest_param(
-        &self,
-        param: &FormalParameter,
-        index: usize,
-        args_list: &[Value],
+    /// Binds the parameters of an ordinary function in the current environment, which must be the
+    /// new environment of the call.
+    ///
+    /// The default values of missing or `undefined` arguments are evaluated from left to right.
+    /// All the parameters are bound first, so a default value that uses a later parameter throws
+    /// a `ReferenceError` instead of reading a variable of an outer scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+    pub(crate) fn initialize_parameters(
+        params: &[FormalParameter],
+        args: &[Value],
         context: &mut Context,
-        local_env: &Environment,
-    ) {
-        // Create array of values
-        let array = Array::new_array(context).unwrap();
-        Array::add_to_array_object(&array, &args_list[index..]).unwrap();
-
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), array);
-    }
+    ) -> Result<()> {
+        let local_env = context
+            .realm_mut()
+            .environment
+            .get_current_environment()
+            .clone();
+
+        for param in params {
+            local_env
+                .borrow_mut()
+                .create_mutable_binding(param.name().to_owned(), false);
+        }
+
+        for (i, param) in params.iter().enumerate() {
+            let value = if param.is_rest_param() {
+                // The rest parameter is always the last one, and collects the arguments left.
+                let array = Array::new_array(context)?;
+                Array::add_to_array_object(&array, args.get(i..).unwrap_or_default())?;
+                array
+            } else {
+                match (args.get(i), param.init()) {
+                    (Some(value), _) if !value.is_undefined() => value.clone(),
+                    (_, Some(init)) => init.run(context)?,
+                    _ => Value::undefined(),
+                }
+            };
+
+            local_env
+                .borrow_mut()
+                .initialize_binding(param.name(), value);
+        }
 
-    // Adds an argument to the environment
-    pub(crate) fn add_arguments_to_environment(
-        &self,
-        param: &FormalParameter,
-        value: Value,
-        local_env: &Environment,
-    ) {
-        // Create binding
-        local_env
-            .borrow_mut()
-            .create_mutable_binding(param.name().to_owned(), false);
-
-        // Set Binding to value
-        local_env
-            .borrow_mut()
-            .initialize_binding(param.name(), value);
+        Ok(())
     }
 
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_callable(),
-            Self::Ordinary { flags, .. } => flags.is_callable(),
+            Self::Closure { flags, .. } | Self::Ordinary { flags, .. } => flags.is_callable(),
+            Self::Bound { .. } => true,
         }
     }
 
@@ -162,17 +206,107 @@ impl Function {
     pub fn is_constructable(&self) -> bool {
         match self {
             Self::BuiltIn(_, flags) => flags.is_constructable(),
-            Self::Ordinary { flags, .. } => flags.is_constructable(),
+            Self::Closure { flags, .. } | Self::Ordinary { flags, .. } => flags.is_constructable(),
+            Self::Bound { target, .. } => target.borrow().is_constructable(),
         }
     }
 }
 
+/// The parameter map of a mapped `arguments` object.
+///
+/// In sloppy mode functions with a simple parameter list, each index of the `arguments` object
+/// that has a matching parameter stays linked to the binding of that parameter.
+///
+/// <https://tc39.es/ecma262/#sec-arguments-exotic-objects>
+#[derive(Debug, Trace, Finalize)]
+pub struct MappedArguments {
+    environment: Environment,
+    parameters: Vec<Option<String>>,
+}
+
+impl MappedArguments {
+    /// Returns the name of the parameter mapped to the given index, if any.
+    fn parameter(&self, index: u32) -> Option<&str> {
+        self.parameters
+            .get(index as usize)
+            .and_then(|name| name.as_deref())
+    }
+
+    /// Gets the value of the parameter mapped to the given index, if any.
+    pub(crate) fn get(&self, index: u32) -> Option<Value> {
+        let name = self.parameter(index)?;
+        Some(self.environment.borrow().get_binding_value(name, false))
+    }
+
+    /// Sets the value of the parameter mapped to the given index, if any.
+    pub(crate) fn set(&self, index: u32, value: Value) {
+        if let Some(name) = self.parameter(index) {
+            self.environment
+                .borrow_mut()
+                .set_mutable_binding(name, value, false);
+        }
+    }
+
+    /// Removes the link between the given index and its parameter.
+    pub(crate) fn unmap(&mut self, index: u32) {
+        if let Some(name) = self.parameters.get_mut(index as usize) {
+            *name = None;
+        }
+    }
+}
+
+/// Creates the `arguments` object of a call to an ordinary function.
+///
+/// The object is mapped to the parameters if the function is sloppy mode code with a simple
+/// parameter list, and unmapped otherwise.
+pub(crate) fn create_arguments_object(
+    body: &RcStatementList,
+    params: &[FormalParameter],
+    arguments_list: &[Value],
+    environment: &Environment,
+    context: &Context,
+) -> Value {
+    let arguments_obj = create_unmapped_arguments_object(arguments_list, context);
+
+    let is_simple = params
+        .iter()
+        .all(|param| param.init().is_none() && !param.is_rest_param());
+    if body.strict() || !is_simple {
+        return arguments_obj;
+    }
+
+    // <https://tc39.es/ecma262/#sec-createmappedargumentsobject>
+    // When a name is repeated, the index of its last occurrence is the mapped one.
+    let mut mapped_names = Vec::with_capacity(params.len());
+    let mut parameters = vec![None; arguments_list.len()];
+    for (index, param) in params.iter().enumerate().rev() {
+        if !mapped_names.contains(&param.name()) {
+            mapped_names.push(param.name());
+            if index < arguments_list.len() {
+                parameters[index] = Some(param.name().to_owned());
+            }
+        }
+    }
+
+    arguments_obj.set_data(ObjectData::Arguments(MappedArguments {
+        environment: environment.clone(),
+        parameters,
+    }));
+    arguments_obj
+}
+
 /// Arguments.
 ///
 /// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
+pub fn create_unmapped_arguments_object(arguments_list: &[Value], context: &Context) -> Value {
     let len = arguments_list.len();
-    let mut obj = GcObject::new(Object::default());
+    let mut obj = GcObject::new(Object::create(
+        context
+            .standard_objects()
+            .object_object()
+            .prototype()
+            .into(),
+    ));
     // Set length
     let length = DataDescriptor::new(
         len,
@@ -192,6 +326,18 @@ pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
         index += 1;
     }
 
+    // Iterate like an array, with `Array.prototype.values`
+    let values_function = context
+        .standard_objects()
+        .array_object()
+        .prototype()
+        .get(&"values".into());
+    let iterator = DataDescriptor::new(
+        values_function,
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+    );
+    obj.insert(context.well_known_symbols().iterator_symbol(), iterator);
+
     Value::from(obj)
 }
 
@@ -299,12 +445,81 @@ impl BuiltInFunctionObject {
             // TODO?: 3.a. PrepareForTailCall
             return context.call(this, &this_arg, &[]);
         }
-        let arg_list = context
-            .extract_array_properties(&arg_array)
-            .map_err(|()| arg_array)?;
+        let arg_list = Reflect::list_from_array_like(Some(&arg_array), context)?;
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.bind( thisArg, ...args )`
+    ///
+    /// The bind() method creates a new function that calls self with the given `this` value,
+    /// and with the given arguments before the ones of the call.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = match this {
+            Value::Object(object) if object.is_callable() => object.clone(),
+            _ => return context.throw_type_error(format!("{} is not a function", this.display())),
+        };
+        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let bound_args: Box<[Value]> = args.get(1..).unwrap_or_default().into();
+
+        let length = if target.get_own_property(&"length".into()).is_some() {
+            match target.get(&"length".into()) {
+                Value::Integer(length) => f64::from(length),
+                Value::Rational(length) if length.is_nan() => 0.0,
+                Value::Rational(length) => length.trunc(),
+                _ => 0.0,
+            }
+        } else {
+            0.0
+        };
+        let length = (length - bound_args.len() as f64).max(0.0);
+
+        let name = match target.get(&"name".into()) {
+            Value::String(name) => name.to_string(),
+            _ => String::new(),
+        };
+
+        let prototype = target.get_prototype_of();
+        let mut function = Object::function(
+            Function::Bound {
+                target,
+                this: this_arg,
+                args: bound_args,
+            },
+            prototype,
+        );
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        function.insert_property("length", length, attribute);
+        function.insert_property("name", format!("bound {}", name), attribute);
+
+        Ok(GcObject::new(function).into())
+    }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// Determines whether `V` is an instance of the function, by looking for the `prototype` of
+    /// the function in the prototype chain of `V`. This is what `instanceof` does by default.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        match this.as_object() {
+            Some(object) => Ok(object.ordinary_has_instance(context, &value)?.into()),
+            None => Ok(false.into()),
+        }
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -325,6 +540,14 @@ impl BuiltIn for BuiltInFunctionObject {
             .constructable(false)
             .build_function_prototype(&function_prototype);
 
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let symbol_has_instance = context.well_known_symbols().has_instance_symbol();
+
         let function_object = ConstructorBuilder::with_standard_object(
             context,
             Self::constructor,
@@ -334,6 +557,12 @@ impl BuiltIn for BuiltInFunctionObject {
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
         .method(Self::apply, "apply", 1)
+        .method(Self::bind, "bind", 1)
+        .property(
+            symbol_has_instance,
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
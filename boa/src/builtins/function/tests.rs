@@ -24,6 +24,174 @@ fn arguments_object() {
     );
 }
 
+#[test]
+fn arguments_object_length_and_iteration() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function f(a) {
+            return arguments.length + ":" + [...arguments].join();
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f(1, 2, 3)"), "\"3:1,2,3\"");
+    assert_eq!(forward(&mut context, "f()"), "\"0:\"");
+}
+
+#[test]
+fn arguments_object_mapped() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function write_arguments(a, b) {
+            arguments[0] = 10;
+            return a;
+        }
+        function write_parameter(a, b) {
+            a = 20;
+            return arguments[0];
+        }
+        function write_missing(a, b) {
+            b = 30;
+            return arguments[1];
+        }
+        function write_deleted(a) {
+            delete arguments[0];
+            arguments[0] = 40;
+            return a;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "write_arguments(1, 2)"), "10");
+    assert_eq!(forward(&mut context, "write_parameter(1, 2)"), "20");
+    assert_eq!(forward(&mut context, "write_missing(1)"), "undefined");
+    assert_eq!(forward(&mut context, "write_deleted(1)"), "1");
+}
+
+#[test]
+fn arguments_object_mapped_builtins() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function join(a, b) {
+            a = 10;
+            return Array.prototype.join.call(arguments);
+        }
+        function reverse(a, b) {
+            Array.prototype.reverse.call(arguments);
+            return a + ":" + b;
+        }
+        function descriptor(a) {
+            a = 20;
+            return Object.getOwnPropertyDescriptor(arguments, "0").value;
+        }
+        function read_only(a) {
+            Object.defineProperty(arguments, "0", { writable: false });
+            a = 30;
+            return arguments[0];
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "join(1, 2)"), "\"10,2\"");
+    assert_eq!(forward(&mut context, "reverse(1, 2)"), "\"2:1\"");
+    assert_eq!(forward(&mut context, "descriptor(1)"), "20");
+    assert_eq!(forward(&mut context, "read_only(1)"), "1");
+}
+
+#[test]
+fn arguments_object_unmapped() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function strict(a) {
+            "use strict";
+            arguments[0] = 10;
+            return a;
+        }
+        function non_simple(a = 0) {
+            arguments[0] = 10;
+            return a;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "strict(1)"), "1");
+    assert_eq!(forward(&mut context, "non_simple(1)"), "1");
+}
+
+#[test]
+fn arguments_object_in_arrow_function() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function f() {
+            let arrow = () => arguments[0];
+            return arrow(2);
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f(1)"), "1");
+}
+
+#[test]
+fn default_parameters() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function f(a, b = a + 1) {
+            return [a, b];
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f(1).join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "f(1, undefined).join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "f(1, 5).join()"), "\"1,5\"");
+    assert_eq!(forward(&mut context, "f(1, null)[1]"), "null");
+}
+
+#[test]
+fn default_parameters_evaluation_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var calls = "";
+        function f(a = calls += "a", b = calls += "b", c = arguments.length) {
+            return c;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "f(undefined, undefined, undefined, 4)"),
+        "4"
+    );
+    assert_eq!(forward(&mut context, "calls"), "\"ab\"");
+}
+
+#[test]
+fn default_parameters_forward_reference() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var b = "outer";
+        function f(a = b, b) {
+            return a;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "try { f(); } catch (e) { e.name }"),
+        "\"ReferenceError\""
+    );
+    assert_eq!(forward(&mut context, "f(1)"), "1");
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     let mut context = Context::new();
@@ -212,3 +380,165 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_prototype_apply_array_like() {
+    let mut context = Context::new();
+    let init = r#"
+        function sum(a, b, c) {
+            return a + b + c;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "sum.apply(null, { length: 3, 0: 1, 1: 10, 2: 100 })"
+        ),
+        "111"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "sum.apply(null, { length: 2, 0: 'a', 1: 'b' })"
+        ),
+        "\"abundefined\""
+    );
+}
+
+#[test]
+fn function_prototype_apply_null_or_undefined_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        function count() {
+            return arguments.length;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "count.apply(null, undefined)"), "0");
+    assert_eq!(forward(&mut context, "count.apply(null, null)"), "0");
+    assert_eq!(forward(&mut context, "count.apply(null)"), "0");
+}
+
+#[test]
+fn function_prototype_apply_non_object_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {}
+        "#;
+    forward(&mut context, init);
+
+    assert!(forward(&mut context, "f.apply(null, 5)").contains("TypeError"));
+    assert!(forward(&mut context, "f.apply(null, 'abc')").contains("TypeError"));
+}
+
+#[test]
+fn function_prototype_call_multiple_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a, b, c) {
+            return this.base + a + b + c;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "f.call({ base: 1000 }, 100, 10, 1)"),
+        "1111"
+    );
+    assert_eq!(forward(&mut context, "f.call({ base: 1 }, 1, 1)"), "NaN");
+}
+
+#[test]
+fn function_this_binding() {
+    let mut context = Context::new();
+    let init = r#"
+        function sloppy() {
+            return this;
+        }
+        function strict() {
+            'use strict';
+            return this;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "sloppy.call(null) === globalThis"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "sloppy.apply(undefined) === globalThis"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "typeof sloppy.call(5)"), "\"object\"");
+    assert_eq!(
+        forward(&mut context, "sloppy.call(5) instanceof Number"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "strict.call(null)"), "null");
+    assert_eq!(
+        forward(&mut context, "strict.apply(undefined)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "strict.call(5)"), "5");
+}
+
+#[test]
+fn function_prototype_bind() {
+    let mut context = Context::new();
+    let init = r#"
+        function add(a, b, c) {
+            return this.base + a + b + c;
+        }
+        var bound = add.bind({ base: 1 }, 10);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "bound(100, 1000)"), "1111");
+    assert_eq!(forward(&mut context, "bound.bind(null, 100)(1000)"), "1111");
+    assert_eq!(forward(&mut context, "typeof bound"), "\"function\"");
+}
+
+#[test]
+fn function_prototype_bind_construct() {
+    let mut context = Context::new();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        var BoundPoint = Point.bind({ ignored: true }, 1);
+        var point = new BoundPoint(2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "point.x"), "1");
+    assert_eq!(forward(&mut context, "point.y"), "2");
+    assert_eq!(forward(&mut context, "point.ignored"), "undefined");
+    assert_eq!(forward(&mut context, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut context, "point instanceof BoundPoint"), "true");
+}
+
+#[test]
+fn function_prototype_bind_length_and_name() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a, b, c) {}
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f.bind(null).length"), "3");
+    assert_eq!(forward(&mut context, "f.bind(null, 1).length"), "2");
+    assert_eq!(
+        forward(&mut context, "f.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "f.bind(null).name"), "\"bound f\"");
+    assert_eq!(
+        forward(&mut context, "f.bind(null).bind(null).name"),
+        "\"bound bound f\""
+    );
+}
@@ -8,3 +8,33 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_this_is_the_global_this_value() {
+    let scenario = r#"
+        globalThis === this;
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn global_this_property_creates_global_binding() {
+    let scenario = r#"
+        globalThis.x = 1;
+        x;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn global_var_is_visible_through_global_this() {
+    let scenario = r#"
+        var y = 1;
+        y = 2;
+        globalThis.y;
+        "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
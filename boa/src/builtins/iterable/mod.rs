@@ -2,6 +2,9 @@ use crate::{
     builtins::string::string_iterator::StringIterator,
     builtins::ArrayIterator,
     builtins::MapIterator,
+    builtins::RegExpStringIterator,
+    builtins::SetIterator,
+    exec::InterpreterState,
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
@@ -13,6 +16,8 @@ pub struct IteratorPrototypes {
     array_iterator: GcObject,
     string_iterator: GcObject,
     map_iterator: GcObject,
+    set_iterator: GcObject,
+    regexp_string_iterator: GcObject,
 }
 
 impl IteratorPrototypes {
@@ -28,9 +33,18 @@ impl IteratorPrototypes {
             string_iterator: StringIterator::create_prototype(context, iterator_prototype.clone())
                 .as_object()
                 .expect("String Iterator Prototype is not an object"),
-            map_iterator: MapIterator::create_prototype(context, iterator_prototype)
+            map_iterator: MapIterator::create_prototype(context, iterator_prototype.clone())
                 .as_object()
                 .expect("Map Iterator Prototype is not an object"),
+            set_iterator: SetIterator::create_prototype(context, iterator_prototype.clone())
+                .as_object()
+                .expect("Set Iterator Prototype is not an object"),
+            regexp_string_iterator: RegExpStringIterator::create_prototype(
+                context,
+                iterator_prototype,
+            )
+            .as_object()
+            .expect("RegExp String Iterator Prototype is not an object"),
         }
     }
 
@@ -52,6 +66,16 @@ impl IteratorPrototypes {
     pub fn map_iterator(&self) -> GcObject {
         self.map_iterator.clone()
     }
+
+    #[inline]
+    pub fn set_iterator(&self) -> GcObject {
+        self.set_iterator.clone()
+    }
+
+    #[inline]
+    pub fn regexp_string_iterator(&self) -> GcObject {
+        self.regexp_string_iterator.clone()
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -67,18 +91,35 @@ pub fn create_iter_result_object(context: &mut Context, value: Value, done: bool
     object
 }
 
-/// Get an iterator record
+/// GetIterator( obj )
+///
+/// Calls the `@@iterator` method of `iterable` and returns a record of the resulting iterator
+/// and its `next` method.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiterator
 pub fn get_iterator(context: &mut Context, iterable: Value) -> Result<IteratorRecord> {
-    // TODO: Fix the accessor handling
-    let iterator_function = iterable
-        .get_property(context.well_known_symbols().iterator_symbol())
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| context.construct_type_error("Not an iterable"))?;
-    let iterator_object = context.call(&iterator_function, &iterable, &[])?;
-    let next_function = iterator_object
-        .get_property("next")
-        .map(|p| p.as_data_descriptor().unwrap().value())
-        .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
+    // GetV: primitives look the method up on their wrapper object, but are still passed as `this`.
+    let iterator_symbol = context.well_known_symbols().iterator_symbol();
+    let iterator_function = Value::from(iterable.to_object(context)?)
+        .get_field_with_accessors(iterator_symbol, context)?;
+    if !iterator_function.is_function() {
+        return Err(context.construct_type_error("Not an iterable"));
+    }
+    let iterator_object = context.call(&iterator_function, &iterable, &[]);
+    // unset the early return flag
+    context
+        .executor()
+        .set_current_state(InterpreterState::Executing);
+    let iterator_object = iterator_object?;
+    if !iterator_object.is_object() {
+        return Err(
+            context.construct_type_error("Result of the Symbol.iterator method is not an object")
+        );
+    }
+    let next_function = iterator_object.get_field_with_accessors("next", context)?;
     Ok(IteratorRecord::new(iterator_object, next_function))
 }
 
@@ -124,21 +165,53 @@ impl IteratorRecord {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-iteratornext
     pub(crate) fn next(&self, context: &mut Context) -> Result<IteratorResult> {
-        let next = context.call(&self.next_function, &self.iterator_object, &[])?;
-        // FIXME: handle accessor descriptors
-        let done = next
-            .get_property("done")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .and_then(|v| v.as_boolean())
-            .ok_or_else(|| context.construct_type_error("Could not find property `done`"))?;
-
-        // FIXME: handle accessor descriptors
-        let next_result = next
-            .get_property("value")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .unwrap_or_default();
+        let next = context.call(&self.next_function, &self.iterator_object, &[]);
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        let next = next?;
+        if !next.is_object() {
+            return Err(context.construct_type_error("Iterator result is not an object"));
+        }
+        let done = next.get_field_with_accessors("done", context)?.to_boolean();
+        let next_result = next.get_field_with_accessors("value", context)?;
         Ok(IteratorResult::new(next_result, done))
     }
+
+    /// Cleanup the iterator
+    ///
+    /// Calls the `return` method of the iterator, if it has one, when the iteration ends before
+    /// the iterator is done. An abrupt `completion` takes precedence over any error thrown by the
+    /// `return` method.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iteratorclose
+    pub(crate) fn close(&self, completion: Result<Value>, context: &mut Context) -> Result<Value> {
+        let return_method = match self
+            .iterator_object
+            .get_field_with_accessors("return", context)
+        {
+            Ok(return_method) => return_method,
+            Err(error) => return completion.and(Err(error)),
+        };
+        if return_method.is_null_or_undefined() {
+            return completion;
+        }
+
+        let inner_result = context.call(&return_method, &self.iterator_object, &[]);
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        let completion = completion?;
+        if !inner_result?.is_object() {
+            return context.throw_type_error("Iterator return result is not an object");
+        }
+        Ok(completion)
+    }
 }
 
 #[derive(Debug)]
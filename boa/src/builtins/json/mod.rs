@@ -14,12 +14,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
 use crate::{
-    builtins::BuiltIn,
-    object::ObjectInitializer,
-    property::{Attribute, DataDescriptor, PropertyKey},
+    builtins::{BuiltIn, Number},
+    object::{GcObject, ObjectInitializer},
+    property::{Attribute, PropertyKey},
+    value::{PreferredType, RcString},
     BoaProfiler, Context, Result, Value,
 };
 use serde_json::{self, Value as JSONValue};
+use std::string::String as StdString;
 
 #[cfg(test)]
 mod tests;
@@ -72,7 +74,7 @@ impl Json {
                 let j = Value::from_json(json, context);
                 match args.get(1) {
                     Some(reviver) if reviver.is_function() => {
-                        let mut holder = Value::new_object(None);
+                        let mut holder = Value::new_object(Some(context.global_object()));
                         holder.set_field("", j);
                         Self::walk(reviver, context, &mut holder, &PropertyKey::from(""))
                     }
@@ -83,12 +85,16 @@ impl Json {
         }
     }
 
-    /// This is a translation of the [Polyfill implementation][polyfill]
+    /// Abstract operation `InternalizeJSONProperty( holder, name, reviver )`
     ///
     /// This function recursively walks the structure, passing each key-value pair to the reviver function
-    /// for possible transformation.
+    /// for possible transformation. The properties of an object or array are revived before the object itself,
+    /// and a property is deleted if the reviver returns `undefined` for it.
     ///
-    /// [polyfill]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
     fn walk(
         reviver: &Value,
         context: &mut Context,
@@ -98,18 +104,19 @@ impl Json {
         let value = holder.get_field(key.clone());
 
         if let Value::Object(ref object) = value {
-            let keys: Vec<_> = object.borrow().keys().collect();
+            let keys: Vec<PropertyKey> = if object.is_array() {
+                let length = value.get_field("length").to_length(context)?;
+                (0..length).map(PropertyKey::from).collect()
+            } else {
+                object.borrow().keys().collect()
+            };
 
             for key in keys {
-                let v = Self::walk(reviver, context, &mut value.clone(), &key);
-                match v {
-                    Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v);
-                    }
-                    Ok(_) => {
-                        value.remove_property(key);
-                    }
-                    Err(_v) => {}
+                let v = Self::walk(reviver, context, &mut value.clone(), &key)?;
+                if v.is_undefined() {
+                    value.remove_property(key);
+                } else {
+                    value.set_field(key, v);
                 }
             }
         }
@@ -133,71 +140,268 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-json.stringify
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify
     pub(crate) fn stringify(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let object = match args.get(0) {
-            Some(obj) if obj.is_symbol() || obj.is_function() || obj.is_undefined() => {
-                return Ok(Value::undefined())
+        let value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let replacer = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        let space = args.get(2).cloned().unwrap_or_else(Value::undefined);
+
+        let mut state = SerializeState::default();
+
+        if let Some(replacer_object) = replacer.as_object() {
+            if replacer_object.is_callable() {
+                state.replacer_function = Some(replacer.clone());
+            } else if replacer_object.is_array() {
+                let length = replacer
+                    .get_field_with_accessors("length", context)?
+                    .to_length(context)?;
+                let mut property_list: Vec<RcString> = Vec::new();
+                for index in 0..length {
+                    let element = replacer.get_field_with_accessors(index, context)?;
+                    let item = match element {
+                        Value::String(ref string) => Some(string.clone()),
+                        Value::Integer(_) | Value::Rational(_) => Some(element.to_string(context)?),
+                        Value::Object(ref object) => {
+                            let is_wrapper = {
+                                let object = object.borrow();
+                                object.is_string() || object.is_number()
+                            };
+                            if is_wrapper {
+                                Some(element.to_string(context)?)
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    };
+                    if let Some(item) = item {
+                        if !property_list.contains(&item) {
+                            property_list.push(item);
+                        }
+                    }
+                }
+                state.property_list = Some(property_list);
             }
-            None => return Ok(Value::undefined()),
-            Some(obj) => obj,
+        }
+
+        let space = match space {
+            Value::Object(ref object) if object.borrow().is_number() => {
+                Value::from(space.to_number(context)?)
+            }
+            Value::Object(ref object) if object.borrow().is_string() => {
+                Value::from(space.to_string(context)?)
+            }
+            _ => space,
         };
-        let replacer = match args.get(1) {
-            Some(replacer) if replacer.is_object() => replacer,
-            _ => return Ok(Value::from(object.to_json(context)?.to_string())),
+        state.gap = match space {
+            Value::Integer(_) | Value::Rational(_) => {
+                let count = space.to_integer(context)?.min(10.0);
+                if count >= 1.0 {
+                    " ".repeat(count as usize)
+                } else {
+                    StdString::new()
+                }
+            }
+            Value::String(ref string) => string.chars().take(10).collect(),
+            _ => StdString::new(),
         };
 
-        let replacer_as_object = replacer
-            .as_object()
-            .expect("JSON.stringify replacer was an object");
-        if replacer_as_object.is_callable() {
-            object
-                .as_object()
-                .map(|obj| {
-                    let object_to_return = Value::new_object(None);
-                    for (key, val) in obj
-                        .borrow()
-                        .iter()
-                        // FIXME: handle accessor descriptors
-                        .map(|(k, v)| (k, v.as_data_descriptor().unwrap().value()))
-                    {
-                        let this_arg = object.clone();
-                        object_to_return.set_property(
-                            key.to_owned(),
-                            DataDescriptor::new(
-                                context.call(
-                                    replacer,
-                                    &this_arg,
-                                    &[Value::from(key.clone()), val.clone()],
-                                )?,
-                                Attribute::all(),
-                            ),
-                        );
-                    }
-                    Ok(Value::from(object_to_return.to_json(context)?.to_string()))
-                })
-                .ok_or_else(Value::undefined)?
-        } else if replacer_as_object.is_array() {
-            let mut obj_to_return = serde_json::Map::new();
-            let replacer_as_object = replacer_as_object.borrow();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
+        let wrapper = Value::new_object(Some(context.global_object()));
+        wrapper.set_field("", value);
+        match Self::serialize_property(&mut state, "".into(), &wrapper, context)? {
+            Some(result) => Ok(Value::from(result)),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// Abstract operation `SerializeJSONProperty( state, key, holder )`
+    ///
+    /// Returns `None` if the value can't be represented in JSON, in which case the property is
+    /// left out of its object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_property(
+        state: &mut SerializeState,
+        key: RcString,
+        holder: &Value,
+        context: &mut Context,
+    ) -> Result<Option<StdString>> {
+        let mut value = holder.get_field_with_accessors(key.clone(), context)?;
+
+        if value.is_object() || value.is_bigint() {
+            let to_json = value.get_field_with_accessors("toJSON", context)?;
+            if to_json.is_function() {
+                value = context.call(&to_json, &value, &[Value::from(key.clone())])?;
+            }
+        }
+
+        if let Some(ref replacer) = state.replacer_function {
+            value = context.call(replacer, holder, &[Value::from(key), value])?;
+        }
+
+        if let Value::Object(ref object) = value {
+            let primitive = {
+                let object = object.borrow();
+                object.is_number() || object.is_string() || object.is_boolean()
+            };
+            if primitive {
+                value = value.to_primitive(context, PreferredType::Default)?;
+            }
+        }
+
+        match value {
+            Value::Null => Ok(Some("null".into())),
+            Value::Boolean(boolean) => Ok(Some(boolean.to_string())),
+            Value::String(ref string) => Ok(Some(quote_json_string(string))),
+            Value::Integer(integer) => Ok(Some(integer.to_string())),
+            Value::Rational(number) if number.is_finite() => {
+                Ok(Some(Number::to_native_string(number)))
+            }
+            Value::Rational(_) => Ok(Some("null".into())),
+            Value::BigInt(_) => {
+                Err(context.construct_type_error("BigInt value can't be serialized in JSON"))
+            }
+            Value::Object(ref object) if !object.is_callable() => {
+                if object.is_array() {
+                    Self::serialize_array(state, object, context).map(Some)
                 } else {
-                    Some(replacer.get_field(key))
-                }
-            });
-            for field in fields {
-                if let Some(value) = object
-                    .get_property(field.to_string(context)?)
-                    // FIXME: handle accessor descriptors
-                    .map(|prop| prop.as_data_descriptor().unwrap().value().to_json(context))
-                    .transpose()?
-                {
-                    obj_to_return.insert(field.to_string(context)?.to_string(), value);
+                    Self::serialize_object(state, object, context).map(Some)
                 }
             }
-            Ok(Value::from(JSONValue::Object(obj_to_return).to_string()))
+            _ => Ok(None),
+        }
+    }
+
+    /// Abstract operation `SerializeJSONObject( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
+    fn serialize_object(
+        state: &mut SerializeState,
+        object: &GcObject,
+        context: &mut Context,
+    ) -> Result<StdString> {
+        state.enter(object, context)?;
+        let stepback = state.indent.clone();
+        state.indent.push_str(&state.gap.clone());
+
+        let keys = if let Some(ref property_list) = state.property_list {
+            property_list.clone()
         } else {
-            Ok(Value::from(object.to_json(context)?.to_string()))
+            // The same keys as `Object.keys`, in the same order.
+            object
+                .own_property_keys()
+                .into_iter()
+                .filter(|key| {
+                    !matches!(key, PropertyKey::Symbol(_))
+                        && matches!(object.get_own_property(key), Some(desc) if desc.enumerable())
+                })
+                .map(|key| RcString::from(key.to_string()))
+                .collect()
+        };
+
+        let holder = Value::from(object.clone());
+        let mut partial = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(value) = Self::serialize_property(state, key.clone(), &holder, context)? {
+                let separator = if state.gap.is_empty() { ":" } else { ": " };
+                partial.push(format!("{}{}{}", quote_json_string(&key), separator, value));
+            }
         }
+
+        let result = state.join(&partial, '{', '}', &stepback);
+        state.stack.pop();
+        state.indent = stepback;
+        Ok(result)
     }
+
+    /// Abstract operation `SerializeJSONArray( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
+    fn serialize_array(
+        state: &mut SerializeState,
+        object: &GcObject,
+        context: &mut Context,
+    ) -> Result<StdString> {
+        state.enter(object, context)?;
+        let stepback = state.indent.clone();
+        state.indent.push_str(&state.gap.clone());
+
+        let holder = Value::from(object.clone());
+        let length = holder
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        let mut partial = Vec::with_capacity(length);
+        for index in 0..length {
+            let key = RcString::from(index.to_string());
+            let value = Self::serialize_property(state, key, &holder, context)?;
+            partial.push(value.unwrap_or_else(|| "null".into()));
+        }
+
+        let result = state.join(&partial, '[', ']', &stepback);
+        state.stack.pop();
+        state.indent = stepback;
+        Ok(result)
+    }
+}
+
+/// The state shared by the serialization steps of `JSON.stringify`.
+#[derive(Debug, Default)]
+struct SerializeState {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<RcString>>,
+    stack: Vec<GcObject>,
+    indent: StdString,
+    gap: StdString,
+}
+
+impl SerializeState {
+    /// Pushes `object` onto the stack, throwing a `TypeError` if it is already being serialized.
+    fn enter(&mut self, object: &GcObject, context: &mut Context) -> Result<()> {
+        if self
+            .stack
+            .iter()
+            .any(|entry| GcObject::equals(entry, object))
+        {
+            return Err(context.construct_type_error("cyclic object value"));
+        }
+        self.stack.push(object.clone());
+        Ok(())
+    }
+
+    /// Joins the serialized members of an object or array, putting each member on its own
+    /// line when a gap is set.
+    fn join(&self, partial: &[StdString], open: char, close: char, stepback: &str) -> StdString {
+        if partial.is_empty() {
+            format!("{}{}", open, close)
+        } else if self.gap.is_empty() {
+            format!("{}{}{}", open, partial.join(","), close)
+        } else {
+            let separator = format!(",\n{}", self.indent);
+            format!(
+                "{}\n{}{}\n{}{}",
+                open,
+                self.indent,
+                partial.join(&separator),
+                stepback,
+                close
+            )
+        }
+    }
+}
+
+/// Abstract operation `QuoteJSONString( value )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-quotejsonstring
+fn quote_json_string(value: &str) -> StdString {
+    JSONValue::String(value.to_string()).to_string()
 }
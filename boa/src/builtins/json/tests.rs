@@ -207,6 +207,81 @@ fn json_stringify_fractional_numbers() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_replacer_array_whitelist() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: { a: 2, c: 3 }, c: 4 }, ['b', 'a'])"#,
+    );
+    let expected = forward(&mut context, r#"'{"b":{"a":2},"a":1}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_replacer_function_nested() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: { b: 1 } }, (key, value) => key === 'b' ? 2 : value)"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":{"b":2}}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_space_number() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: [1, {}] }, null, 2)"#,
+    );
+    let expected = forward(
+        &mut context,
+        r#"'{\n  "a": 1,\n  "b": [\n    1,\n    {}\n  ]\n}'"#,
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_space_string() {
+    let mut context = Context::new();
+    let actual = forward(&mut context, r#"JSON.stringify([1], null, '--')"#);
+    let expected = forward(&mut context, r#"'[\n--1\n]'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_to_json() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: { toJSON(key) { return 'to json ' + key; } } })"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":"to json a"}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_circular_reference() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        let object = {};
+        object.self = object;
+        let thrown;
+        try {
+            JSON.stringify(object);
+        } catch (err) {
+            thrown = err;
+        }
+        thrown instanceof TypeError
+        "#,
+    );
+    assert_eq!(actual, "true");
+}
+
 #[test]
 fn json_parse_array_with_reviver() {
     let mut context = Context::new();
@@ -264,6 +339,90 @@ fn json_parse_object_with_reviver() {
     assert_eq!(result, r#""{"firstname":"boa","lastname":"interpreter"}""#);
 }
 
+#[test]
+fn json_parse_reviver_doubles_numbers() {
+    let mut context = Context::new();
+    let init = r#"
+        var result = JSON.parse('{"a": 1, "b": [2, 3], "c": "d"}', function (key, value) {
+            return typeof value === 'number' ? value * 2 : value;
+        });
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result.a"), "2");
+    assert_eq!(forward(&mut context, "result.b[0]"), "4");
+    assert_eq!(forward(&mut context, "result.b[1]"), "6");
+    assert_eq!(forward(&mut context, "result.b.length"), "2");
+    assert_eq!(forward(&mut context, "result.c"), "\"d\"");
+}
+
+#[test]
+fn json_parse_reviver_deletes_undefined() {
+    let mut context = Context::new();
+    let init = r#"
+        var result = JSON.parse('{"a": 1, "b": 2}', function (key, value) {
+            return key === 'a' ? undefined : value;
+        });
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result.hasOwnProperty('a')"), "false");
+    assert_eq!(forward(&mut context, "result.b"), "2");
+}
+
+#[test]
+fn json_parse_reviver_order() {
+    let mut context = Context::new();
+    let init = r#"
+        var keys = [];
+        var holder;
+        JSON.parse('{"a": {"b": {"c": 1}}, "d": [1, [2]]}', function (key, value) {
+            keys.push(key);
+            if (key === '') {
+                holder = this;
+            }
+            return value;
+        });
+        var objectKeys = keys.filter(function (key) { return 'abc'.includes(key) && key !== ''; });
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "objectKeys.join()"), "\"c,b,a\"");
+    assert_eq!(
+        forward(&mut context, "keys.indexOf('0') < keys.indexOf('d')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "keys.indexOf('1') < keys.indexOf('d')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "keys.length"), "8");
+    assert_eq!(forward(&mut context, "keys[keys.length - 1]"), "\"\"");
+    assert_eq!(forward(&mut context, "typeof holder"), "\"object\"");
+    assert_eq!(forward(&mut context, "typeof holder['']"), "\"object\"");
+}
+
+#[test]
+fn json_parse_reviver_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        try {
+            JSON.parse('{"a": {"b": 1}}', function (key, value) {
+                if (key === 'b') {
+                    throw new Error('reviver');
+                }
+                return value;
+            });
+        } catch (e) {
+            result = e.message;
+        }
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result"), "\"reviver\"");
+}
+
 #[test]
 fn json_parse_sets_prototypes() {
     let mut context = Context::new();
@@ -323,9 +482,50 @@ fn json_fields_should_be_enumerable() {
     assert_eq!(actual_array_index, expected);
 }
 
+#[test]
+fn json_stringify_key_order() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        r#"var obj = { b: 1, "2": 2, a: 3, "1": 4 }; obj.c = 5;"#,
+    );
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(obj)"),
+        r#""{"1":4,"2":2,"b":1,"a":3,"c":5}""#
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        r#""1,2,b,a,c""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify(new String('ab')) === '"ab"'"#
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn json_parse_with_no_args_throws_syntax_error() {
     let mut context = Context::new();
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_stringify_calls_getters() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var obj = {};
+        Object.defineProperty(obj, 'a', { get: function() { return 1; }, enumerable: true });
+        var arr = [0];
+        Object.defineProperty(arr, '0', { get: function() { return 'b'; }, enumerable: true });
+        "#,
+    );
+    assert_eq!(forward(&mut context, "JSON.stringify(obj)"), r#""{"a":1}""#);
+    assert_eq!(forward(&mut context, "JSON.stringify(arr)"), r#""["b"]""#);
+}
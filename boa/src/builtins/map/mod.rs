@@ -1,7 +1,7 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{iterable::get_iterator, BuiltIn},
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
@@ -87,37 +87,36 @@ impl Map {
         // to its Javascript Identifier (global constructor method name)
 
         // add our arguments in
-        let data = match args.len() {
-            0 => OrderedMap::new(),
-            _ => match &args[0] {
-                Value::Object(object) => {
-                    let object = object.borrow();
-                    if let Some(map) = object.as_map_ref().cloned() {
-                        map
-                    } else if object.is_array() {
-                        let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length").to_integer(context)? as i32;
-                        for i in 0..len {
-                            let val = &args[0].get_field(i.to_string());
-                            let (key, value) = Self::get_key_value(val).ok_or_else(|| {
-                                context.construct_type_error(
-                                    "iterable for Map should have array-like objects",
-                                )
-                            })?;
-                            map.insert(key, value);
-                        }
-                        map
+        let data = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => OrderedMap::new(),
+            Some(iterable) => {
+                let mut map = OrderedMap::new();
+                let iterator = get_iterator(context, iterable.clone())?;
+                loop {
+                    let next = iterator.next(context)?;
+                    if next.is_done() {
+                        break;
+                    }
+
+                    let entry = next.value();
+                    let entry = if entry.is_object() {
+                        entry
+                            .get_field_with_accessors(0, context)
+                            .and_then(|key| Ok((key, entry.get_field_with_accessors(1, context)?)))
                     } else {
-                        return Err(context.construct_type_error(
+                        Err(context.construct_type_error(
                             "iterable for Map should have array-like objects",
-                        ));
+                        ))
+                    };
+                    match entry {
+                        Ok((key, value)) => {
+                            map.insert(Self::normalize_key(key), value);
+                        }
+                        Err(error) => return iterator.close(Err(error), context),
                     }
                 }
-                _ => {
-                    return Err(context
-                        .construct_type_error("iterable for Map should have array-like objects"))
-                }
-            },
+                map
+            }
         };
 
         // finally create length property
@@ -186,7 +185,7 @@ impl Map {
         let size = if let Value::Object(ref object) = this {
             let mut object = object.borrow_mut();
             if let Some(map) = object.as_map_mut() {
-                map.insert(key, value);
+                map.insert(Self::normalize_key(key), value);
                 map.len()
             } else {
                 return Err(context.construct_type_error("'this' is not a Map"));
@@ -353,18 +352,18 @@ impl Map {
         MapIterator::create_map_iterator(context, this.clone(), MapIterationKind::Value)
     }
 
-    /// Helper function to get a key-value pair from an array.
-    fn get_key_value(value: &Value) -> Option<(Value, Value)> {
-        if let Value::Object(object) = value {
-            if object.is_array() {
-                let (key, value) = match value.get_field("length").as_number().unwrap() as i32 {
-                    0 => (Value::Undefined, Value::Undefined),
-                    1 => (value.get_field("0"), Value::Undefined),
-                    _ => (value.get_field("0"), value.get_field("1")),
-                };
-                return Some((key, value));
+    /// Helper function to turn a `-0` key into `+0`, so it is stored and iterated as `+0`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.set
+    pub(crate) fn normalize_key(key: Value) -> Value {
+        match key {
+            Value::Rational(number) if number == 0.0 && number.is_sign_negative() => {
+                Value::Integer(0)
             }
+            key => key,
         }
-        None
     }
 }
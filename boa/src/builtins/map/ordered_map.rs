@@ -1,5 +1,8 @@
 use crate::gc::{custom_trace, Finalize, Trace};
-use indexmap::{map::IntoIter, map::Iter, map::IterMut, IndexMap};
+use indexmap::{
+    map::{IntoIter, Iter, IterMut, Keys, Values},
+    IndexMap,
+};
 use std::{
     collections::hash_map::RandomState,
     fmt::Debug,
@@ -22,15 +25,15 @@ unsafe impl<K: Eq + Hash + Trace, V: Trace, S: BuildHasher> Trace for OrderedMap
     });
 }
 
-impl<K: Hash + Eq + Debug, V: Debug> Debug for OrderedMap<K, V> {
+impl<K: Hash + Eq + Debug, V: Debug, S> Debug for OrderedMap<K, V, S> {
     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         self.0.fmt(formatter)
     }
 }
 
-impl<K: Hash + Eq, V> Default for OrderedMap<K, V> {
+impl<K: Hash + Eq, V, S: Default> Default for OrderedMap<K, V, S> {
     fn default() -> Self {
-        Self::new()
+        OrderedMap(IndexMap::default())
     }
 }
 
@@ -45,7 +48,13 @@ where
     pub fn with_capacity(capacity: usize) -> Self {
         OrderedMap(IndexMap::with_capacity(capacity))
     }
+}
 
+impl<K, V, S> OrderedMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
     /// Return the number of key-value pairs in the map.
     ///
     /// Computes in **O(1)** time.
@@ -108,6 +117,16 @@ where
         self.0.iter()
     }
 
+    /// Return an iterator over the keys of the map, in their order
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.0.keys()
+    }
+
+    /// Return an iterator over the values of the map, in their order
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.0.values()
+    }
+
     /// Return `true` if an equivalent to `key` exists in the map.
     ///
     /// Computes in **O(1)** time (average).
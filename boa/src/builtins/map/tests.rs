@@ -354,3 +354,66 @@ fn not_a_function() {
         "\"TypeError: function object is not callable\""
     );
 }
+
+#[test]
+fn construct_from_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[1, "a"]]);
+        let copy = new Map(map.entries());
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "map.get(1)");
+    assert_eq!(result, "\"a\"");
+    let result = forward(&mut context, "copy.get(1)");
+    assert_eq!(result, "\"a\"");
+    let result = forward(
+        &mut context,
+        "try { new Map([1]) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn same_value_zero_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map();
+        map.set(NaN, "nan");
+        map.set(-0, "zero");
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "map.get(NaN)");
+    assert_eq!(result, "\"nan\"");
+    let result = forward(&mut context, "map.get(0)");
+    assert_eq!(result, "\"zero\"");
+    let result = forward(&mut context, "map.get(0.5 - 0.5)");
+    assert_eq!(result, "\"zero\"");
+    let result = forward(&mut context, "1 / map.keys().next().value");
+    assert_eq!(result, "NaN");
+    let result = forward(&mut context, "1 / [...map.keys()][1]");
+    assert_eq!(result, "Infinity");
+}
+
+#[test]
+fn construct_closes_iterator_on_invalid_entry() {
+    let mut context = Context::new();
+    let init = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function() {
+            return {
+                next() { return { value: 1, done: false }; },
+                return() { closed = true; return {}; }
+            };
+        };
+        "#;
+    forward(&mut context, init);
+    let result = forward(
+        &mut context,
+        "try { new Map(iterable) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "closed");
+    assert_eq!(result, "true");
+}
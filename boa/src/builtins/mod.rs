@@ -1,6 +1,7 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
@@ -17,13 +18,21 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
+pub mod reflect;
 pub mod regexp;
+pub mod set;
 pub mod string;
 pub mod symbol;
+pub mod typed_array;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
     bigint::BigInt,
     boolean::Boolean,
     date::Date,
@@ -38,10 +47,21 @@ pub(crate) use self::{
     nan::NaN,
     number::Number,
     object::Object as BuiltInObjectObject,
-    regexp::RegExp,
+    promise::Promise,
+    proxy::Proxy,
+    reflect::Reflect,
+    regexp::{regexp_string_iterator::RegExpStringIterator, RegExp},
+    set::set_iterator::SetIterator,
+    set::Set,
     string::String,
     symbol::Symbol,
+    typed_array::{
+        Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, Uint16Array, Uint32Array,
+        Uint8Array, Uint8ClampedArray,
+    },
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_set::WeakSet,
 };
 use crate::{
     property::{Attribute, DataDescriptor},
@@ -69,6 +89,7 @@ pub fn init(context: &mut Context) {
         BuiltInObjectObject::init,
         Math::init,
         Json::init,
+        Reflect::init,
         Array::init,
         BigInt::init,
         Boolean::init,
@@ -77,6 +98,21 @@ pub fn init(context: &mut Context) {
         Number::init,
         String::init,
         RegExp::init,
+        Set::init,
+        WeakMap::init,
+        WeakSet::init,
+        Promise::init,
+        ArrayBuffer::init,
+        Int8Array::init,
+        Uint8Array::init,
+        Uint8ClampedArray::init,
+        Int16Array::init,
+        Uint16Array::init,
+        Int32Array::init,
+        Uint32Array::init,
+        Float32Array::init,
+        Float64Array::init,
+        Proxy::init,
         Symbol::init,
         Error::init,
         RangeError::init,
@@ -15,7 +15,7 @@
 
 use super::function::make_builtin_fn;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{string::String as BuiltInString, BuiltIn},
     object::{ConstructorBuilder, ObjectData},
     property::Attribute,
     value::{AbstractRelation, Value},
@@ -235,14 +235,97 @@ impl Number {
     pub(crate) fn to_fixed(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         let this_num = Self::this_number_value(this, context)?;
         let precision = match args.get(0) {
-            Some(n) => match n.to_integer(context)? as i32 {
-                x if x > 0 => n.to_integer(context)? as usize,
-                _ => 0,
-            },
-            None => 0,
+            Some(n) => n.to_integer(context)?,
+            None => 0.0,
+        };
+        if !(0.0..=100.0).contains(&precision) {
+            return context
+                .throw_range_error("toFixed() digits argument must be between 0 and 100");
+        }
+        let precision = precision as usize;
+
+        if !this_num.is_finite() {
+            return Ok(Value::from(Self::to_native_string(this_num)));
+        }
+
+        // The sign is handled separately, so that `-0` is formatted as `0` and ties are rounded
+        // away from zero for negative numbers as well.
+        let sign = if this_num < 0.0 { "-" } else { "" };
+        let abs = this_num.abs();
+        if abs >= 1e21 {
+            return Ok(Value::from(format!(
+                "{}{}",
+                sign,
+                Self::to_native_string(abs)
+            )));
+        }
+
+        Ok(Value::from(format!(
+            "{}{}",
+            sign,
+            Self::num_to_fixed(abs, precision)
+        )))
+    }
+
+    /// Formats a non-negative finite number with exactly `precision` fractional digits.
+    ///
+    /// Rust rounds the exact value of the number to the nearest digit, breaking ties to even,
+    /// whereas the specification picks the larger candidate. A tie is only possible if the number
+    /// can be written exactly with one more digit that is a `5`, so that case is rounded up here.
+    fn num_to_fixed(n: f64, precision: usize) -> String {
+        let fixed = format!("{:.*}", precision, n);
+        if !Self::has_exact_decimal_digits(n, precision + 1) {
+            return fixed;
+        }
+
+        let longer = format!("{:.*}", precision + 1, n);
+        if !longer.ends_with('5') {
+            return fixed;
+        }
+
+        // Increment the digits before the trailing `5`, carrying over to the left.
+        let mut digits: Vec<u8> = longer[..longer.len() - 1].bytes().collect();
+        if digits.last() == Some(&b'.') {
+            digits.pop();
+        }
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            match *digit {
+                b'.' => continue,
+                b'9' => *digit = b'0',
+                _ => {
+                    *digit += 1;
+                    carry = false;
+                    break;
+                }
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+
+        String::from_utf8(digits).expect("digits are always ASCII")
+    }
+
+    /// Checks if the fractional part of a finite number has at most `digits` decimal digits.
+    ///
+    /// A number `m * 2^e` with an odd `m` and a negative `e` has exactly `-e` fractional
+    /// decimal digits.
+    fn has_exact_decimal_digits(n: f64, digits: usize) -> bool {
+        let bits = n.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), exponent - 1075)
         };
-        let this_fixed_num = format!("{:.*}", precision, this_num);
-        Ok(Value::from(this_fixed_num))
+        if mantissa == 0 {
+            return true;
+        }
+
+        let exponent = exponent + i64::from(mantissa.trailing_zeros());
+        exponent >= 0 || (-exponent as usize) <= digits
     }
 
     /// `Number.prototype.toLocaleString( [locales [, options]] )`
@@ -501,9 +584,10 @@ impl Number {
     ///
     /// Parses the given string as an integer using the given radix as a base.
     ///
-    /// An argument of type Number (i.e. Integer or Rational) is also accepted in place of string.
+    /// Leading whitespace is ignored and parsing stops at the first character that is not a valid
+    /// digit in the radix. A `0x` or `0X` prefix selects radix 16 when no radix (or zero) is given.
     ///
-    /// The radix must be an integer in the range [2, 36] inclusive.
+    /// The radix must be an integer in the range [2, 36] inclusive, otherwise `NaN` is returned.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -511,65 +595,65 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-parseint-string-radix
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseInt
-    pub(crate) fn parse_int(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let (Some(val), r) = (args.get(0), args.get(1)) {
-            let mut radix = if let Some(rx) = r {
-                if let Value::Integer(i) = rx {
-                    *i as u32
-                } else {
-                    // Handling a second argument that isn't an integer but was provided so cannot be defaulted.
-                    return Ok(Value::from(f64::NAN));
-                }
-            } else {
-                // No second argument provided therefore radix is unknown
-                0
-            };
+    pub(crate) fn parse_int(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let input = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let mut radix = args.get(1).cloned().unwrap_or_default().to_i32(context)?;
 
-            match val {
-                Value::String(s) => {
-                    // Attempt to infer radix from given string.
-
-                    if radix == 0 {
-                        if s.starts_with("0x") || s.starts_with("0X") {
-                            return if let Ok(i) = i32::from_str_radix(&s[2..], 16) {
-                                Ok(Value::integer(i))
-                            } else {
-                                // String can't be parsed.
-                                Ok(Value::from(f64::NAN))
-                            };
-                        } else {
-                            radix = 10
-                        };
-                    }
+        let mut string = input.trim_start_matches(BuiltInString::is_trimmable_whitespace);
 
-                    if let Ok(i) = i32::from_str_radix(s, radix) {
-                        Ok(Value::integer(i))
-                    } else {
-                        // String can't be parsed.
-                        Ok(Value::from(f64::NAN))
-                    }
-                }
-                Value::Integer(i) => Ok(Value::integer(*i)),
-                Value::Rational(f) => Ok(Value::integer(*f as i32)),
-                _ => {
-                    // Wrong argument type to parseInt.
-                    Ok(Value::from(f64::NAN))
-                }
+        let sign = if string.starts_with('-') { -1.0 } else { 1.0 };
+        if string.starts_with('-') || string.starts_with('+') {
+            string = &string[1..];
+        }
+
+        let mut strip_prefix = true;
+        if radix != 0 {
+            if !(2..=36).contains(&radix) {
+                return Ok(Value::from(f64::NAN));
+            }
+            if radix != 16 {
+                strip_prefix = false;
             }
         } else {
-            // Not enough arguments to parseInt.
-            Ok(Value::from(f64::NAN))
+            radix = 10;
         }
+
+        if strip_prefix && (string.starts_with("0x") || string.starts_with("0X")) {
+            string = &string[2..];
+            radix = 16;
+        }
+
+        let radix = radix as u32;
+        let end = string
+            .find(|c: char| !c.is_digit(radix))
+            .unwrap_or(string.len());
+        let digits = &string[..end];
+        if digits.is_empty() {
+            return Ok(Value::from(f64::NAN));
+        }
+
+        let number = if radix == 10 {
+            // Let the standard library handle rounding of long decimal strings.
+            digits.parse::<f64>().unwrap_or(f64::NAN)
+        } else {
+            digits.chars().fold(0.0, |acc, c| {
+                acc * f64::from(radix) + f64::from(c.to_digit(radix).unwrap_or(0))
+            })
+        };
+
+        Ok(Value::from(sign * number))
     }
 
     /// Builtin javascript 'parseFloat(str)' function.
     ///
     /// Parses the given string as a floating point value.
     ///
-    /// An argument of type Number (i.e. Integer or Rational) is also accepted in place of string.
-    ///
-    /// To improve performance an Integer type Number is returned in place of a Rational if the given
-    /// string can be parsed and stored as an Integer.
+    /// Leading whitespace is ignored and the longest prefix that forms a decimal literal
+    /// (including `Infinity` and exponent forms) is parsed. If no such prefix exists `NaN` is returned.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -577,32 +661,63 @@ impl Number {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-parsefloat-string
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/parseFloat
-    pub(crate) fn parse_float(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
-        if let Some(val) = args.get(0) {
-            match val {
-                Value::String(s) => {
-                    if let Ok(i) = s.parse::<i32>() {
-                        // Attempt to parse an integer first so that it can be stored as an integer
-                        // to improve performance
-                        Ok(Value::integer(i))
-                    } else if let Ok(f) = s.parse::<f64>() {
-                        Ok(Value::rational(f))
-                    } else {
-                        // String can't be parsed.
-                        Ok(Value::from(f64::NAN))
-                    }
-                }
-                Value::Integer(i) => Ok(Value::integer(*i)),
-                Value::Rational(f) => Ok(Value::rational(*f)),
-                _ => {
-                    // Wrong argument type to parseFloat.
-                    Ok(Value::from(f64::NAN))
-                }
+    pub(crate) fn parse_float(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let input = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let string = input.trim_start_matches(BuiltInString::is_trimmable_whitespace);
+
+        let bytes = string.as_bytes();
+        let mut end = 0;
+        if let Some(b'+') | Some(b'-') = bytes.first() {
+            end += 1;
+        }
+
+        if string[end..].starts_with("Infinity") {
+            let number = if string.starts_with('-') {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+            return Ok(Value::from(number));
+        }
+
+        let count_digits = |from: usize| {
+            bytes[from..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count()
+        };
+
+        let integer_digits = count_digits(end);
+        end += integer_digits;
+        let mut fraction_digits = 0;
+        if bytes.get(end) == Some(&b'.') {
+            fraction_digits = count_digits(end + 1);
+            if integer_digits > 0 || fraction_digits > 0 {
+                end += 1 + fraction_digits;
             }
-        } else {
-            // Not enough arguments to parseFloat.
-            Ok(Value::from(f64::NAN))
         }
+        if integer_digits == 0 && fraction_digits == 0 {
+            return Ok(Value::from(f64::NAN));
+        }
+
+        if let Some(b'e') | Some(b'E') = bytes.get(end) {
+            let mut exponent_start = end + 1;
+            if let Some(b'+') | Some(b'-') = bytes.get(exponent_start) {
+                exponent_start += 1;
+            }
+            let exponent_digits = count_digits(exponent_start);
+            if exponent_digits > 0 {
+                end = exponent_start + exponent_digits;
+            }
+        }
+
+        Ok(Value::from(
+            string[..end].parse::<f64>().unwrap_or(f64::NAN),
+        ))
     }
 
     /// Builtin javascript 'isFinite(number)' function.
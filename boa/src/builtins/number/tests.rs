@@ -100,6 +100,71 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_rounding() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "(1.005).toFixed(2)"), "\"1.00\"");
+    assert_eq!(forward(&mut context, "(1.45).toFixed(1)"), "\"1.4\"");
+    assert_eq!(forward(&mut context, "(1.25).toFixed(1)"), "\"1.3\"");
+    assert_eq!(forward(&mut context, "(0.5).toFixed(0)"), "\"1\"");
+    assert_eq!(forward(&mut context, "(2.5).toFixed(0)"), "\"3\"");
+    assert_eq!(forward(&mut context, "(-2.5).toFixed(0)"), "\"-3\"");
+    assert_eq!(forward(&mut context, "(9.995).toFixed(2)"), "\"9.99\"");
+    assert_eq!(forward(&mut context, "(99.5).toFixed(0)"), "\"100\"");
+    assert_eq!(forward(&mut context, "(0.125).toFixed(2)"), "\"0.13\"");
+}
+
+#[test]
+fn to_fixed_special_values() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "(0).toFixed(2) === '0.00'"), "true");
+    assert_eq!(forward(&mut context, "(-0).toFixed(2)"), "\"0.00\"");
+    assert_eq!(forward(&mut context, "(-1.5).toFixed(2)"), "\"-1.50\"");
+    assert_eq!(forward(&mut context, "NaN.toFixed(2)"), "\"NaN\"");
+    assert_eq!(forward(&mut context, "Infinity.toFixed(2)"), "\"Infinity\"");
+    assert_eq!(
+        forward(&mut context, "(-Infinity).toFixed(2)"),
+        "\"-Infinity\""
+    );
+    assert_eq!(forward(&mut context, "(1e21).toFixed(2)"), "\"1e+21\"");
+    assert_eq!(forward(&mut context, "(-1e21).toFixed(2)"), "\"-1e+21\"");
+}
+
+#[test]
+fn to_fixed_digits_range() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "(1).toFixed(100).length"), "102");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { (1).toFixed(101) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { (1).toFixed(-1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn to_fixed_on_numeric_literal() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "0 .toFixed(2)"), "\"0.00\"");
+    assert_eq!(forward(&mut context, "(0).toFixed(2)"), "\"0.00\"");
+    assert_eq!(
+        forward(&mut context, "0.toFixed(2)"),
+        "Uncaught \"SyntaxError\": \"Syntax Error: unexpected identifier after number; did you mean (0).toFixed()? at position: 1:3\""
+    );
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::new();
@@ -506,7 +571,7 @@ fn parse_int_float() {
 fn parse_int_float_str() {
     let mut context = Context::new();
 
-    assert_eq!(&forward(&mut context, "parseInt(\"100.5\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"100.5\")"), "100");
 }
 
 #[test]
@@ -594,6 +659,40 @@ fn parse_int_too_many_args() {
     assert_eq!(&forward(&mut context, "parseInt(\"100\", 10, 10)"), "100");
 }
 
+#[test]
+fn parse_int_prefix_and_radix() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 16)"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"-0xF\")"), "-15");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 2)"), "2");
+    assert_eq!(&forward(&mut context, "parseInt(\"z\", 36)"), "35");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", \"8\")"), "8");
+}
+
+#[test]
+fn parse_int_invalid_radix() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 37)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 0)"), "10");
+}
+
+#[test]
+fn parse_int_whitespace_and_trailing_characters() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"  \\n\\t42\")"), "42");
+    assert_eq!(&forward(&mut context, "parseInt(\"123abc\")"), "123");
+    assert_eq!(&forward(&mut context, "parseInt(\"12\", 2)"), "1");
+    assert_eq!(&forward(&mut context, "parseInt(\"2\", 2)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"-\")"), "NaN");
+}
+
 #[test]
 fn parse_float_simple() {
     let mut context = Context::new();
@@ -659,6 +758,43 @@ fn parse_float_too_many_args() {
     assert_eq!(&forward(&mut context, "parseFloat(\"100.5\", 10)"), "100.5");
 }
 
+#[test]
+fn parse_float_trailing_characters() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseFloat(\"3.14abc\")"), "3.14");
+    assert_eq!(&forward(&mut context, "parseFloat(\"  1.5\")"), "1.5");
+    assert_eq!(&forward(&mut context, "parseFloat(\".5\")"), "0.5");
+    assert_eq!(&forward(&mut context, "parseFloat(\"5.\")"), "5");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1.2.3\")"), "1.2");
+    assert_eq!(&forward(&mut context, "parseFloat(\".\")"), "NaN");
+}
+
+#[test]
+fn parse_float_infinity() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        &forward(&mut context, "parseFloat(\"Infinity\")"),
+        "Infinity"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseFloat(\"-Infinityxyz\")"),
+        "-Infinity"
+    );
+    assert_eq!(&forward(&mut context, "parseFloat(\"inf\")"), "NaN");
+}
+
+#[test]
+fn parse_float_exponent() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e3\")"), "1000");
+    assert_eq!(&forward(&mut context, "parseFloat(\"2.5E-1\")"), "0.25");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e\")"), "1");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e+\")"), "1");
+}
+
 #[test]
 fn global_is_finite() {
     let mut context = Context::new();
@@ -14,11 +14,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer},
+    builtins::{iterable::get_iterator, Array, BuiltIn},
+    object::{
+        ConstructorBuilder, GcObject, Object as BuiltinObject, ObjectData, ObjectInitializer,
+    },
     property::Attribute,
     property::DataDescriptor,
     property::PropertyDescriptor,
+    property::PropertyKey,
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -30,6 +33,14 @@ mod tests;
 #[derive(Debug, Clone, Copy)]
 pub struct Object;
 
+/// What `Object.keys`, `Object.values` and `Object.entries` list for each property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PropertyNameKind {
+    Key,
+    Value,
+    KeyAndValue,
+}
+
 impl BuiltIn for Object {
     const NAME: &'static str = "Object";
 
@@ -51,12 +62,17 @@ impl BuiltIn for Object {
         .method(Self::has_own_property, "hasOwnProperty", 0)
         .method(Self::property_is_enumerable, "propertyIsEnumerable", 0)
         .method(Self::to_string, "toString", 0)
+        .static_method(Self::assign, "assign", 2)
         .static_method(Self::create, "create", 2)
         .static_method(Self::set_prototype_of, "setPrototypeOf", 2)
         .static_method(Self::get_prototype_of, "getPrototypeOf", 1)
         .static_method(Self::define_property, "defineProperty", 3)
         .static_method(Self::define_properties, "defineProperties", 2)
         .static_method(Self::is, "is", 2)
+        .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::keys, "keys", 1)
+        .static_method(Self::values, "values", 1)
+        .static_method(Self::entries, "entries", 1)
         .static_method(
             Self::get_own_property_descriptor,
             "getOwnPropertyDescriptor",
@@ -87,6 +103,150 @@ impl Object {
         Ok(Value::new_object(Some(global)))
     }
 
+    /// `Object.assign( target, ...sources )`
+    ///
+    /// Copies the values of all enumerable own properties, with string or symbol keys, from
+    /// the source objects to the target object, and returns the target object.
+    ///
+    /// The values are read with the getters of the sources and written with the setters of the
+    /// target. `null` and `undefined` sources are skipped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.assign
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign
+    pub fn assign(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let to = Value::from(
+            args.get(0)
+                .cloned()
+                .unwrap_or_default()
+                .to_object(context)?,
+        );
+
+        for source in args.iter().skip(1) {
+            if source.is_null_or_undefined() {
+                continue;
+            }
+
+            let from = source.to_object(context)?;
+            for key in from.own_property_keys() {
+                // A getter of a previous property may have removed this one.
+                if matches!(from.get_own_property(&key), Some(desc) if desc.enumerable()) {
+                    let value =
+                        Value::from(from.clone()).get_field_with_accessors(key.clone(), context)?;
+                    to.set_field_with_accessors(key, value, context)?;
+                }
+            }
+        }
+
+        Ok(to)
+    }
+
+    /// `Object.keys( target )`
+    ///
+    /// Returns the keys of the enumerable own properties with string keys of an object.
+    ///
+    /// Integer keys come first in ascending order, followed by the other keys in the order they
+    /// were added.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        Self::enumerable_own_property_names(&object, PropertyNameKind::Key, context)
+    }
+
+    /// `Object.values( target )`
+    ///
+    /// Returns the values of the enumerable own properties with string keys of an object, in
+    /// the same order as `Object.keys`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+    pub fn values(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        Self::enumerable_own_property_names(&object, PropertyNameKind::Value, context)
+    }
+
+    /// `Object.entries( target )`
+    ///
+    /// Returns the `[key, value]` pairs of the enumerable own properties with string keys of an
+    /// object, in the same order as `Object.keys`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+        Self::enumerable_own_property_names(&object, PropertyNameKind::KeyAndValue, context)
+    }
+
+    /// The abstract operation `EnumerableOwnPropertyNames`, returning the result as an array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    fn enumerable_own_property_names(
+        object: &GcObject,
+        kind: PropertyNameKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut properties = Vec::new();
+        for key in object.own_property_keys() {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            // A getter of a previous property may have removed this one.
+            if !matches!(object.get_own_property(&key), Some(desc) if desc.enumerable()) {
+                continue;
+            }
+
+            let key_value = Value::from(key.to_string());
+            if kind == PropertyNameKind::Key {
+                properties.push(key_value);
+                continue;
+            }
+
+            let value = Value::from(object.clone()).get_field_with_accessors(key, context)?;
+            if kind == PropertyNameKind::Value {
+                properties.push(value);
+            } else {
+                let entry = Array::new_array(context)?;
+                Array::construct_array(&entry, &[key_value, value])?;
+                properties.push(entry);
+            }
+        }
+
+        let array = Array::new_array(context)?;
+        Array::construct_array(&array, &properties)
+    }
+
     /// `Object.create( proto, [propertiesObject] )`
     ///
     /// Creates a new object from the provided prototype.
@@ -198,27 +358,30 @@ impl Object {
     fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> Result<Value> {
         let mut descriptor = ObjectInitializer::new(context);
 
-        if let PropertyDescriptor::Data(data_desc) = &desc {
-            descriptor.property("value", data_desc.value(), Attribute::all());
-        }
-
-        if let PropertyDescriptor::Accessor(accessor_desc) = &desc {
-            if let Some(setter) = accessor_desc.setter() {
-                descriptor.property("set", Value::Object(setter.to_owned()), Attribute::all());
+        match &desc {
+            PropertyDescriptor::Data(data_desc) => {
+                descriptor
+                    .property("value", data_desc.value(), Attribute::all())
+                    .property(
+                        "writable",
+                        Value::from(data_desc.writable()),
+                        Attribute::all(),
+                    );
             }
-            if let Some(getter) = accessor_desc.getter() {
-                descriptor.property("get", Value::Object(getter.to_owned()), Attribute::all());
+            PropertyDescriptor::Accessor(accessor_desc) => {
+                let getter = accessor_desc
+                    .getter()
+                    .map_or_else(Value::undefined, |getter| getter.clone().into());
+                let setter = accessor_desc
+                    .setter()
+                    .map_or_else(Value::undefined, |setter| setter.clone().into());
+                descriptor
+                    .property("get", getter, Attribute::all())
+                    .property("set", setter, Attribute::all());
             }
         }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
-
         descriptor
-            .property("writable", Value::from(writable), Attribute::all())
             .property(
                 "enumerable",
                 Value::from(desc.enumerable()),
@@ -241,6 +404,41 @@ impl Object {
         Ok(same_value(&x, &y).into())
     }
 
+    /// `Object.fromEntries( iterable )`
+    ///
+    /// Creates a new object from an iterable of key-value pairs, such as an array of arrays
+    /// or a `Map`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+    pub fn from_entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let iterable = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let object = Value::new_object(Some(context.global_object()));
+
+        let iterator = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                break;
+            }
+
+            let entry = next.value();
+            if !entry.is_object() {
+                return context.throw_type_error("Iterator value is not an entry object");
+            }
+
+            let key = entry.get_field(0).to_property_key(context)?;
+            let value = entry.get_field(1);
+            object.set_property(key, DataDescriptor::new(value, Attribute::all()));
+        }
+
+        Ok(object)
+    }
+
     /// Get the `prototype` of an object.
     pub fn get_prototype_of(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
         let obj = args.get(0).expect("Cannot get object");
@@ -257,12 +455,27 @@ impl Object {
         Ok(obj)
     }
 
-    /// Define a property in an object
+    /// `Object.defineProperty( object, property, descriptor )`
+    ///
+    /// Defines a new property directly on an object, or modifies an existing property,
+    /// and returns the object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty
     pub fn define_property(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let obj = args.get(0).expect("Cannot get object");
+        let obj = args.get(0).cloned().unwrap_or_default();
+        let mut object = match obj {
+            Value::Object(ref object) => object.clone(),
+            _ => return context.throw_type_error("Object.defineProperty called on non-object"),
+        };
         let prop = args
             .get(1)
-            .expect("Cannot get object")
+            .cloned()
+            .unwrap_or_default()
             .to_property_key(context)?;
 
         let desc = if let Value::Object(ref object) = args.get(2).cloned().unwrap_or_default() {
@@ -270,8 +483,11 @@ impl Object {
         } else {
             return context.throw_type_error("Property description must be an object");
         };
-        obj.set_property(prop, desc);
-        Ok(Value::undefined())
+
+        if !object.define_own_partial_property(prop.clone(), desc) {
+            return context.throw_type_error(format!("cannot redefine property: {}", prop));
+        }
+        Ok(obj)
     }
 
     /// `Object.defineProperties( proto, [propertiesObject] )`
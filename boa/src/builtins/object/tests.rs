@@ -279,3 +279,406 @@ fn object_define_properties() {
 
     assert_eq!(forward(&mut context, "obj.p"), "42");
 }
+
+#[test]
+fn object_define_property_accessor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var store = 0;
+        const obj = {};
+        Object.defineProperty(obj, "p", {
+            get() { return store * 2; },
+            set(v) { store = v; },
+            enumerable: true
+        });
+        obj.p = 21;
+        const desc = Object.getOwnPropertyDescriptor(obj, "p");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.p"), "42");
+    assert_eq!(forward(&mut context, "typeof desc.get"), "\"function\"");
+    assert_eq!(forward(&mut context, "typeof desc.set"), "\"function\"");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut context, "desc.configurable"), "false");
+    assert_eq!(forward(&mut context, "'value' in desc"), "false");
+    assert_eq!(forward(&mut context, "'writable' in desc"), "false");
+}
+
+#[test]
+fn object_define_property_value_and_getter() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+    "#;
+    forward(&mut context, init);
+
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { value: 1, get() { return 2; } })",
+    );
+    assert!(result.contains("TypeError"));
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { writable: true, get: undefined })",
+    );
+    assert!(result.contains("TypeError"));
+    assert_eq!(forward(&mut context, "'p' in obj"), "false");
+}
+
+#[test]
+fn object_define_property_non_configurable() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", { value: 1 });
+    "#;
+    forward(&mut context, init);
+
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { value: 2 })",
+    );
+    assert!(result.contains("TypeError"));
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { get() { return 2; } })",
+    );
+    assert!(result.contains("TypeError"));
+    assert_eq!(forward(&mut context, "obj.p"), "1");
+
+    // Redefining it with the same attributes and value is allowed.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'p', { value: 1 }) === obj"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn object_define_properties_non_configurable() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", { value: 1 });
+    "#;
+    forward(&mut context, init);
+
+    let result = forward(
+        &mut context,
+        "Object.defineProperties(obj, { p: { value: 2, configurable: true } })",
+    );
+    assert!(result.contains("TypeError"));
+    assert_eq!(forward(&mut context, "obj.p"), "1");
+}
+
+#[test]
+fn object_define_property_converts_data_to_accessor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { p: 1 };
+        const result = Object.defineProperty(obj, "p", { get() { return 2; }, configurable: true });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.p"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'p').set"
+        ),
+        "undefined"
+    );
+}
+
+#[test]
+fn object_define_property_partial() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", { value: 1, writable: true, enumerable: true });
+        Object.defineProperty(obj, "p", { value: 2 });
+        const desc = Object.getOwnPropertyDescriptor(obj, "p");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "desc.value"), "2");
+    assert_eq!(forward(&mut context, "desc.writable"), "true");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut context, "desc.configurable"), "false");
+
+    forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { writable: false });",
+    );
+    assert_eq!(forward(&mut context, "obj.p"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'p').writable"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.defineProperty(obj, 'p', { value: 2, enumerable: true }) === obj"
+        ),
+        "true"
+    );
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { value: 3 })",
+    );
+    assert!(result.contains("TypeError"));
+}
+
+#[test]
+fn object_define_property_partial_accessor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+        Object.defineProperty(obj, "p", { get() { return 1; }, configurable: true });
+        Object.defineProperty(obj, "p", { set(v) {} });
+        Object.defineProperty(obj, "p", { enumerable: true });
+        const desc = Object.getOwnPropertyDescriptor(obj, "p");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "obj.p"), "1");
+    assert_eq!(forward(&mut context, "typeof desc.get"), "\"function\"");
+    assert_eq!(forward(&mut context, "typeof desc.set"), "\"function\"");
+    assert_eq!(forward(&mut context, "desc.enumerable"), "true");
+    assert_eq!(forward(&mut context, "desc.configurable"), "true");
+
+    forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { set: undefined, configurable: false });",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'p').set"
+        ),
+        "undefined"
+    );
+    let result = forward(
+        &mut context,
+        "Object.defineProperty(obj, 'p', { get: undefined })",
+    );
+    assert!(result.contains("TypeError"));
+    assert_eq!(forward(&mut context, "obj.p"), "1");
+}
+
+#[test]
+fn object_from_entries() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const fromArray = Object.fromEntries([["a", 1], ["b", 2]]);
+        const fromMap = Object.fromEntries(new Map([["c", 3], ["d", 4]]));
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "fromArray.a"), "1");
+    assert_eq!(forward(&mut context, "fromArray.b"), "2");
+    assert_eq!(forward(&mut context, "fromMap.c"), "3");
+    assert_eq!(forward(&mut context, "fromMap.d"), "4");
+}
+
+#[test]
+fn object_from_entries_non_object_entry() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let result;
+        try {
+            Object.fromEntries([1]);
+        } catch (e) {
+            result = e instanceof TypeError;
+        }
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
+#[test]
+fn object_assign_symbol_keys() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var sym = Symbol("s");
+        var source = { a: 1 };
+        source[sym] = 2;
+        var target = {};
+        var result = Object.assign(target, source);
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result === target"), "true");
+    assert_eq!(forward(&mut context, "target.a"), "1");
+    assert_eq!(forward(&mut context, "target[sym]"), "2");
+}
+
+#[test]
+fn object_assign_accessors() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var getterCalls = 0;
+        var source = { get value() { getterCalls += 1; return 42; } };
+        var received;
+        var target = { set value(v) { received = v; } };
+        Object.assign(target, source);
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "getterCalls"), "1");
+    assert_eq!(forward(&mut context, "received"), "42");
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof Object.getOwnPropertyDescriptor(target, 'value').set"
+        ),
+        "\"function\""
+    );
+}
+
+#[test]
+fn object_assign_setter_throws() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { set a(v) { throw new RangeError("no"); } };
+        var result;
+        try {
+            Object.assign(target, { a: 1 });
+        } catch (e) {
+            result = e instanceof RangeError;
+        }
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
+#[test]
+fn object_assign_multiple_sources() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var hidden = {};
+        Object.defineProperty(hidden, "c", { value: 5, enumerable: false });
+        var target = Object.assign({ a: 0 }, { a: 1, b: 1 }, null, undefined, { b: 2 }, hidden);
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "target.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "target.hasOwnProperty('c')"), "false");
+    assert_eq!(
+        forward(&mut context, "typeof Object.assign(1)"),
+        "\"object\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.assign(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn object_keys_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var obj = { b: 1, "2": 2, a: 3, "1": 4, "10": 5 };
+        obj[Symbol("s")] = 6;
+        obj.c = 7;
+        Object.defineProperty(obj, "hidden", { value: 8, enumerable: false });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"1,2,10,b,a,c\""
+    );
+    assert_eq!(forward(&mut context, "Object.keys('ab').join()"), "\"0,1\"");
+}
+
+#[test]
+fn string_object_own_properties() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var s = new String('ab'); s[5] = 'x'; s.c = 'y';",
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(s).join()"),
+        "\"0,1,5,c\""
+    );
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(s).join()"),
+        "\"0,1,5,length,c\""
+    );
+    assert_eq!(
+        forward(&mut context, "s.propertyIsEnumerable('length')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "s.hasOwnProperty('1')"), "true");
+    assert_eq!(forward(&mut context, "s.hasOwnProperty('2')"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.values('ab').join()"),
+        "\"a,b\""
+    );
+}
+
+#[test]
+fn object_values_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var obj = { b: 1, "2": 2, a: 3, "1": 4, "10": 5 };
+        obj[Symbol("s")] = 6;
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(
+        forward(&mut context, "Object.values(obj).join()"),
+        "\"4,2,5,1,3\""
+    );
+}
+
+#[test]
+fn object_entries_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var obj = { b: 1, "2": 2, a: 3, "1": 4, "10": 5 };
+        obj[Symbol("s")] = 6;
+        delete obj.b;
+        obj.b = 7;
+        var entries = Object.entries(obj);
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "entries.length"), "5");
+    assert_eq!(
+        forward(&mut context, "entries.map(e => e[0]).join()"),
+        "\"1,2,10,a,b\""
+    );
+    assert_eq!(
+        forward(&mut context, "entries.map(e => e[1]).join()"),
+        "\"4,2,5,3,7\""
+    );
+}
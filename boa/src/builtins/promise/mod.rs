@@ -0,0 +1,825 @@
+//! This module implements the global `Promise` object.
+//!
+//! Promise reactions are run as jobs of the job queue of the context, which is emptied after
+//! every script. Subclassing is not supported, so the promises created by the promise functions
+//! always use the `%Promise%` intrinsic.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    exec::InterpreterState,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// Whether a reaction handles the fulfillment or the rejection of a promise.
+#[derive(Debug, Clone, Copy, Trace, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+/// A promise and the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PromiseCapability {
+    promise: Value,
+    resolve: GcObject,
+    reject: GcObject,
+}
+
+/// A handler to run once a promise is fulfilled or rejected, with the capability of the promise
+/// that it settles in turn.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PromiseReaction {
+    capability: PromiseCapability,
+    reaction_type: ReactionType,
+    handler: Option<GcObject>,
+}
+
+/// The state shared by the resolving functions of a promise, captured by both of them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+#[derive(Debug, Trace, Finalize)]
+struct ResolvingFunctions {
+    promise: GcObject,
+    already_resolved: bool,
+}
+
+/// The values collected by `Promise.all` or `Promise.allSettled`, and the number of elements
+/// that are not settled yet.
+#[derive(Debug, Trace, Finalize)]
+struct CombinedValues {
+    values: Vec<Value>,
+    remaining: usize,
+    resolve: GcObject,
+}
+
+/// The state captured by the functions that settle one element of `Promise.all` or
+/// `Promise.allSettled`.
+///
+/// For `Promise.allSettled`, the fulfillment and rejection functions of an element share it.
+#[derive(Debug, Trace, Finalize)]
+struct CombinedElement {
+    index: usize,
+    already_called: bool,
+    combined: GcObject,
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let promise_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::then, "then", 2)
+            .method(Self::catch, "catch", 1)
+            .method(Self::finally, "finally", 1)
+            .static_method(Self::all, "all", 1)
+            .static_method(Self::all_settled, "allSettled", 1)
+            .static_method(Self::race, "race", 1)
+            .static_method(Self::reject, "reject", 1)
+            .static_method(Self::resolve, "resolve", 1)
+            .callable(false)
+            .build();
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the internal representation of a pending promise.
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+        }
+    }
+
+    /// Create a new promise
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let executor = match args.get(0) {
+            Some(Value::Object(executor)) if executor.is_callable() => executor.clone(),
+            _ => return context.throw_type_error("Promise executor is not a function"),
+        };
+
+        this.set_data(ObjectData::Promise(Self::new()));
+        let promise = this.as_object().expect("this is promise object");
+
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+        let completion = executor.call(
+            &Value::undefined(),
+            &[resolve.into(), reject.clone().into()],
+            context,
+        );
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        if let Err(error) = completion {
+            reject.call(&Value::undefined(), &[error], context)?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Creates a new pending promise object.
+    fn create_promise(context: &mut Context) -> GcObject {
+        let prototype = context
+            .global_object()
+            .get_field("Promise")
+            .get_field(PROTOTYPE);
+
+        let mut object = Object::create(prototype);
+        object.data = ObjectData::Promise(Self::new());
+        GcObject::new(object)
+    }
+
+    /// The abstract operation `NewPromiseCapability`, for the `%Promise%` intrinsic.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    fn new_promise_capability(context: &mut Context) -> PromiseCapability {
+        let promise = Self::create_promise(context);
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        PromiseCapability {
+            promise: promise.into(),
+            resolve,
+            reject,
+        }
+    }
+
+    /// The abstract operation `CreateResolvingFunctions`.
+    ///
+    /// Only the first call to either of the returned functions has an effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(
+        promise: &GcObject,
+        context: &mut Context,
+    ) -> (GcObject, GcObject) {
+        let state = Value::from(GcObject::new(Object::native_object(ResolvingFunctions {
+            promise: promise.clone(),
+            already_resolved: false,
+        })));
+
+        let resolve =
+            context.create_builtin_closure("", 1, state.clone(), |_, args, state, context| {
+                if let Some(promise) = Self::take_unresolved_promise(state) {
+                    let resolution = args.get(0).cloned().unwrap_or_else(Value::undefined);
+                    Self::resolve_promise(&promise, resolution, context);
+                }
+                Ok(Value::undefined())
+            });
+
+        let reject = context.create_builtin_closure("", 1, state, |_, args, state, context| {
+            if let Some(promise) = Self::take_unresolved_promise(state) {
+                let reason = args.get(0).cloned().unwrap_or_else(Value::undefined);
+                Self::reject_promise(&promise, reason, context);
+            }
+            Ok(Value::undefined())
+        });
+
+        (resolve, reject)
+    }
+
+    /// Marks the promise of the resolving functions as resolved, returning it only if it was not
+    /// resolved yet.
+    fn take_unresolved_promise(state: &Value) -> Option<GcObject> {
+        let mut object = state.as_object().expect("captures are an object");
+        let mut state = object
+            .downcast_mut::<ResolvingFunctions>()
+            .expect("captures are the state of resolving functions");
+        if state.already_resolved {
+            None
+        } else {
+            state.already_resolved = true;
+            Some(state.promise.clone())
+        }
+    }
+
+    /// Resolves the promise with the given resolution, adopting its state if it is a thenable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_promise(promise: &GcObject, resolution: Value, context: &mut Context) {
+        if let Value::Object(ref object) = resolution {
+            if GcObject::equals(object, promise) {
+                let error = context.construct_type_error("Chaining cycle detected for promise");
+                return Self::reject_promise(promise, error, context);
+            }
+
+            let then = match resolution.get_field_with_accessors("then", context) {
+                Ok(then) => then,
+                Err(error) => return Self::reject_promise(promise, error, context),
+            };
+
+            if let Value::Object(then) = then {
+                if then.is_callable() {
+                    let promise = promise.clone();
+                    context.enqueue_job(move |context| {
+                        Self::resolve_thenable_job(&promise, &resolution, &then, context)
+                    });
+                    return;
+                }
+            }
+        }
+
+        Self::fulfill_promise(promise, resolution, context);
+    }
+
+    /// The job that resolves a promise with a thenable, by calling its `then` method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn resolve_thenable_job(
+        promise: &GcObject,
+        thenable: &Value,
+        then: &GcObject,
+        context: &mut Context,
+    ) -> Result<()> {
+        let (resolve, reject) = Self::create_resolving_functions(promise, context);
+        if let Err(error) = then.call(thenable, &[resolve.into(), reject.clone().into()], context) {
+            reject.call(&Value::undefined(), &[error], context)?;
+        }
+
+        Ok(())
+    }
+
+    /// The abstract operation `FulfillPromise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &GcObject, value: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("object is a promise");
+            promise.state = PromiseState::Fulfilled(value.clone());
+            promise.reject_reactions.clear();
+            std::mem::take(&mut promise.fulfill_reactions)
+        };
+
+        Self::trigger_reactions(reactions, &value, context);
+    }
+
+    /// The abstract operation `RejectPromise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &GcObject, reason: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object.as_promise_mut().expect("object is a promise");
+            promise.state = PromiseState::Rejected(reason.clone());
+            promise.fulfill_reactions.clear();
+            std::mem::take(&mut promise.reject_reactions)
+        };
+
+        Self::trigger_reactions(reactions, &reason, context);
+    }
+
+    /// The abstract operation `TriggerPromiseReactions`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_reactions(reactions: Vec<PromiseReaction>, argument: &Value, context: &mut Context) {
+        for reaction in reactions {
+            let argument = argument.clone();
+            context.enqueue_job(move |context| Self::reaction_job(reaction, argument, context));
+        }
+    }
+
+    /// The job that runs the handler of a reaction, and settles the promise of the reaction with
+    /// its result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn reaction_job(
+        reaction: PromiseReaction,
+        argument: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let handler_result = match (reaction.handler, reaction.reaction_type) {
+            (Some(handler), _) => handler.call(&Value::undefined(), &[argument], context),
+            (None, ReactionType::Fulfill) => Ok(argument),
+            (None, ReactionType::Reject) => Err(argument),
+        };
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        let capability = reaction.capability;
+        match handler_result {
+            Ok(value) => capability
+                .resolve
+                .call(&Value::undefined(), &[value], context)?,
+            Err(reason) => capability
+                .reject
+                .call(&Value::undefined(), &[reason], context)?,
+        };
+
+        Ok(())
+    }
+
+    /// The abstract operation `PerformPromiseThen`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    fn perform_then(
+        promise: &GcObject,
+        on_fulfilled: Option<&Value>,
+        on_rejected: Option<&Value>,
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) {
+        let handler = |value: Option<&Value>| match value {
+            Some(Value::Object(handler)) if handler.is_callable() => Some(handler.clone()),
+            _ => None,
+        };
+        let fulfill_reaction = PromiseReaction {
+            capability: capability.clone(),
+            reaction_type: ReactionType::Fulfill,
+            handler: handler(on_fulfilled),
+        };
+        let reject_reaction = PromiseReaction {
+            capability,
+            reaction_type: ReactionType::Reject,
+            handler: handler(on_rejected),
+        };
+
+        let mut object = promise.borrow_mut();
+        let promise = object.as_promise_mut().expect("object is a promise");
+        match promise.state.clone() {
+            PromiseState::Pending => {
+                promise.fulfill_reactions.push(fulfill_reaction);
+                promise.reject_reactions.push(reject_reaction);
+            }
+            PromiseState::Fulfilled(value) => {
+                drop(object);
+                context.enqueue_job(move |context| {
+                    Self::reaction_job(fulfill_reaction, value, context)
+                });
+            }
+            PromiseState::Rejected(reason) => {
+                drop(object);
+                context.enqueue_job(move |context| {
+                    Self::reaction_job(reject_reaction, reason, context)
+                });
+            }
+        }
+    }
+
+    /// The abstract operation `PromiseResolve`, for the `%Promise%` intrinsic.
+    ///
+    /// Returns the value itself if it is already a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    fn promise_resolve(value: Value, context: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = value {
+            if object.borrow().is_promise() {
+                return Ok(value);
+            }
+        }
+
+        let capability = Self::new_promise_capability(context);
+        capability
+            .resolve
+            .call(&Value::undefined(), &[value], context)?;
+        Ok(capability.promise)
+    }
+
+    /// Calls the `then` method of the value with the given arguments.
+    fn invoke_then(value: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let then = value.get_field_with_accessors("then", context)?;
+        context.call(&then, value, args)
+    }
+
+    /// `Promise.prototype.then( onFulfilled, onRejected )`
+    ///
+    /// This method registers the handlers to call once the promise is fulfilled or rejected.
+    /// Returns a new promise, which is resolved with the result of the handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let promise = match this {
+            Value::Object(object) if object.borrow().is_promise() => object.clone(),
+            _ => return context.throw_type_error("'this' is not a Promise"),
+        };
+
+        let capability = Self::new_promise_capability(context);
+        let derived_promise = capability.promise.clone();
+        Self::perform_then(&promise, args.get(0), args.get(1), capability, context);
+
+        Ok(derived_promise)
+    }
+
+    /// `Promise.prototype.catch( onRejected )`
+    ///
+    /// This method registers the handler to call once the promise is rejected, like
+    /// `promise.then(undefined, onRejected)`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let on_rejected = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        Self::invoke_then(this, &[Value::undefined(), on_rejected], context)
+    }
+
+    /// `Promise.prototype.finally( onFinally )`
+    ///
+    /// This method registers the handler to call once the promise is settled, either way. The
+    /// returned promise is settled like this promise, once the promise returned by the handler,
+    /// if any, is fulfilled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return context.throw_type_error("'this' is not an object");
+        }
+
+        let on_finally = match args.get(0) {
+            Some(Value::Object(on_finally)) if on_finally.is_callable() => on_finally.clone(),
+            on_finally => {
+                let on_finally = on_finally.cloned().unwrap_or_else(Value::undefined);
+                return Self::invoke_then(this, &[on_finally.clone(), on_finally], context);
+            }
+        };
+
+        let then_finally = context.create_builtin_closure(
+            "",
+            1,
+            on_finally.clone(),
+            |_, args, on_finally, context| {
+                let value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+                let result = context.call(on_finally, &Value::undefined(), &[])?;
+                let promise = Self::promise_resolve(result, context)?;
+                let value_thunk = context
+                    .create_builtin_closure("", 0, value, |_, _, value, _| Ok(value.clone()));
+                Self::invoke_then(&promise, &[value_thunk.into()], context)
+            },
+        );
+
+        let catch_finally =
+            context.create_builtin_closure("", 1, on_finally, |_, args, on_finally, context| {
+                let reason = args.get(0).cloned().unwrap_or_else(Value::undefined);
+                let result = context.call(on_finally, &Value::undefined(), &[])?;
+                let promise = Self::promise_resolve(result, context)?;
+                let thrower = context
+                    .create_builtin_closure("", 0, reason, |_, _, reason, _| Err(reason.clone()));
+                Self::invoke_then(&promise, &[thrower.into()], context)
+            });
+
+        Self::invoke_then(this, &[then_finally.into(), catch_finally.into()], context)
+    }
+
+    /// `Promise.resolve( value )`
+    ///
+    /// This method returns a promise resolved with the given value, or the value itself if it is
+    /// a promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        Self::promise_resolve(value, context)
+    }
+
+    /// `Promise.reject( reason )`
+    ///
+    /// This method returns a promise rejected with the given reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_else(Value::undefined);
+
+        let capability = Self::new_promise_capability(context);
+        capability
+            .reject
+            .call(&Value::undefined(), &[reason], context)?;
+        Ok(capability.promise)
+    }
+
+    /// Runs a promise combinator over the values of the iterable, rejecting the promise of the
+    /// capability if the iteration fails.
+    fn combine<F>(args: &[Value], context: &mut Context, perform: F) -> Result<Value>
+    where
+        F: FnOnce(&IteratorRecord, &PromiseCapability, &mut Context) -> Result<()>,
+    {
+        let capability = Self::new_promise_capability(context);
+        let iterable = args.get(0).cloned().unwrap_or_else(Value::undefined);
+
+        let result = get_iterator(context, iterable)
+            .and_then(|iterator| perform(&iterator, &capability, context));
+        if let Err(error) = result {
+            capability
+                .reject
+                .call(&Value::undefined(), &[error], context)?;
+        }
+
+        Ok(capability.promise)
+    }
+
+    /// `Promise.all( iterable )`
+    ///
+    /// This method returns a promise fulfilled with an array of the fulfillment values of the
+    /// promises of the iterable, or rejected with the reason of the first one that is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(args, context, |iterator, capability, context| {
+            let combined = Self::create_combined_values(capability);
+
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+
+                let element = Self::create_combined_element(&combined);
+                let next_promise = Self::promise_resolve(next.value(), context)?;
+
+                let on_fulfilled =
+                    context.create_builtin_closure("", 1, element, |_, args, element, context| {
+                        let value = args.get(0).cloned().unwrap_or_default();
+                        Self::resolve_element(element, value, context)
+                    });
+
+                Self::invoke_then(
+                    &next_promise,
+                    &[on_fulfilled.into(), capability.reject.clone().into()],
+                    context,
+                )?;
+            }
+
+            Self::finish_combined_values(&combined, context)
+        })
+    }
+
+    /// `Promise.allSettled( iterable )`
+    ///
+    /// This method returns a promise fulfilled with an array of objects describing the outcome
+    /// of each promise of the iterable, once they are all settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(args, context, |iterator, capability, context| {
+            let combined = Self::create_combined_values(capability);
+
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+
+                let element = Self::create_combined_element(&combined);
+                let next_promise = Self::promise_resolve(next.value(), context)?;
+
+                let on_fulfilled = context.create_builtin_closure(
+                    "",
+                    1,
+                    element.clone(),
+                    |_, args, element, context| {
+                        let outcome = Value::new_object(Some(context.global_object()));
+                        outcome.set_field("status", "fulfilled");
+                        outcome.set_field("value", args.get(0).cloned().unwrap_or_default());
+                        Self::resolve_element(element, outcome, context)
+                    },
+                );
+                let on_rejected =
+                    context.create_builtin_closure("", 1, element, |_, args, element, context| {
+                        let outcome = Value::new_object(Some(context.global_object()));
+                        outcome.set_field("status", "rejected");
+                        outcome.set_field("reason", args.get(0).cloned().unwrap_or_default());
+                        Self::resolve_element(element, outcome, context)
+                    });
+
+                Self::invoke_then(
+                    &next_promise,
+                    &[on_fulfilled.into(), on_rejected.into()],
+                    context,
+                )?;
+            }
+
+            Self::finish_combined_values(&combined, context)
+        })
+    }
+
+    /// Creates the values collected by `Promise.all` or `Promise.allSettled`, which resolve the
+    /// promise of the capability once complete.
+    ///
+    /// The count of remaining elements starts at one, so the promise is not resolved before all
+    /// the elements of the iterable have been added.
+    fn create_combined_values(capability: &PromiseCapability) -> GcObject {
+        GcObject::new(Object::native_object(CombinedValues {
+            values: Vec::new(),
+            remaining: 1,
+            resolve: capability.resolve.clone(),
+        }))
+    }
+
+    /// Adds an element to the values collected by `Promise.all` or `Promise.allSettled`, and
+    /// returns the state to capture in the functions that settle it.
+    fn create_combined_element(combined: &GcObject) -> Value {
+        let mut object = combined.clone();
+        let mut values = object
+            .downcast_mut::<CombinedValues>()
+            .expect("object is combined values");
+        values.values.push(Value::undefined());
+        values.remaining += 1;
+
+        GcObject::new(Object::native_object(CombinedElement {
+            index: values.values.len() - 1,
+            already_called: false,
+            combined: combined.clone(),
+        }))
+        .into()
+    }
+
+    /// Stores the value of a settled element of `Promise.all` or `Promise.allSettled`, unless
+    /// it was already settled.
+    fn resolve_element(element: &Value, value: Value, context: &mut Context) -> Result<Value> {
+        let mut object = element.as_object().expect("captures are an object");
+        let combined = {
+            let mut element = object
+                .downcast_mut::<CombinedElement>()
+                .expect("captures are a combined element");
+            if element.already_called {
+                return Ok(Value::undefined());
+            }
+            element.already_called = true;
+
+            let mut combined = element.combined.clone();
+            combined
+                .downcast_mut::<CombinedValues>()
+                .expect("object is combined values")
+                .values[element.index] = value;
+            element.combined.clone()
+        };
+
+        Self::finish_combined_values(&combined, context)?;
+        Ok(Value::undefined())
+    }
+
+    /// Counts down the elements of `Promise.all` or `Promise.allSettled` that are not settled
+    /// yet, and resolves the combined promise with the values once there are none left.
+    fn finish_combined_values(combined: &GcObject, context: &mut Context) -> Result<()> {
+        let (values, resolve) = {
+            let mut object = combined.clone();
+            let mut combined = object
+                .downcast_mut::<CombinedValues>()
+                .expect("object is combined values");
+            combined.remaining -= 1;
+            if combined.remaining > 0 {
+                return Ok(());
+            }
+            (combined.values.clone(), combined.resolve.clone())
+        };
+
+        let array = Array::construct_array(&Array::new_array(context)?, &values)?;
+        resolve.call(&Value::undefined(), &[array], context)?;
+        Ok(())
+    }
+
+    /// `Promise.race( iterable )`
+    ///
+    /// This method returns a promise settled like the first promise of the iterable that is
+    /// settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(args, context, |iterator, capability, context| loop {
+            let next = iterator.next(context)?;
+            if next.is_done() {
+                return Ok(());
+            }
+
+            let next_promise = Self::promise_resolve(next.value(), context)?;
+            Self::invoke_then(
+                &next_promise,
+                &[
+                    capability.resolve.clone().into(),
+                    capability.reject.clone().into(),
+                ],
+                context,
+            )?;
+        })
+    }
+}
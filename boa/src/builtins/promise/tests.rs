@@ -0,0 +1,179 @@
+use crate::{forward, gc::force_collect, Context};
+
+#[test]
+fn chained_then() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        new Promise(function(resolve) { resolve(1); })
+            .then(function(value) { return value + 1; })
+            .then(function(value) { return value * 10; })
+            .then(function(value) { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "20");
+}
+
+#[test]
+fn rejection_propagates_to_catch() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        new Promise(function(resolve, reject) { reject("boom"); })
+            .then(function(value) { log.push("then"); })
+            .catch(function(reason) { log.push("catch " + reason); });
+        Promise.resolve(1)
+            .then(function(value) { throw "thrown"; })
+            .catch(function(reason) { log.push("catch " + reason); });
+        new Promise(function() { throw "executor"; })
+            .catch(function(reason) { log.push("catch " + reason); });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"catch executor,catch boom,catch thrown\""
+    );
+}
+
+#[test]
+fn then_runs_after_synchronous_code() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var promise = new Promise(function(resolve) {
+            log.push("executor");
+            resolve();
+        });
+        promise.then(function() { log.push("then"); });
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"executor,sync,then\""
+    );
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var thenable = {
+            then: function(resolve) { resolve(42); }
+        };
+        new Promise(function(resolve) { resolve(thenable); })
+            .then(function(value) { result = value; });
+        var rejected;
+        Promise.resolve(Promise.reject("nope"))
+            .catch(function(reason) { rejected = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "42");
+    assert_eq!(forward(&mut context, "rejected"), "\"nope\"");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let mut context = Context::new();
+    let init = r#"
+        var error;
+        var resolveSelf;
+        var promise = new Promise(function(resolve) { resolveSelf = resolve; });
+        resolveSelf(promise);
+        promise.catch(function(reason) { error = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+}
+
+#[test]
+fn finally() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1)
+            .finally(function() { log.push("finally"); return 2; })
+            .then(function(value) { log.push("then " + value); });
+        Promise.reject(3)
+            .finally(function() { log.push("finally"); })
+            .catch(function(reason) { log.push("catch " + reason); });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"finally,finally,then 1,catch 3\""
+    );
+}
+
+#[test]
+fn all() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var rejected;
+        Promise.all([1, Promise.resolve(2), new Promise(function(resolve) { resolve(3); })])
+            .then(function(values) { result = values.join(); });
+        Promise.all([1, Promise.reject("nope")])
+            .catch(function(reason) { rejected = reason; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "rejected"), "\"nope\"");
+}
+
+#[test]
+fn all_settled() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.allSettled([Promise.resolve(1), Promise.reject(2)])
+            .then(function(outcomes) { result = outcomes; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.length"), "2");
+    assert_eq!(forward(&mut context, "result[0].status"), "\"fulfilled\"");
+    assert_eq!(forward(&mut context, "result[0].value"), "1");
+    assert_eq!(forward(&mut context, "result[1].status"), "\"rejected\"");
+    assert_eq!(forward(&mut context, "result[1].reason"), "2");
+}
+
+#[test]
+fn race() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.race([new Promise(function() {}), Promise.resolve("first")])
+            .then(function(value) { result = value; });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"first\"");
+}
+
+#[test]
+fn executor_not_callable() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Promise(1); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn resolving_functions_survive_collection() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var resolveLater;
+        var all = Promise.all([new Promise(function(resolve) { resolveLater = resolve; }), 2]);
+        all.then(function(values) { result = values.join(); });
+        "#;
+    forward(&mut context, init);
+    force_collect();
+    forward(&mut context, "resolveLater(1)");
+    force_collect();
+    assert_eq!(forward(&mut context, "result"), "\"1,2\"");
+}
@@ -0,0 +1,298 @@
+//! This module implements the global `Proxy` object.
+//!
+//! A proxy wraps a target object, and lets a handler object intercept the operations done on it
+//! through trap functions. Only the `get`, `set`, `has` and `deleteProperty` traps are supported,
+//! and operations without a trap are forwarded to the target. A proxy created with
+//! `Proxy.revocable` can be revoked, after which every trapped operation throws a `TypeError`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, ObjectData},
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    value::same_value,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal representation of a `Proxy` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    target: GcObject,
+    handler: GcObject,
+    revoked: bool,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let proxy_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .callable(false)
+            .static_method(Self::revocable, "revocable", 2)
+            .build();
+
+        (Self::NAME, proxy_object.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Create a new proxy
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler) = match (args.get(0), args.get(1)) {
+            (Some(Value::Object(target)), Some(Value::Object(handler))) => {
+                (target.clone(), handler.clone())
+            }
+            _ => {
+                return context
+                    .throw_type_error("Cannot create proxy with a non-object as target or handler")
+            }
+        };
+
+        // A proxy does not have a prototype of its own.
+        this.as_object()
+            .expect("this is proxy object")
+            .set_prototype_instance(Value::null());
+
+        this.set_data(ObjectData::Proxy(Self {
+            target,
+            handler,
+            revoked: false,
+        }));
+
+        Ok(this.clone())
+    }
+
+    /// `Proxy.revocable( target, handler )`
+    ///
+    /// Creates a proxy, and returns it in an object along with a function that revokes it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/revocable
+    pub(crate) fn revocable(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let proxy = Value::new_object(Some(context.global_object()));
+        Self::constructor(&proxy, args, context)?;
+
+        let revoke = context.create_builtin_closure("", 0, proxy.clone(), |_, _, proxy, _| {
+            if let Some(object) = proxy.as_object() {
+                if let ObjectData::Proxy(ref mut proxy) = object.borrow_mut().data {
+                    proxy.revoked = true;
+                }
+            }
+            Ok(Value::undefined())
+        });
+
+        let result = Value::new_object(Some(context.global_object()));
+        result.set_field("proxy", proxy);
+        result.set_field("revoke", revoke);
+
+        Ok(result)
+    }
+
+    /// Gets the target and the handler of the proxy object, with the trap of the handler that has
+    /// the given name, if any.
+    fn trap(
+        proxy: &GcObject,
+        name: &str,
+        context: &mut Context,
+    ) -> Result<(GcObject, GcObject, Option<GcObject>)> {
+        let proxy = proxy
+            .borrow()
+            .as_proxy()
+            .cloned()
+            .expect("object is a proxy");
+        if proxy.revoked {
+            return Err(context.construct_type_error(format!(
+                "Cannot perform '{}' on a proxy that has been revoked",
+                name
+            )));
+        }
+        let trap = proxy.handler.get_method(context, name)?;
+
+        Ok((proxy.target, proxy.handler, trap))
+    }
+
+    /// The `[[Get]]` internal method of proxy objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        proxy: &GcObject,
+        key: PropertyKey,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (target, handler, trap) = Self::trap(proxy, "get", context)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return Value::from(target).get_field_with_accessors(key, context),
+        };
+
+        let value = trap.call(
+            &handler.into(),
+            &[target.clone().into(), key.clone().into(), receiver.clone()],
+            context,
+        )?;
+
+        match target.get_own_property(&key) {
+            Some(PropertyDescriptor::Data(ref desc))
+                if !desc.configurable() && !desc.writable() && !same_value(&value, &desc.value()) =>
+            {
+                context.throw_type_error(
+                    "proxy get trap returned a different value for a non-writable, non-configurable property",
+                )
+            }
+            Some(PropertyDescriptor::Accessor(ref desc))
+                if !desc.configurable() && desc.getter().is_none() && !value.is_undefined() =>
+            {
+                context.throw_type_error(
+                    "proxy get trap returned a value for a non-configurable accessor property without a getter",
+                )
+            }
+            _ => Ok(value),
+        }
+    }
+
+    /// The `[[Set]]` internal method of proxy objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        proxy: &GcObject,
+        key: PropertyKey,
+        value: Value,
+        receiver: &Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let (target, handler, trap) = Self::trap(proxy, "set", context)?;
+        let trap = if let Some(trap) = trap {
+            trap
+        } else {
+            Value::from(target).set_field_with_accessors(key, value, context)?;
+            return Ok(true);
+        };
+
+        let success = trap
+            .call(
+                &handler.into(),
+                &[
+                    target.clone().into(),
+                    key.clone().into(),
+                    value.clone(),
+                    receiver.clone(),
+                ],
+                context,
+            )?
+            .to_boolean();
+        if !success {
+            return Ok(false);
+        }
+
+        match target.get_own_property(&key) {
+            Some(PropertyDescriptor::Data(ref desc))
+                if !desc.configurable() && !desc.writable() && !same_value(&value, &desc.value()) =>
+            {
+                Err(context.construct_type_error("proxy set trap succeeded for a non-writable, non-configurable property"))
+            }
+            Some(PropertyDescriptor::Accessor(ref desc))
+                if !desc.configurable() && desc.setter().is_none() =>
+            {
+                Err(context.construct_type_error("proxy set trap succeeded for a non-configurable accessor property without a setter"))
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// The `[[HasProperty]]` internal method of proxy objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has(proxy: &GcObject, key: PropertyKey, context: &mut Context) -> Result<bool> {
+        let (target, handler, trap) = Self::trap(proxy, "has", context)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return context.has_property(&target.into(), &key),
+        };
+
+        let found = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), key.clone().into()],
+                context,
+            )?
+            .to_boolean();
+
+        match target.get_own_property(&key) {
+            Some(ref desc) if !found && !desc.configurable() => Err(context.construct_type_error(
+                "proxy has trap reported a non-configurable property as non-existent",
+            )),
+            _ => Ok(found),
+        }
+    }
+
+    /// The `[[Delete]]` internal method of proxy objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete_property(
+        proxy: &GcObject,
+        key: PropertyKey,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let (target, handler, trap) = Self::trap(proxy, "deleteProperty", context)?;
+        let trap = match trap {
+            Some(trap) => trap,
+            None => return context.delete_property(&target.into(), &key),
+        };
+
+        let deleted = trap
+            .call(
+                &handler.into(),
+                &[target.clone().into(), key.clone().into()],
+                context,
+            )?
+            .to_boolean();
+
+        match target.get_own_property(&key) {
+            Some(ref desc) if deleted && !desc.configurable() => Err(context.construct_type_error(
+                "proxy deleteProperty trap reported a non-configurable property as deleted",
+            )),
+            _ => Ok(deleted),
+        }
+    }
+}
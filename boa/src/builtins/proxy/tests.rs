@@ -0,0 +1,168 @@
+use crate::{forward, Context};
+
+#[test]
+fn logging_get_trap() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let target = { x: 1 };
+        let proxy = new Proxy(target, {
+            get: function(target, key, receiver) {
+                log.push(key);
+                return target[key] * 10;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "proxy.x");
+    assert_eq!(result, "10");
+    let result = forward(&mut context, "proxy['x'] + proxy.x");
+    assert_eq!(result, "20");
+    let result = forward(&mut context, "log.join()");
+    assert_eq!(result, "\"x,x,x\"");
+}
+
+#[test]
+fn validating_set_trap() {
+    let mut context = Context::new();
+    let init = r#"
+        let target = {};
+        let proxy = new Proxy(target, {
+            set: function(target, key, value) {
+                if (typeof value !== 'number') {
+                    throw new TypeError('not a number');
+                }
+                target[key] = value;
+                return true;
+            }
+        });
+        proxy.age = 30;
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "target.age");
+    assert_eq!(result, "30");
+    let result = forward(&mut context, "proxy.age = 'old'");
+    assert_eq!(result, "Uncaught \"TypeError\": \"not a number\"");
+    let result = forward(&mut context, "target.age");
+    assert_eq!(result, "30");
+}
+
+#[test]
+fn has_and_delete_property_traps() {
+    let mut context = Context::new();
+    let init = r#"
+        let target = { x: 1, _hidden: 2 };
+        let proxy = new Proxy(target, {
+            has: function(target, key) {
+                return !key.startsWith('_') && key in target;
+            },
+            deleteProperty: function(target, key) {
+                return false;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "'x' in proxy");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "'_hidden' in proxy");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "delete proxy.x");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "target.x");
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn absent_traps_forward_to_target() {
+    let mut context = Context::new();
+    let init = r#"
+        let target = { x: 1 };
+        let proxy = new Proxy(target, {});
+        proxy.y = 2;
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "proxy.x");
+    assert_eq!(result, "1");
+    let result = forward(&mut context, "target.y");
+    assert_eq!(result, "2");
+    let result = forward(&mut context, "'y' in proxy");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "delete proxy.y");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "'y' in target");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn get_trap_invariant() {
+    let mut context = Context::new();
+    let init = r#"
+        let target = {};
+        Object.defineProperty(target, 'fixed', { value: 1 });
+        let proxy = new Proxy(target, {
+            get: function() {
+                return 2;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "proxy.fixed");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+    let result = forward(&mut context, "proxy.other");
+    assert_eq!(result, "2");
+}
+
+#[test]
+fn non_object_arguments() {
+    let mut context = Context::new();
+    let result = forward(&mut context, "new Proxy(1, {})");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+    let result = forward(&mut context, "new Proxy({}, null)");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+}
+
+#[test]
+fn array_methods_use_traps() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let proxy = new Proxy([1, 2, 3], {
+            get: function(target, key) {
+                log.push(key);
+                return target[key];
+            },
+            set: function(target, key, value) {
+                target[key] = value * 2;
+                return true;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "Array.prototype.join.call(proxy, '-')");
+    assert_eq!(result, "\"1-2-3\"");
+    let result = forward(&mut context, "log.join()");
+    assert_eq!(result, "\"length,0,1,2\"");
+    forward(&mut context, "Array.prototype.fill.call(proxy, 5)");
+    let result = forward(&mut context, "[...proxy].join()");
+    assert_eq!(result, "\"10,10,10\"");
+}
+
+#[test]
+fn revocable() {
+    let mut context = Context::new();
+    let init = r#"
+        let revocable = Proxy.revocable({ x: 1 }, {});
+        let proxy = revocable.proxy;
+        let revoke = revocable.revoke;
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "proxy.x");
+    assert_eq!(result, "1");
+    forward(&mut context, "revoke()");
+    let result = forward(&mut context, "proxy.x");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+    let result = forward(&mut context, "'x' in proxy");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+    let result = forward(&mut context, "revoke()");
+    assert_eq!(result, "undefined");
+}
@@ -0,0 +1,314 @@
+//! This module implements the global `Reflect` object.
+//!
+//! The `Reflect` global object is a built-in object that provides methods for interceptable
+//! JavaScript operations.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-reflect-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect
+
+use crate::{
+    builtins::{Array, BuiltIn},
+    object::{GcObject, ObjectInitializer},
+    property::{Attribute, PropertyDescriptor},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Javascript `Reflect` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Reflect;
+
+impl BuiltIn for Reflect {
+    const NAME: &'static str = "Reflect";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let object = ObjectInitializer::new(context)
+            .function(Self::apply, "apply", 3)
+            .function(Self::construct, "construct", 2)
+            .function(Self::define_property, "defineProperty", 3)
+            .function(Self::delete_property, "deleteProperty", 2)
+            .function(Self::get, "get", 2)
+            .function(Self::get_prototype_of, "getPrototypeOf", 1)
+            .function(Self::has, "has", 2)
+            .function(Self::own_keys, "ownKeys", 1)
+            .function(Self::set, "set", 3)
+            .build();
+
+        (Self::NAME, object.into(), Self::attribute())
+    }
+}
+
+impl Reflect {
+    /// The maximum length of an array-like that can be spread into an arguments list.
+    const MAX_ARGUMENTS_LENGTH: usize = 65_535;
+
+    /// `Reflect.apply( target, thisArgument, argumentsList )`
+    ///
+    /// Calls the target function with the given `this` value and the arguments in the array-like
+    /// `argumentsList`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.apply
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/apply
+    pub(crate) fn apply(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, context)?;
+        if !target.is_callable() {
+            return context.throw_type_error("target must be a function");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        let arguments = Self::list_from_array_like(args.get(2), context)?;
+
+        target.call(&this_arg, &arguments, context)
+    }
+
+    /// `Reflect.construct( target, argumentsList [ , newTarget ] )`
+    ///
+    /// Calls the target function as a constructor with the arguments in the array-like
+    /// `argumentsList`. A `newTarget` different from `target` is not supported yet.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.construct
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/construct
+    pub(crate) fn construct(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, context)?;
+        if !target.is_constructable() {
+            return context.throw_type_error("target must be a constructor");
+        }
+        let arguments = Self::list_from_array_like(args.get(1), context)?;
+
+        target.construct(&arguments, context)
+    }
+
+    /// `Reflect.defineProperty( target, propertyKey, attributes )`
+    ///
+    /// Defines or modifies a property of the target. Unlike `Object.defineProperty`, it returns
+    /// `false` instead of throwing when the property cannot be defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.defineproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/defineProperty
+    pub(crate) fn define_property(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, context)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)?;
+        let desc = args
+            .get(2)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_descriptor(context)?;
+
+        Ok(target.define_own_partial_property(key, desc).into())
+    }
+
+    /// `Reflect.deleteProperty( target, propertyKey )`
+    ///
+    /// Deletes a property of the target, returning whether it was deleted.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.deleteproperty
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/deleteProperty
+    pub(crate) fn delete_property(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut target = Self::target(args, context)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)?;
+
+        Ok(target.delete(&key).into())
+    }
+
+    /// `Reflect.get( target, propertyKey [ , receiver ] )`
+    ///
+    /// Gets the value of a property of the target, like a property access does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/get
+    pub(crate) fn get(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, context)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)?;
+
+        Value::from(target).get_field_with_accessors(key, context)
+    }
+
+    /// `Reflect.getPrototypeOf( target )`
+    ///
+    /// Returns the prototype of the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.getprototypeof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/getPrototypeOf
+    pub(crate) fn get_prototype_of(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let target = Self::target(args, context)?;
+
+        Ok(target.get_prototype_of())
+    }
+
+    /// `Reflect.has( target, propertyKey )`
+    ///
+    /// Returns whether the target or its prototype chain has the property, like the `in`
+    /// operator does.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/has
+    pub(crate) fn has(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, context)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)?;
+
+        Ok(target.has_property(&key).into())
+    }
+
+    /// `Reflect.ownKeys( target )`
+    ///
+    /// Returns an array of the own property keys of the target, including symbols and
+    /// non-enumerable properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.ownkeys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys
+    pub(crate) fn own_keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::target(args, context)?;
+        let keys: Vec<Value> = target
+            .own_property_keys()
+            .into_iter()
+            .map(Value::from)
+            .collect();
+
+        Array::construct_array(&Array::new_array(context)?, &keys)
+    }
+
+    /// `Reflect.set( target, propertyKey, V [ , receiver ] )`
+    ///
+    /// Sets the value of a property of the target, returning whether it was set instead of
+    /// throwing when the property is not writable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-reflect.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/set
+    pub(crate) fn set(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let mut target = Self::target(args, context)?;
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_property_key(context)?;
+        let value = args.get(2).cloned().unwrap_or_else(Value::undefined);
+
+        let target_value = Value::from(target.clone());
+        if let Some(PropertyDescriptor::Accessor(desc)) = target_value.get_property(key.clone()) {
+            return match desc.setter() {
+                Some(setter) => {
+                    setter.call(&target_value, &[value], context)?;
+                    Ok(true.into())
+                }
+                None => Ok(false.into()),
+            };
+        }
+
+        Ok(target.set(key, value).into())
+    }
+
+    /// Gets the `target` argument of a `Reflect` function, which must be an object.
+    fn target(args: &[Value], context: &mut Context) -> Result<GcObject> {
+        match args.get(0) {
+            Some(Value::Object(object)) => Ok(object.clone()),
+            _ => Err(context.construct_type_error("target must be an object")),
+        }
+    }
+
+    /// The abstract operation `CreateListFromArrayLike`.
+    ///
+    /// Throws a `RangeError` for array-likes longer than `MAX_ARGUMENTS_LENGTH`, instead of
+    /// allocating an arguments list for any length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createlistfromarraylike
+    pub(crate) fn list_from_array_like(
+        value: Option<&Value>,
+        context: &mut Context,
+    ) -> Result<Vec<Value>> {
+        let value = match value {
+            Some(value) if value.is_object() => value,
+            _ => return Err(context.construct_type_error("arguments list must be an object")),
+        };
+
+        let len = value
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        if len > Self::MAX_ARGUMENTS_LENGTH {
+            return Err(context.construct_range_error("too many arguments in arguments list"));
+        }
+
+        let mut list = Vec::new();
+        for index in 0..len {
+            list.push(value.get_field_with_accessors(index, context)?);
+        }
+        Ok(list)
+    }
+}
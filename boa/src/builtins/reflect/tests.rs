@@ -0,0 +1,154 @@
+use crate::{forward, Context};
+
+#[test]
+fn get() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = { x: 10 };
+        Object.defineProperty(o, 'y', { get: function() { return 20; } });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "Reflect.get(o, 'x') === o.x");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "Reflect.get(o, 'y')");
+    assert_eq!(result, "20");
+    let result = forward(&mut context, "Reflect.get(o, 'z')");
+    assert_eq!(result, "undefined");
+}
+
+#[test]
+fn set() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = {};
+        Object.defineProperty(o, 'fixed', { value: 1, writable: false });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "Reflect.set(o, 'x', 5)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "o.x");
+    assert_eq!(result, "5");
+    let result = forward(&mut context, "Reflect.set(o, 'fixed', 2)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "o.fixed");
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn define_property() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = {};
+        "#;
+    forward(&mut context, init);
+    let result = forward(
+        &mut context,
+        "Reflect.defineProperty(o, 'x', { value: 1, configurable: false })",
+    );
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "o.x");
+    assert_eq!(result, "1");
+    let result = forward(&mut context, "Reflect.defineProperty(o, 'x', { value: 2 })");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "o.x");
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn has_and_delete_property() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = { x: 1 };
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "Reflect.has(o, 'x')");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "Reflect.has(o, 'toString')");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "Reflect.deleteProperty(o, 'x')");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "Reflect.has(o, 'x')");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn own_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let o = { a: 1, b: 2 };
+        Object.defineProperty(o, 'c', { value: 3, enumerable: false });
+        let keys = Reflect.ownKeys(o);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "keys.length");
+    assert_eq!(result, "3");
+    let result = forward(
+        &mut context,
+        "keys.includes('a') && keys.includes('b') && keys.includes('c')",
+    );
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn get_prototype_of() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        "Reflect.getPrototypeOf([]) === Array.prototype",
+    );
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn apply() {
+    let mut context = Context::new();
+    let init = r#"
+        function sum(a, b, c) { return this.base + a + b + c; }
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "Reflect.apply(sum, { base: 1 }, [2, 3, 4])");
+    assert_eq!(result, "10");
+    let result = forward(
+        &mut context,
+        "Reflect.apply(Math.max, undefined, [1, 3, 2])",
+    );
+    assert_eq!(result, "3");
+}
+
+#[test]
+fn apply_too_many_arguments() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        "Reflect.apply(Math.max, null, { length: 2 ** 32 })",
+    );
+    assert!(result.starts_with("Uncaught \"RangeError\""));
+    let result = forward(&mut context, "Math.max.apply(null, { length: 2 ** 32 })");
+    assert!(result.starts_with("Uncaught \"RangeError\""));
+    let result = forward(
+        &mut context,
+        "Math.max.apply(null, { length: 2, 0: 1, 1: 2 })",
+    );
+    assert_eq!(result, "2");
+}
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+    let init = r#"
+        function Point(x, y) { this.x = x; this.y = y; }
+        let p = Reflect.construct(Point, [1, 2]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "p.x + p.y");
+    assert_eq!(result, "3");
+    let result = forward(&mut context, "p instanceof Point");
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn non_object_target() {
+    let mut context = Context::new();
+    let result = forward(&mut context, "Reflect.get(1, 'x')");
+    assert!(result.starts_with("Uncaught \"TypeError\""));
+}
@@ -9,10 +9,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-regexp-constructor
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
+pub mod regexp_string_iterator;
+
 use crate::{
-    builtins::BuiltIn,
+    builtins::{regexp::regexp_string_iterator::RegExpStringIterator, BuiltIn},
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData},
+    object::{ConstructorBuilder, Object, ObjectData},
     property::{Attribute, DataDescriptor},
     value::{RcString, Value},
     BoaProfiler, Context, Result,
@@ -26,7 +28,7 @@ mod tests;
 #[derive(Debug, Clone, Finalize)]
 pub struct RegExp {
     /// Regex matcher.
-    matcher: Regex,
+    pub(crate) matcher: Regex,
 
     /// Update last_index, set if global or sticky flags are set.
     use_last_index: bool,
@@ -38,7 +40,7 @@ pub struct RegExp {
     dot_all: bool,
 
     /// Flag 'g'
-    global: bool,
+    pub(crate) global: bool,
 
     /// Flag 'i' - ignore case.
     ignore_case: bool,
@@ -54,8 +56,14 @@ pub struct RegExp {
 
     pub(crate) original_source: Box<str>,
     original_flags: Box<str>,
+
+    /// The names of the named capture groups, with the index of their capture group.
+    group_names: GroupNames,
 }
 
+/// The names of the named capture groups of a pattern, with the index of their capture group.
+type GroupNames = Box<[(Box<str>, usize)]>;
+
 // Only safe while regress::Regex doesn't implement Trace itself.
 unsafe impl Trace for RegExp {
     empty_trace!();
@@ -98,7 +106,11 @@ impl RegExp {
     pub(crate) const LENGTH: usize = 2;
 
     /// Create a new `RegExp`
-    pub(crate) fn constructor(this: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
         let arg = args.get(0).ok_or_else(Value::undefined)?;
 
         let (regex_body, mut regex_flags) = match arg {
@@ -161,8 +173,24 @@ impl RegExp {
             sorted_flags.push('y');
         }
 
-        let matcher = Regex::with_flags(&regex_body, sorted_flags.as_str())
-            .expect("failed to create matcher");
+        let (pattern, group_names) = match Self::parse_named_groups(&regex_body) {
+            Ok(parsed) => parsed,
+            Err(message) => {
+                return context.throw_syntax_error(format!(
+                    "Invalid regular expression: /{}/: {}",
+                    regex_body, message
+                ))
+            }
+        };
+        let matcher = match Regex::with_flags(&pattern, sorted_flags.as_str()) {
+            Ok(matcher) => matcher,
+            Err(error) => {
+                return context.throw_syntax_error(format!(
+                    "Invalid regular expression: /{}/: {}",
+                    regex_body, error.text
+                ))
+            }
+        };
         let regexp = RegExp {
             matcher,
             use_last_index: global || sticky,
@@ -175,6 +203,7 @@ impl RegExp {
             unicode,
             original_source: regex_body,
             original_flags: regex_flags,
+            group_names,
         };
 
         this.set_data(ObjectData::RegExp(Box::new(regexp)));
@@ -360,7 +389,11 @@ impl RegExp {
             let object = object.borrow();
             let regex = object.as_regexp().unwrap();
             let result = {
-                if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
+                if last_index > arg_str.len() {
+                    last_index = 0;
+                    Value::null()
+                } else if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next()
+                {
                     if regex.use_last_index {
                         last_index = m.end();
                     }
@@ -376,9 +409,23 @@ impl RegExp {
                         }
                     }
 
+                    let groups = if regex.group_names.is_empty() {
+                        Value::undefined()
+                    } else {
+                        let groups = Value::object(Object::with_prototype(
+                            Value::null(),
+                            ObjectData::Ordinary,
+                        ));
+                        for (name, index) in regex.group_names.iter() {
+                            groups.set_field(&**name, result[*index].clone());
+                        }
+                        groups
+                    };
+
                     let result = Value::from(result);
                     result.set_property("index", DataDescriptor::new(m.start(), Attribute::all()));
                     result.set_property("input", DataDescriptor::new(arg_str, Attribute::all()));
+                    result.set_property("groups", DataDescriptor::new(groups, Attribute::all()));
                     result
                 } else {
                     if regex.use_last_index {
@@ -454,7 +501,7 @@ impl RegExp {
 
     /// `RegExp.prototype[ @@matchAll ]( string )`
     ///
-    /// The `[@@matchAll]` method returns all matches of the regular expression against a string.
+    /// The `[@@matchAll]` method returns an iterator of all matches of the regular expression against a string.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -462,46 +509,135 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
-    // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String) -> Result<Value> {
-        let matches = if let Some(object) = this.as_object() {
+    pub(crate) fn match_all(
+        this: &Value,
+        arg_str: RcString,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (flags, global) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             let regex = object.as_regexp().unwrap();
-            let mut matches = Vec::new();
+            (regex.flags.clone(), regex.global)
+        } else {
+            panic!("object is not a regexp")
+        };
+
+        // The matches are collected with a copy of the regular expression, so iterating does not
+        // change the `lastIndex` of the original one.
+        let matcher = Self::constructor(
+            &Value::from(Object::default()),
+            &[this.clone(), Value::from(flags.to_string())],
+            context,
+        )?;
+        let last_index = this.get_field("lastIndex").to_index(context)?;
+        matcher.set_field("lastIndex", Value::from(last_index));
 
-            for mat in regex.matcher.find_iter(&arg_str) {
-                let match_vec: Vec<Value> = mat
-                    .groups()
-                    .map(|group| match group {
-                        Some(range) => Value::from(&arg_str[range]),
-                        None => Value::undefined(),
-                    })
-                    .collect();
-
-                let match_val = Value::from(match_vec);
-
-                match_val.set_property("index", DataDescriptor::new(mat.start(), Attribute::all()));
-                match_val.set_property(
-                    "input",
-                    DataDescriptor::new(arg_str.clone(), Attribute::all()),
-                );
-                matches.push(match_val);
-
-                if !regex.flags.contains('g') {
-                    break;
+        RegExpStringIterator::create_regexp_string_iterator(context, matcher, arg_str, global)
+    }
+
+    /// Returns the names of the named capture groups, with the index of their capture group.
+    #[inline]
+    pub(crate) fn group_names(&self) -> &[(Box<str>, usize)] {
+        &self.group_names
+    }
+
+    /// Replaces the named capture groups `(?<name>...)` of a pattern with plain capture groups,
+    /// and their backreferences `\k<name>` with numbered ones, as the matcher does not support
+    /// them.
+    ///
+    /// Returns the new pattern and the names of the groups with their capture group index, or
+    /// an error message if a name is invalid, duplicated or references no group.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+    fn parse_named_groups(pattern: &str) -> std::result::Result<(String, GroupNames), String> {
+        let mut result = String::with_capacity(pattern.len());
+        let mut group_names: Vec<(Box<str>, usize)> = Vec::new();
+        // The backreferences are resolved at the end, as they can come before their group.
+        let mut backreferences = Vec::new();
+        let mut group_count = 0;
+        let mut in_class = false;
+
+        let mut chars = pattern.chars().peekable();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => match chars.next() {
+                    Some('k') if !in_class && chars.peek() == Some(&'<') => {
+                        chars.next();
+                        let name = Self::parse_group_name(&mut chars);
+                        backreferences.push((result.len(), name));
+                    }
+                    Some(escaped) => {
+                        result.push('\\');
+                        result.push(escaped);
+                    }
+                    None => result.push('\\'),
+                },
+                '[' if !in_class => {
+                    in_class = true;
+                    result.push('[');
                 }
+                ']' if in_class => {
+                    in_class = false;
+                    result.push(']');
+                }
+                '(' if !in_class => {
+                    let mut lookahead = chars.clone();
+                    match (lookahead.next(), lookahead.next(), lookahead.next()) {
+                        (Some('?'), Some('<'), Some(next)) if next != '=' && next != '!' => {
+                            chars.next();
+                            chars.next();
+                            group_count += 1;
+                            let name = Self::parse_group_name(&mut chars)?;
+                            if group_names.iter().any(|(other, _)| *other == name) {
+                                return Err(format!("duplicate capture group name '{}'", name));
+                            }
+                            group_names.push((name, group_count));
+                        }
+                        (Some('?'), _, _) => {}
+                        _ => group_count += 1,
+                    }
+                    result.push('(');
+                }
+                ch => result.push(ch),
             }
+        }
 
-            matches
-        } else {
-            panic!("object is not a regexp")
-        };
+        // Without named groups, `\k` is an identity escape that the matcher handles.
+        if group_names.is_empty() {
+            return Ok((pattern.to_string(), Box::default()));
+        }
+        for (position, name) in backreferences.into_iter().rev() {
+            let name = name?;
+            let index = match group_names.iter().find(|(other, _)| *other == name) {
+                Some((_, index)) => index,
+                None => return Err(format!("invalid named capture referenced '{}'", name)),
+            };
+            // The group keeps a following digit from being read as part of the number.
+            result.insert_str(position, &format!("(?:\\{})", index));
+        }
 
-        let length = matches.len();
-        let result = Value::from(matches);
-        result.set_field("length", Value::from(length));
-        result.set_data(ObjectData::Array);
+        Ok((result, group_names.into_boxed_slice()))
+    }
 
-        Ok(result)
+    /// Parses a capture group name up to its closing `>`, which is consumed.
+    fn parse_group_name<I>(chars: &mut I) -> std::result::Result<Box<str>, String>
+    where
+        I: Iterator<Item = char>,
+    {
+        let mut name = String::new();
+        for ch in chars {
+            match ch {
+                '>' if !name.is_empty() => return Ok(name.into_boxed_str()),
+                '$' | '_' => name.push(ch),
+                ch if ch.is_alphabetic() || (!name.is_empty() && ch.is_alphanumeric()) => {
+                    name.push(ch)
+                }
+                _ => break,
+            }
+        }
+        Err("invalid capture group name".to_string())
     }
 }
@@ -0,0 +1,157 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, RegExp},
+    gc::{Finalize, Trace},
+    object::ObjectData,
+    property::{Attribute, DataDescriptor},
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+
+/// The RegExp String Iterator object represents an iteration over the matches of a regular
+/// expression in a string. It implements the iterator protocol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp-string-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct RegExpStringIterator {
+    matcher: Value,
+    string: RcString,
+    global: bool,
+    completed: bool,
+}
+
+impl RegExpStringIterator {
+    fn new(matcher: Value, string: RcString, global: bool) -> Self {
+        Self {
+            matcher,
+            string,
+            global,
+            completed: false,
+        }
+    }
+
+    /// Abstract operation CreateRegExpStringIterator( R, S, global, fullUnicode )
+    ///
+    /// Creates a new iterator over the matches of the `matcher` regular expression in `string`.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createregexpstringiterator
+    pub(crate) fn create_regexp_string_iterator(
+        context: &Context,
+        matcher: Value,
+        string: RcString,
+        global: bool,
+    ) -> Result<Value> {
+        let regexp_string_iterator = Value::new_object(Some(context.global_object()));
+        regexp_string_iterator.set_data(ObjectData::RegExpStringIterator(Self::new(
+            matcher, string, global,
+        )));
+        regexp_string_iterator
+            .as_object()
+            .expect("regexp string iterator object")
+            .set_prototype_instance(
+                context
+                    .iterator_prototypes()
+                    .regexp_string_iterator()
+                    .into(),
+            );
+        Ok(regexp_string_iterator)
+    }
+
+    /// %RegExpStringIteratorPrototype%.next( )
+    ///
+    /// Gets the next match of the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let iterator = match this.as_object() {
+            Some(object) => object.borrow().as_regexp_string_iterator().cloned(),
+            None => None,
+        };
+        let iterator = match iterator {
+            Some(iterator) => iterator,
+            None => return context.throw_type_error("`this` is not a RegExpStringIterator"),
+        };
+
+        if iterator.completed {
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+
+        let matched = RegExp::exec(
+            &iterator.matcher,
+            &[Value::from(iterator.string.clone())],
+            context,
+        )?;
+
+        if matched.is_null() || !iterator.global {
+            if let Some(object) = this.as_object() {
+                if let Some(iterator) = object.borrow_mut().as_regexp_string_iterator_mut() {
+                    iterator.completed = true;
+                }
+            }
+
+            if matched.is_null() {
+                return Ok(create_iter_result_object(context, Value::undefined(), true));
+            }
+            return Ok(create_iter_result_object(context, matched, false));
+        }
+
+        // An empty match does not move `lastIndex`, so it is advanced here to avoid
+        // matching the same position forever.
+        if matched.get_field(0).to_string(context)?.is_empty() {
+            let this_index = iterator.matcher.get_field("lastIndex").to_index(context)?;
+            let next_index = advance_string_index(&iterator.string, this_index);
+            iterator.matcher.set_field("lastIndex", next_index);
+        }
+
+        Ok(create_iter_result_object(context, matched, false))
+    }
+
+    /// Create the %RegExpStringIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> Value {
+        let global = context.global_object();
+        let _timer = BoaProfiler::global().start_event("RegExp String Iterator", "init");
+
+        // Create prototype
+        let result = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &result, 0, context);
+        result
+            .as_object()
+            .expect("regexp string iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property =
+            DataDescriptor::new("RegExp String Iterator", Attribute::CONFIGURABLE);
+        result.set_property(to_string_tag, to_string_tag_property);
+        result
+    }
+}
+
+/// Abstract operation AdvanceStringIndex( S, index, unicode )
+///
+/// `lastIndex` is a byte offset into the string here, so the index always moves past a
+/// whole character.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
+fn advance_string_index(string: &str, index: usize) -> usize {
+    match string.get(index..).and_then(|rest| rest.chars().next()) {
+        Some(ch) => index + ch.len_utf8(),
+        None => index + 1,
+    }
+}
@@ -98,3 +98,74 @@ fn to_string() {
     );
     assert_eq!(forward(&mut context, "/\\n/g.toString()"), "\"/\\n/g\"");
 }
+
+#[test]
+fn named_groups() {
+    let mut context = Context::new();
+    let init = r#"
+        var result = /(?<y>\d{4})-(?<m>\d{2})/.exec("2020-01");
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "result.groups.y === '2020'"), "true");
+    assert_eq!(forward(&mut context, "result.groups.m"), "\"01\"");
+    assert_eq!(forward(&mut context, "result[1]"), "\"2020\"");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(result.groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "/(\\d+)/.exec('1').groups"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "'2020-01'.match(/(?<y>\\d{4})/).groups.y"),
+        "\"2020\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>x)|(?<b>y)/.exec('y').groups.a"),
+        "undefined"
+    );
+}
+
+#[test]
+fn named_group_backreferences() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "/(?<a>.)\\k<a>/.exec('xaab')[0]"),
+        "\"aa\""
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>.)\\k<a>0/.test('aa0')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>.)-\\k<a>/.test('a-b')"),
+        "false"
+    );
+}
+
+#[test]
+fn named_group_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<a>x)(?<a>y)') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { /(?<a>x)\\k<b>/ } catch (e) { e.name }"),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<1>x)') } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+}
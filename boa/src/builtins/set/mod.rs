@@ -0,0 +1,291 @@
+#![allow(clippy::mutable_key_type)]
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn, Map},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result, Value,
+};
+use ordered_set::OrderedSet;
+
+pub mod set_iterator;
+use set_iterator::{SetIterationKind, SetIterator};
+
+pub mod ordered_set;
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Set(OrderedSet<Value>);
+
+impl BuiltIn for Set {
+    const NAME: &'static str = "Set";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let iterator_symbol = context.well_known_symbols().iterator_symbol();
+
+        let values_function = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        let set_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .property(
+                "values",
+                values_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                "keys",
+                values_function.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                iterator_symbol,
+                values_function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::add, "add", 1)
+            .method(Self::clear, "clear", 0)
+            .method(Self::delete, "delete", 1)
+            .method(Self::entries, "entries", 0)
+            .method(Self::for_each, "forEach", 1)
+            .method(Self::has, "has", 1)
+            .callable(false)
+            .build();
+
+        (Self::NAME, set_object.into(), Self::attribute())
+    }
+}
+
+impl Set {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new set
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // Set Prototype
+        let prototype = context
+            .global_object()
+            .get_field("Set")
+            .get_field(PROTOTYPE);
+
+        this.as_object()
+            .expect("this is set object")
+            .set_prototype_instance(prototype);
+
+        // add our arguments in
+        let data = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => OrderedSet::new(),
+            Some(iterable) => {
+                let mut set = OrderedSet::new();
+                let iterator = get_iterator(context, iterable.clone())?;
+                loop {
+                    let next = iterator.next(context)?;
+                    if next.is_done() {
+                        break;
+                    }
+
+                    set.insert(Map::normalize_key(next.value()));
+                }
+                set
+            }
+        };
+
+        // finally create size property
+        Self::set_size(this, data.len());
+
+        this.set_data(ObjectData::Set(data));
+
+        Ok(this.clone())
+    }
+
+    /// Helper function to set the size property.
+    fn set_size(this: &Value, size: usize) {
+        let size = DataDescriptor::new(
+            size,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+
+        this.set_property("size".to_string(), size);
+    }
+
+    /// `Set.prototype.add( value )`
+    ///
+    /// This method appends the value to the set, if it is not already present. Returns the set object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/add
+    pub(crate) fn add(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+
+        let size = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_set_mut() {
+                set.insert(Map::normalize_key(value));
+                set.len()
+            } else {
+                return Err(context.construct_type_error("'this' is not a Set"));
+            }
+        } else {
+            return Err(context.construct_type_error("'this' is not a Set"));
+        };
+
+        Self::set_size(this, size);
+        Ok(this.clone())
+    }
+
+    /// `Set.prototype.clear( )`
+    ///
+    /// This method removes all values from the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.clear
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/clear
+    pub(crate) fn clear(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        this.set_data(ObjectData::Set(OrderedSet::new()));
+
+        Self::set_size(this, 0);
+
+        Ok(Value::Undefined)
+    }
+
+    /// `Set.prototype.delete( value )`
+    ///
+    /// This method removes the value, if it exists. Returns true if there was such a value, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let value = args.get(0).unwrap_or(&undefined);
+
+        let (deleted, size) = if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_set_mut() {
+                let deleted = set.remove(value);
+                (deleted, set.len())
+            } else {
+                return Err(context.construct_type_error("'this' is not a Set"));
+            }
+        } else {
+            return Err(context.construct_type_error("'this' is not a Set"));
+        };
+        Self::set_size(this, size);
+        Ok(deleted.into())
+    }
+
+    /// `Set.prototype.entries()`
+    ///
+    /// Returns a new Iterator object that contains a [value, value] pair for each value in the Set object in insertion order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/entries
+    pub(crate) fn entries(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        SetIterator::create_set_iterator(context, this.clone(), SetIterationKind::KeyAndValue)
+    }
+
+    /// `Set.prototype.forEach( callbackFn [ , thisArg ] )`
+    ///
+    /// This method executes the provided callback function for each value in the set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/forEach
+    pub(crate) fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Value::from("Missing argument for Set.prototype.forEach"));
+        }
+
+        let callback_arg = &args[0];
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        // The set is cloned so the callback can modify the set while it is being iterated.
+        let set = match this {
+            Value::Object(ref object) => object.borrow().as_set_ref().cloned(),
+            _ => None,
+        };
+        let set = match set {
+            Some(set) => set,
+            None => return Err(context.construct_type_error("'this' is not a Set")),
+        };
+
+        for value in set {
+            let arguments = [value.clone(), value, this.clone()];
+
+            context.call(callback_arg, &this_arg, &arguments)?;
+        }
+
+        Ok(Value::Undefined)
+    }
+
+    /// `Set.prototype.has( value )`
+    ///
+    /// This method checks if the set contains the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let value = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let object = object.borrow();
+            if let Some(set) = object.as_set_ref() {
+                return Ok(set.contains(value).into());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a Set"))
+    }
+
+    /// `Set.prototype.values()`
+    ///
+    /// Returns a new Iterator object that contains the values for each element in the Set object in insertion order.
+    /// `Set.prototype.keys` and `Set.prototype[@@iterator]` are the same function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/values
+    pub(crate) fn values(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        SetIterator::create_set_iterator(context, this.clone(), SetIterationKind::Value)
+    }
+}
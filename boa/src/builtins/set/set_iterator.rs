@@ -0,0 +1,132 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, Array, Value},
+    object::ObjectData,
+    property::{Attribute, DataDescriptor},
+    BoaProfiler, Context, Result,
+};
+use gc::{Finalize, Trace};
+
+#[derive(Debug, Clone, Finalize, Trace)]
+pub enum SetIterationKind {
+    Value,
+    KeyAndValue,
+}
+
+/// The Set Iterator object represents an iteration over a set. It implements the iterator protocol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct SetIterator {
+    iterated_set: Value,
+    set_next_index: usize,
+    set_iteration_kind: SetIterationKind,
+}
+
+impl SetIterator {
+    pub(crate) const NAME: &'static str = "SetIterator";
+
+    fn new(set: Value, kind: SetIterationKind) -> Self {
+        SetIterator {
+            iterated_set: set,
+            set_next_index: 0,
+            set_iteration_kind: kind,
+        }
+    }
+
+    /// Abstract operation CreateSetIterator( set, kind )
+    ///
+    /// Creates a new iterator over the given set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createsetiterator
+    pub(crate) fn create_set_iterator(
+        context: &Context,
+        set: Value,
+        kind: SetIterationKind,
+    ) -> Result<Value> {
+        let set_iterator = Value::new_object(Some(context.global_object()));
+        set_iterator.set_data(ObjectData::SetIterator(Self::new(set, kind)));
+        set_iterator
+            .as_object()
+            .expect("set iterator object")
+            .set_prototype_instance(context.iterator_prototypes().set_iterator().into());
+        Ok(set_iterator)
+    }
+
+    /// %SetIteratorPrototype%.next( )
+    ///
+    /// Advances the iterator and gets the next result in the set.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set_iterator) = object.as_set_iterator_mut() {
+                if set_iterator.iterated_set.is_undefined() {
+                    return Ok(create_iter_result_object(context, Value::undefined(), true));
+                }
+
+                let value = if let Value::Object(ref object) = set_iterator.iterated_set {
+                    if let Some(entries) = object.borrow().as_set_ref() {
+                        entries.get_index(set_iterator.set_next_index).cloned()
+                    } else {
+                        return Err(context.construct_type_error("'this' is not a Set"));
+                    }
+                } else {
+                    return Err(context.construct_type_error("'this' is not a Set"));
+                };
+
+                if let Some(value) = value {
+                    set_iterator.set_next_index += 1;
+                    let result = match set_iterator.set_iteration_kind {
+                        SetIterationKind::Value => value,
+                        SetIterationKind::KeyAndValue => Array::construct_array(
+                            &Array::new_array(context)?,
+                            &[value.clone(), value],
+                        )?,
+                    };
+                    return Ok(create_iter_result_object(context, result, false));
+                }
+
+                set_iterator.iterated_set = Value::undefined();
+                Ok(create_iter_result_object(context, Value::undefined(), true))
+            } else {
+                context.throw_type_error("`this` is not a SetIterator")
+            }
+        } else {
+            context.throw_type_error("`this` is not a SetIterator")
+        }
+    }
+
+    /// Create the %SetIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%setiteratorprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> Value {
+        let global = context.global_object();
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let set_iterator = Value::new_object(Some(global));
+        make_builtin_fn(Self::next, "next", &set_iterator, 0, context);
+        set_iterator
+            .as_object()
+            .expect("set iterator prototype object")
+            .set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = context.well_known_symbols().to_string_tag_symbol();
+        let to_string_tag_property = DataDescriptor::new("Set Iterator", Attribute::CONFIGURABLE);
+        set_iterator.set_property(to_string_tag, to_string_tag_property);
+        set_iterator
+    }
+}
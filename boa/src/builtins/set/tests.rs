@@ -0,0 +1,162 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_empty() {
+    let mut context = Context::new();
+    let init = r#"
+        var empty = new Set();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "empty.size");
+    assert_eq!(result, "0");
+}
+
+#[test]
+fn construct_from_array() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set(["one", "two", "one"]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.size");
+    assert_eq!(result, "2");
+    let result = forward(&mut context, "set");
+    assert_eq!(result, "Set { \"one\", \"two\" }");
+}
+
+#[test]
+fn add() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.add(1) === set");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.add(1); set.add(2); set.size");
+    assert_eq!(result, "2");
+}
+
+#[test]
+fn delete() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.delete(1)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.delete(1)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "set.size");
+    assert_eq!(result, "1");
+}
+
+#[test]
+fn clear() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        set.clear();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.size");
+    assert_eq!(result, "0");
+    let result = forward(&mut context, "set.has(1)");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn same_value_zero() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([NaN, -0]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.has(NaN)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.has(0)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.add(NaN).add(0).size");
+    assert_eq!(result, "2");
+    let result = forward(&mut context, "1 / set.values().next().value");
+    assert_eq!(result, "NaN");
+    let result = forward(&mut context, "1 / [...set][1]");
+    assert_eq!(result, "Infinity");
+}
+
+#[test]
+fn order() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([3, 1]);
+        set.add(2);
+        set.add(3);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "[...set].join()");
+    assert_eq!(result, "\"3,1,2\"");
+    let result = forward(&mut context, "set.delete(3); set.add(3); [...set].join()");
+    assert_eq!(result, "\"1,2,3\"");
+}
+
+#[test]
+fn iterators() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set(["a", "b"]);
+        let values = [];
+        for (let value of set.keys()) {
+            values.push(value);
+        }
+        const entries = set.entries();
+        let item1 = entries.next();
+        entries.next();
+        let item3 = entries.next();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "values.join()");
+    assert_eq!(result, "\"a,b\"");
+    let result = forward(&mut context, "set.keys === set.values");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set[Symbol.iterator] === set.values");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "item1.value.join()");
+    assert_eq!(result, "\"a,a\"");
+    let result = forward(&mut context, "item3.done");
+    assert_eq!(result, "true");
+}
+
+#[test]
+fn for_each() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([1, 2]);
+        let result = [];
+        set.forEach(function (value, key, s) {
+            result.push(value + key);
+            s.add(value * 10);
+        });
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "result.join()");
+    assert_eq!(result, "\"2,4\"");
+    let result = forward(&mut context, "set.size");
+    assert_eq!(result, "4");
+}
+
+#[test]
+fn not_a_function() {
+    let mut context = Context::new();
+    let init = r"
+        try {
+            let set = Set()
+        } catch(e) {
+            e.toString()
+        }
+    ";
+    assert_eq!(
+        forward(&mut context, init),
+        "\"TypeError: function object is not callable\""
+    );
+}
@@ -20,11 +20,11 @@ use crate::{
     value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
 use std::{
     char::decode_utf16,
     cmp::{max, min},
     f64::NAN,
+    ops::Range,
     string::String as StdString,
 };
 
@@ -82,6 +82,7 @@ impl BuiltIn for String {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .property("length", 0, attribute)
+        .static_method(Self::raw, "raw", 1)
         .method(Self::char_at, "charAt", 1)
         .method(Self::char_code_at, "charCodeAt", 1)
         .method(Self::to_string, "toString", 0)
@@ -106,6 +107,7 @@ impl BuiltIn for String {
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
+        .method(Self::replace_all, "replaceAll", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
         .build();
 
@@ -141,7 +143,13 @@ impl String {
 
         let length = string.encode_utf16().count();
 
-        this.set_field("length", Value::from(length as i32));
+        if let Some(mut object) = this.as_object() {
+            object.insert_property(
+                "length",
+                length,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            );
+        }
 
         this.set_data(ObjectData::String(string.clone()));
 
@@ -171,6 +179,50 @@ impl String {
         Ok(Value::from(Self::this_string_value(this, context)?))
     }
 
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The static `String.raw()` method is the tag function of template literals. It returns the
+    /// raw strings of the template, interleaved with the given substitutions.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let cooked = Value::from(
+            args.get(0)
+                .cloned()
+                .unwrap_or_default()
+                .to_object(context)?,
+        );
+        let raw = Value::from(
+            cooked
+                .get_field_with_accessors("raw", context)?
+                .to_object(context)?,
+        );
+        let literal_segments = raw
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        let substitutions = args.get(1..).unwrap_or(&[]);
+
+        let mut result = StdString::new();
+        for index in 0..literal_segments {
+            let segment = raw.get_field_with_accessors(index, context)?;
+            result.push_str(&segment.to_string(context)?);
+
+            if index + 1 == literal_segments {
+                break;
+            }
+            if let Some(substitution) = substitutions.get(index) {
+                result.push_str(&substitution.to_string(context)?);
+            }
+        }
+
+        Ok(Value::from(result))
+    }
+
     /// `String.prototype.charAt( index )`
     ///
     /// The `String` object's `charAt()` method returns a new string consisting of the single UTF-16 code unit located at the specified offset into the string.
@@ -518,28 +570,143 @@ impl String {
         Ok(Value::from(this_string.contains(search_string.as_str())))
     }
 
-    /// Return either the string itself or the string of the regex equivalent
-    fn get_regex_string(value: &Value) -> StdString {
+    fn is_regexp_object(value: &Value) -> bool {
         match value {
-            Value::String(ref body) => body.to_string(),
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
+            Value::Object(ref obj) => obj.borrow().is_regexp(),
+            _ => false,
+        }
+    }
 
-                if let Some(regexp) = obj.as_regexp() {
-                    // first argument is another `RegExp` object, so copy its pattern and flags
-                    return regexp.original_source.clone().into();
+    /// Abstract operation `GetSubstitution`.
+    ///
+    /// Expands the `$` patterns of a replacement string for the match at `matched` in `string`.
+    ///
+    /// `group_names` holds the named capture groups of the pattern, with their capture group
+    /// index. Without named groups, `$<` is left in the result as is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+    fn get_substitution(
+        string: &str,
+        matched: Range<usize>,
+        captures: &[Option<Range<usize>>],
+        group_names: &[(Box<str>, usize)],
+        replacement: &str,
+    ) -> StdString {
+        let mut result = StdString::new();
+        let mut chars = replacement.chars().peekable();
+
+        let m = captures.len();
+
+        while let Some(first) = chars.next() {
+            if first == '$' {
+                let second = chars.next();
+                let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
+                // we use peek so that it is still in the iterator if not used
+                let third = if second_is_digit { chars.peek() } else { None };
+                let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
+
+                match (second, third) {
+                    (Some('$'), _) => {
+                        // $$
+                        result.push('$');
+                    }
+                    (Some('&'), _) => {
+                        // $&
+                        result.push_str(&string[matched.clone()]);
+                    }
+                    (Some('`'), _) => {
+                        // $`
+                        result.push_str(&string[..matched.start]);
+                    }
+                    (Some('\''), _) => {
+                        // $'
+                        result.push_str(&string[matched.end..]);
+                    }
+                    (Some(second), Some(third)) if second_is_digit && third_is_digit => {
+                        // $nn
+                        let tens = second.to_digit(10).unwrap() as usize;
+                        let units = third.to_digit(10).unwrap() as usize;
+                        let nn = 10 * tens + units;
+                        if nn == 0 || nn > m {
+                            result.push(first);
+                            result.push(second);
+                            if let Some(ch) = chars.next() {
+                                result.push(ch);
+                            }
+                        } else {
+                            let group = match captures[nn - 1] {
+                                Some(ref range) => &string[range.clone()],
+                                _ => "",
+                            };
+                            result.push_str(group);
+                            chars.next(); // consume third
+                        }
+                    }
+                    (Some(second), _) if second_is_digit => {
+                        // $n
+                        let n = second.to_digit(10).unwrap() as usize;
+                        if n == 0 || n > m {
+                            result.push(first);
+                            result.push(second);
+                        } else {
+                            let group = match captures[n - 1] {
+                                Some(ref range) => &string[range.clone()],
+                                _ => "",
+                            };
+                            result.push_str(group);
+                        }
+                    }
+                    (Some('<'), _) if !group_names.is_empty() => {
+                        // $<name>
+                        let mut name_chars = chars.clone();
+                        let mut name = StdString::new();
+                        let closed = loop {
+                            match name_chars.next() {
+                                Some('>') => break true,
+                                Some(ch) => name.push(ch),
+                                None => break false,
+                            }
+                        };
+
+                        if closed {
+                            chars = name_chars;
+                            // An unknown name or an unmatched group is replaced by nothing.
+                            let group = group_names
+                                .iter()
+                                .find(|(group_name, _)| **group_name == *name)
+                                .and_then(|(_, index)| captures[index - 1].clone());
+                            if let Some(range) = group {
+                                result.push_str(&string[range]);
+                            }
+                        } else {
+                            result.push(first);
+                            result.push('<');
+                        }
+                    }
+                    (Some('<'), _) => {
+                        // $<
+                        // Without named capture groups, this is left in the result as is.
+                        result.push(first);
+                        result.push('<');
+                    }
+                    _ => {
+                        // $?, ? is none of the above
+                        // we can consume second because it isn't $
+                        result.push(first);
+                        if let Some(second) = second {
+                            result.push(second);
+                        }
+                    }
                 }
-                "undefined".to_string()
+            } else {
+                result.push(first);
             }
-            _ => "undefined".to_string(),
         }
-    }
 
-    fn is_regexp_object(value: &Value) -> bool {
-        match value {
-            Value::Object(ref obj) => obj.borrow().is_regexp(),
-            _ => false,
-        }
+        result
     }
 
     /// `String.prototype.replace( regexp|substr, newSubstr|function )`
@@ -564,124 +731,47 @@ impl String {
             return Ok(Value::from(primitive_val));
         }
 
-        let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
-        let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
-        let mat = match re.find(&primitive_val) {
-            Some(mat) => mat,
-            None => return Ok(Value::from(primitive_val)),
+        // The match is stored as the range of the whole match followed by its capture groups.
+        let search_value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let regexp = search_value
+            .as_object()
+            .and_then(|object| object.borrow().as_regexp().cloned());
+        let (matched, caps, group_names) = match regexp {
+            Some(regexp) => match regexp.matcher.find(&primitive_val) {
+                Some(mat) => (mat.range(), mat.captures, regexp.group_names().to_vec()),
+                None => return Ok(Value::from(primitive_val)),
+            },
+            None => {
+                let search_string = search_value.to_string(context)?;
+                match primitive_val.find(search_string.as_str()) {
+                    Some(start) => (start..start + search_string.len(), Vec::new(), Vec::new()),
+                    None => return Ok(Value::from(primitive_val)),
+                }
+            }
         };
-        let caps = re
-            .find(&primitive_val)
-            .expect("unable to get capture groups from text")
-            .captures;
 
         let replace_value = if args.len() > 1 {
             // replace_object could be a string or function or not exist at all
             let replace_object: &Value = args.get(1).expect("second argument expected");
             match replace_object {
-                Value::String(val) => {
-                    // https://tc39.es/ecma262/#table-45
-                    let mut result = StdString::new();
-                    let mut chars = val.chars().peekable();
-
-                    let m = caps.len();
-
-                    while let Some(first) = chars.next() {
-                        if first == '$' {
-                            let second = chars.next();
-                            let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
-                            // we use peek so that it is still in the iterator if not used
-                            let third = if second_is_digit { chars.peek() } else { None };
-                            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-                            match (second, third) {
-                                (Some('$'), _) => {
-                                    // $$
-                                    result.push('$');
-                                }
-                                (Some('&'), _) => {
-                                    // $&
-                                    result.push_str(&primitive_val[mat.range()]);
-                                }
-                                (Some('`'), _) => {
-                                    // $`
-                                    let start_of_match = mat.start();
-                                    result.push_str(&primitive_val[..start_of_match]);
-                                }
-                                (Some('\''), _) => {
-                                    // $'
-                                    let end_of_match = mat.end();
-                                    result.push_str(&primitive_val[end_of_match..]);
-                                }
-                                (Some(second), Some(third))
-                                    if second_is_digit && third_is_digit =>
-                                {
-                                    // $nn
-                                    let tens = second.to_digit(10).unwrap() as usize;
-                                    let units = third.to_digit(10).unwrap() as usize;
-                                    let nn = 10 * tens + units;
-                                    if nn == 0 || nn > m {
-                                        result.push(first);
-                                        result.push(second);
-                                        if let Some(ch) = chars.next() {
-                                            result.push(ch);
-                                        }
-                                    } else {
-                                        let group = match mat.group(nn) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                        chars.next(); // consume third
-                                    }
-                                }
-                                (Some(second), _) if second_is_digit => {
-                                    // $n
-                                    let n = second.to_digit(10).unwrap() as usize;
-                                    if n == 0 || n > m {
-                                        result.push(first);
-                                        result.push(second);
-                                    } else {
-                                        let group = match mat.group(n) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                    }
-                                }
-                                (Some('<'), _) => {
-                                    // $<
-                                    todo!("named capture groups")
-                                }
-                                _ => {
-                                    // $?, ? is none of the above
-                                    // we can consume second because it isn't $
-                                    result.push(first);
-                                    if let Some(second) = second {
-                                        result.push(second);
-                                    }
-                                }
-                            }
-                        } else {
-                            result.push(first);
-                        }
-                    }
-
-                    result
-                }
+                Value::String(val) => Self::get_substitution(
+                    &primitive_val,
+                    matched.clone(),
+                    &caps,
+                    &group_names,
+                    val,
+                ),
                 Value::Object(_) => {
                     // This will return the matched substring first, then captured parenthesized groups later
-                    let mut results: Vec<Value> = mat
-                        .groups()
-                        .map(|group| match group {
-                            Some(range) => Value::from(&primitive_val[range]),
-                            None => Value::undefined(),
-                        })
-                        .collect();
-
-                    // Returns the starting byte offset of the match
-                    let start = mat.start();
-                    results.push(Value::from(start));
+                    let mut results = vec![Value::from(&primitive_val[matched.clone()])];
+                    results.extend(caps.iter().map(|group| match group {
+                        Some(range) => Value::from(&primitive_val[range.clone()]),
+                        None => Value::undefined(),
+                    }));
+
+                    // Push the UTF-16 index of the match, not its byte offset
+                    let position = primitive_val[..matched.start].encode_utf16().count();
+                    results.push(Value::from(position));
                     // Push the whole string being examined
                     results.push(Value::from(primitive_val.to_string()));
 
@@ -695,11 +785,102 @@ impl String {
             "undefined".to_string()
         };
 
-        Ok(Value::from(primitive_val.replacen(
-            &primitive_val[mat.range()],
-            &replace_value,
-            1,
-        )))
+        let mut result = StdString::with_capacity(primitive_val.len());
+        result.push_str(&primitive_val[..matched.start]);
+        result.push_str(&replace_value);
+        result.push_str(&primitive_val[matched.end..]);
+        Ok(Value::from(result))
+    }
+
+    /// `String.prototype.replaceAll( regexp|substr, newSubstr|function )`
+    ///
+    /// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+    ///
+    /// The `pattern` can be a string or a global `RegExp`, and the `replacement` can be a string or a function to be called for each match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+    pub(crate) fn replace_all(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = this.require_object_coercible(context)?;
+        let string = object.to_string(context)?;
+        let search_value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let replace_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        // Each match is stored as the range of the whole match followed by its capture groups.
+        let regexp = search_value
+            .as_object()
+            .and_then(|object| object.borrow().as_regexp().cloned());
+        let group_names = regexp
+            .as_ref()
+            .map_or_else(Vec::new, |regexp| regexp.group_names().to_vec());
+        let matches: Vec<_> = match regexp {
+            Some(regexp) if !regexp.global => {
+                return context.throw_type_error(
+                    "String.prototype.replaceAll called with a non-global RegExp argument",
+                );
+            }
+            Some(regexp) => regexp
+                .matcher
+                .find_iter(&string)
+                .map(|mat| (mat.range(), mat.captures))
+                .collect(),
+            None => {
+                let search_string = search_value.to_string(context)?;
+                string
+                    .match_indices(search_string.as_str())
+                    .map(|(position, matched)| (position..position + matched.len(), Vec::new()))
+                    .collect()
+            }
+        };
+
+        let replace_string = if replace_value.is_function() {
+            None
+        } else {
+            Some(replace_value.to_string(context)?)
+        };
+
+        let mut result = StdString::with_capacity(string.len());
+        let mut end_of_last_match = 0;
+        for (matched, captures) in matches {
+            let replacement = if let Some(ref replace_string) = replace_string {
+                Self::get_substitution(
+                    &string,
+                    matched.clone(),
+                    &captures,
+                    &group_names,
+                    replace_string,
+                )
+            } else {
+                let mut arguments = vec![Value::from(&string[matched.clone()])];
+                arguments.extend(captures.iter().map(|capture| match capture {
+                    Some(range) => Value::from(&string[range.clone()]),
+                    None => Value::undefined(),
+                }));
+                let position = string[..matched.start].encode_utf16().count();
+                arguments.push(Value::from(position));
+                arguments.push(Value::from(string.clone()));
+
+                context
+                    .call(&replace_value, &Value::undefined(), &arguments)?
+                    .to_string(context)?
+                    .to_string()
+            };
+
+            result.push_str(&string[end_of_last_match..matched.start]);
+            result.push_str(&replacement);
+            end_of_last_match = matched.end;
+        }
+        result.push_str(&string[end_of_last_match..]);
+
+        Ok(Value::from(result))
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -813,32 +994,52 @@ impl String {
     /// Performs the actual string padding for padStart/End.
     /// <https://tc39.es/ecma262/#sec-stringpad/>
     fn string_pad(
-        primitive: RcString,
-        max_length: i32,
-        fill_string: Option<RcString>,
+        object: &Value,
+        max_length: &Value,
+        fill_string: &Value,
         at_start: bool,
+        context: &mut Context,
     ) -> Result<Value> {
-        let primitive_length = primitive.len() as i32;
+        let primitive = object.to_string(context)?;
+        let max_length = max_length.to_length(context)?;
+        let primitive_length = primitive.encode_utf16().count();
 
         if max_length <= primitive_length {
             return Ok(Value::from(primitive));
         }
 
-        let filter = fill_string.as_deref().unwrap_or(" ");
+        let filler = if fill_string.is_undefined() {
+            RcString::from(" ")
+        } else {
+            fill_string.to_string(context)?
+        };
 
-        let fill_len = max_length.wrapping_sub(primitive_length);
-        let mut fill_str = StdString::new();
+        if filler.is_empty() {
+            return Ok(Value::from(primitive));
+        }
 
-        while fill_str.len() < fill_len as usize {
-            fill_str.push_str(filter);
+        if max_length as f64 > Self::MAX_STRING_LENGTH {
+            return context
+                .throw_range_error("padded string must not overflow maximum string length");
         }
-        // Cut to size max_length
-        let concat_fill_str: StdString = fill_str.chars().take(fill_len as usize).collect();
+
+        // Repeat the filler and cut it to size, counting in UTF-16 code units.
+        let fill_len = max_length - primitive_length;
+        let fill_units: Vec<u16> = filler.encode_utf16().cycle().take(fill_len).collect();
+        // Strings are stored as UTF-8, which can not hold the lone leading surrogate left by a cut
+        // through a surrogate pair, so it becomes a replacement character of the same length.
+        let truncated_fill_string = StdString::from_utf16_lossy(&fill_units);
 
         if at_start {
-            Ok(Value::from(format!("{}{}", concat_fill_str, &primitive)))
+            Ok(Value::from(format!(
+                "{}{}",
+                truncated_fill_string, primitive
+            )))
         } else {
-            Ok(Value::from(format!("{}{}", primitive, &concat_fill_str)))
+            Ok(Value::from(format!(
+                "{}{}",
+                primitive, truncated_fill_string
+            )))
         }
     }
 
@@ -855,18 +1056,11 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd
     pub(crate) fn pad_end(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padEnd requires maxLength argument"));
-        }
-        let max_length = args
-            .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
-
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
+        let object = this.require_object_coercible(context)?;
+        let max_length = args.get(0).cloned().unwrap_or_default();
+        let fill_string = args.get(1).cloned().unwrap_or_default();
 
-        Self::string_pad(primitive, max_length, fill_string, false)
+        Self::string_pad(object, &max_length, &fill_string, false, context)
     }
 
     /// `String.prototype.padStart( targetLength [, padString] )`
@@ -882,23 +1076,16 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart
     pub(crate) fn pad_start(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padStart requires maxLength argument"));
-        }
-        let max_length = args
-            .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
-
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
+        let object = this.require_object_coercible(context)?;
+        let max_length = args.get(0).cloned().unwrap_or_default();
+        let fill_string = args.get(1).cloned().unwrap_or_default();
 
-        Self::string_pad(primitive, max_length, fill_string, true)
+        Self::string_pad(object, &max_length, &fill_string, true, context)
     }
 
     /// Helper function to check if a `char` is trimmable.
     #[inline]
-    fn is_trimmable_whitespace(c: char) -> bool {
+    pub(crate) fn is_trimmable_whitespace(c: char) -> bool {
         // The rust implementation of `trim` does not regard the same characters whitespace as ecma standard does
         //
         // Rust uses \p{White_Space} by default, which also includes:
@@ -1152,34 +1339,32 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/matchAll
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     /// [cg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions/Groups_and_Ranges
-    // TODO: update this method to return iterator
     pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let re: Value = match args.get(0) {
-            Some(arg) => {
-                if arg.is_null() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::from(arg.to_string(context)?), Value::from("g")],
-                        context,
-                    )
-                } else if arg.is_undefined() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::undefined(), Value::from("g")],
-                        context,
-                    )
-                } else {
-                    Ok(arg.clone())
-                }
+        let object = this.require_object_coercible(context)?;
+        let global = args
+            .get(0)
+            .and_then(Value::as_object)
+            .and_then(|object| object.borrow().as_regexp().map(|regex| regex.global));
+        let re = match (args.get(0), global) {
+            (Some(arg), Some(true)) => arg.clone(),
+            (_, Some(false)) => {
+                return context.throw_type_error(
+                    "String.prototype.matchAll called with a non-global RegExp argument",
+                )
             }
-            None => RegExp::constructor(
+            (Some(arg), None) if !arg.is_undefined() => RegExp::constructor(
+                &Value::from(Object::default()),
+                &[Value::from(arg.to_string(context)?), Value::from("g")],
+                context,
+            )?,
+            _ => RegExp::constructor(
                 &Value::from(Object::default()),
                 &[Value::from(""), Value::from("g")],
                 context,
-            ),
-        }?;
+            )?,
+        };
 
-        RegExp::match_all(&re, this.to_string(context)?.to_string())
+        RegExp::match_all(&re, object.to_string(context)?, context)
     }
 
     pub(crate) fn iterator(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
@@ -206,6 +206,31 @@ fn replace() {
     assert_eq!(forward(&mut context, "a"), "\"2bc\"");
 }
 
+#[test]
+fn replace_named_groups() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a1'.replace(/(?<d>\\d)/, 'x')"),
+        "\"ax\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a1b2'.replace(/(?<d>\\d)/, '[$<d>]')"),
+        "\"a[1]b2\""
+    );
+}
+
+#[test]
+fn replace_string_pattern_is_literal() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a.b.'.replace('.', '!')"),
+        "\"a!b.\""
+    );
+    assert_eq!(forward(&mut context, "'a(b'.replace('(', '')"), "\"ab\"");
+}
+
 #[test]
 fn replace_no_match() {
     let mut context = Context::new();
@@ -298,6 +323,148 @@ fn replace_with_function() {
     assert_eq!(forward(&mut context, "length"), "14");
 }
 
+#[test]
+fn replace_with_function_position_is_utf16_index() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'é1'.replace(/1/, (m, i) => i)"),
+        "\"é1\""
+    );
+    assert_eq!(
+        forward(&mut context, "'é😀1'.replace('1', (m, i) => i)"),
+        "\"é😀3\""
+    );
+    assert_eq!(
+        forward(&mut context, "'é1é1'.replaceAll('1', (m, i) => i)"),
+        "\"é1é3\""
+    );
+}
+
+#[test]
+fn replace_all_string() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a-b-c'.replaceAll('-', '+')"),
+        "\"a+b+c\""
+    );
+    assert_eq!(
+        forward(&mut context, "'aaa'.replaceAll('aa', 'b')"),
+        "\"ba\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.replaceAll('', '_')"),
+        "\"_a_b_c_\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.replaceAll('d', '_')"),
+        "\"abc\""
+    );
+}
+
+#[test]
+fn replace_all_regexp() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a1b22c333'.replaceAll(/\\d+/g, '#')"),
+        "\"a#b#c#\""
+    );
+    assert_eq!(
+        forward(&mut context, "'ab'.replaceAll(/x*/g, '-')"),
+        "\"-a-b-\""
+    );
+}
+
+#[test]
+fn replace_all_substitutions() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a1b22'.replaceAll(/\\d+/g, '[$&]')"),
+        "\"a[1]b[22]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'john smith, jane doe'.replaceAll(/(\\w+) (\\w+)/g, '$2 $1')"
+        ),
+        "\"smith john, doe jane\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a.b'.replaceAll('.', '$$')"),
+        "\"a$b\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a.b'.replaceAll('.', '($`|$\\')')"),
+        "\"a(a|b)b\""
+    );
+}
+
+#[test]
+fn replace_all_named_substitutions() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a1b2'.replaceAll(/(?<d>\\d)/g, '[$<d>]')"),
+        "\"a[1]b[2]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'ab'.replaceAll(/(?<d>\\d)|(?<w>b)/g, '[$<d>|$<w>|$<x>]')"
+        ),
+        "\"a[|b|]\""
+    );
+    // An unclosed name is left as is.
+    assert_eq!(
+        forward(&mut context, "'a1'.replaceAll(/(?<d>\\d)/g, '$<d')"),
+        "\"a$<d\""
+    );
+    // Without named groups, `$<name>` is left as is.
+    assert_eq!(
+        forward(&mut context, "'a1'.replaceAll(/(\\d)/g, '$<d>')"),
+        "\"a$<d>\""
+    );
+}
+
+#[test]
+fn replace_all_with_function() {
+    let mut context = Context::new();
+    let init = r#"
+        var positions = [];
+        var a = "x1y2".replaceAll(/\d/g, (match, position) => {
+            positions.push(position);
+            return match * 2;
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"x2y4\"");
+    assert_eq!(forward(&mut context, "positions.length"), "2");
+    assert_eq!(forward(&mut context, "positions[0]"), "1");
+    assert_eq!(forward(&mut context, "positions[1]"), "3");
+}
+
+#[test]
+fn replace_all_non_global_regexp() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        try {
+            'aa'.replaceAll(/a/, 'b');
+        } catch (e) {
+            result = e instanceof TypeError;
+        }
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
 #[test]
 fn starts_with() {
     let mut context = Context::new();
@@ -434,14 +601,23 @@ fn includes_with_regex_arg() {
 fn match_all() {
     let mut context = Context::new();
 
-    assert_eq!(forward(&mut context, "'aa'.matchAll(null).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/b/).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/).length"), "1");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/g).length"), "2");
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(null)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/b/g)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/a/g)].length"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "[...'aa'.matchAll('a')].length"), "2");
 
     forward(
         &mut context,
-        "var groupMatches = 'test1test2'.matchAll(/t(e)(st(\\d?))/g)",
+        "var groupMatches = [...'test1test2'.matchAll(/t(e)(st(\\d?))/g)]",
     );
 
     assert_eq!(forward(&mut context, "groupMatches.length"), "2");
@@ -450,18 +626,10 @@ fn match_all() {
     assert_eq!(forward(&mut context, "groupMatches[0][3]"), "\"1\"");
     assert_eq!(forward(&mut context, "groupMatches[1][3]"), "\"2\"");
 
-    assert_eq!(
-        forward(
-            &mut context,
-            "'test1test2'.matchAll(/t(e)(st(\\d?))/).length"
-        ),
-        "1"
-    );
-
     let init = r#"
         var regexp = RegExp('foo[a-z]*','g');
         var str = 'table football, foosball';
-        var matches = str.matchAll(regexp);
+        var matches = [...str.matchAll(regexp)];
         "#;
 
     forward(&mut context, init);
@@ -472,6 +640,62 @@ fn match_all() {
     assert_eq!(forward(&mut context, "matches[1].index"), "16");
 }
 
+#[test]
+fn match_all_iterates_matches() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var iterator = 'a1b22c333'.matchAll(/\d+/g);
+        var first = iterator.next();
+        var second = iterator.next();
+        var third = iterator.next();
+        var last = iterator.next();
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "first.value[0]"), "\"1\"");
+    assert_eq!(forward(&mut context, "first.value.index"), "1");
+    assert_eq!(forward(&mut context, "second.value[0]"), "\"22\"");
+    assert_eq!(forward(&mut context, "second.value.index"), "3");
+    assert_eq!(forward(&mut context, "third.value[0]"), "\"333\"");
+    assert_eq!(forward(&mut context, "third.value.index"), "6");
+    assert_eq!(forward(&mut context, "third.done"), "false");
+    assert_eq!(forward(&mut context, "last.done"), "true");
+}
+
+#[test]
+fn match_all_zero_width_matches() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "[...'abc'.matchAll(/x*/g)].length"),
+        "4"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'abc'.matchAll(/x*/g)][3].index"),
+        "3"
+    );
+}
+
+#[test]
+fn match_all_non_global_regexp() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var result;
+        try {
+            'aa'.matchAll(/a/);
+        } catch (e) {
+            result = e instanceof TypeError;
+        }
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result"), "true");
+}
+
 #[test]
 fn test_match() {
     let mut context = Context::new();
@@ -549,6 +773,133 @@ fn trim_end() {
     assert_eq!(forward(&mut context, "' Hello '.trimEnd()"), "\" Hello\"");
 }
 
+#[test]
+fn pad_start() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(10, 'foo')"),
+        "\"foofoofabc\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(6, '123465')"),
+        "\"123abc\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padStart(5)"), "\"  abc\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(5, undefined)"),
+        "\"  abc\""
+    );
+    assert_eq!(forward(&mut context, "'5'.padStart(3, 0)"), "\"005\"");
+}
+
+#[test]
+fn pad_end() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "'abc'.padEnd(10, 'foo')"),
+        "\"abcfoofoof\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.padEnd(6, '123456')"),
+        "\"abc123\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padEnd(5)"), "\"abc  \"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padEnd(5, undefined)"),
+        "\"abc  \""
+    );
+}
+
+#[test]
+fn pad_no_op() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'abc'.padStart()"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd()"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(1, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(3, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(-1, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(10, '')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(10, '')"), "\"abc\"");
+}
+
+#[test]
+fn pad_throws_when_length_overflows_max_length() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+        try {
+            'a'.padStart(Infinity)
+        } catch (e) {
+            e.toString()
+        }
+    "#
+        ),
+        "\"RangeError: padded string must not overflow maximum string length\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+        try {
+            'a'.padEnd(2 ** 40, 'b')
+        } catch (e) {
+            e.toString()
+        }
+    "#
+        ),
+        "\"RangeError: padded string must not overflow maximum string length\""
+    );
+}
+
+#[test]
+fn raw() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, r#"String.raw`a\n${1}b`"#),
+        r#""a\n1b""#
+    );
+    assert_eq!(forward(&mut context, r#"String.raw`a\n${1}b`.length"#), "5");
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: ['x', 'y', 'z'] }, 1)"),
+        "\"x1yz\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: ['x', 'y'] }, 1, 2, 3)"),
+        "\"x1y\""
+    );
+    assert_eq!(forward(&mut context, "String.raw({ raw: [] }, 1)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "String.raw()"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn raw_with_invalid_escape() {
+    let mut context = Context::new();
+    let init = r#"
+        function tag(strings) {
+            return [strings[0], strings.raw[0], strings.length];
+        }
+        var result = tag`\unicode and \u{55}`;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result[0]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "result[1]"),
+        r#""\unicode and \u{55}""#
+    );
+    assert_eq!(forward(&mut context, "result[2]"), "1");
+    assert_eq!(
+        forward(&mut context, r#"String.raw`\xg${1}\01`"#),
+        r#""\xg1\01""#
+    );
+}
+
 #[test]
 fn index_of_with_no_arguments() {
     let mut context = Context::new();
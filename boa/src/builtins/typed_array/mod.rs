@@ -0,0 +1,503 @@
+//! This module implements the typed array objects, such as `Uint8Array` or `Float64Array`.
+//!
+//! A typed array is a view over an `ArrayBuffer`, which reads and writes its bytes as numbers of
+//! a given element type. Elements are always stored in little-endian byte order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        function::NativeFunction,
+        iterable::get_iterator,
+        number::{f64_to_int32, f64_to_uint32},
+        ArrayBuffer, BuiltIn, Number,
+    },
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, ObjectData},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+use std::{convert::TryInto, ops::Range};
+
+#[cfg(test)]
+mod tests;
+
+/// The panic message for element bytes that don't have the size of the element type.
+const ELEMENT_SIZE: &str = "element has the size of its type";
+
+/// The element type of a typed array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+}
+
+impl TypedArrayKind {
+    /// Gets the name of the typed array constructor for this element type.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+        }
+    }
+
+    /// Gets the size of an element in bytes.
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 => 8,
+        }
+    }
+
+    /// Reads an element from its bytes.
+    fn decode(self, bytes: &[u8]) -> Value {
+        match self {
+            Self::Int8 => i32::from(bytes[0] as i8).into(),
+            Self::Uint8 | Self::Uint8Clamped => i32::from(bytes[0]).into(),
+            Self::Int16 => {
+                i32::from(i16::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE))).into()
+            }
+            Self::Uint16 => {
+                i32::from(u16::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE))).into()
+            }
+            Self::Int32 => i32::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE)).into(),
+            Self::Uint32 => u32::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE)).into(),
+            Self::Float32 => {
+                f64::from(f32::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE))).into()
+            }
+            Self::Float64 => f64::from_le_bytes(bytes.try_into().expect(ELEMENT_SIZE)).into(),
+        }
+    }
+
+    /// Writes the number to the bytes of an element, wrapping it around or clamping it to the
+    /// range of the element type.
+    fn encode(self, number: f64, bytes: &mut [u8]) {
+        match self {
+            Self::Int8 => bytes.copy_from_slice(&(f64_to_int32(number) as i8).to_le_bytes()),
+            Self::Uint8 => bytes.copy_from_slice(&(f64_to_uint32(number) as u8).to_le_bytes()),
+            Self::Uint8Clamped => bytes[0] = Self::clamp_to_uint8(number),
+            Self::Int16 => bytes.copy_from_slice(&(f64_to_int32(number) as i16).to_le_bytes()),
+            Self::Uint16 => bytes.copy_from_slice(&(f64_to_uint32(number) as u16).to_le_bytes()),
+            Self::Int32 => bytes.copy_from_slice(&f64_to_int32(number).to_le_bytes()),
+            Self::Uint32 => bytes.copy_from_slice(&f64_to_uint32(number).to_le_bytes()),
+            Self::Float32 => bytes.copy_from_slice(&(number as f32).to_le_bytes()),
+            Self::Float64 => bytes.copy_from_slice(&number.to_le_bytes()),
+        }
+    }
+
+    /// The abstract operation `ToUint8Clamp`, rounding halfway cases to even.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+    fn clamp_to_uint8(number: f64) -> u8 {
+        if number.is_nan() || number <= 0.0 {
+            return 0;
+        }
+        if number >= 255.0 {
+            return 255;
+        }
+
+        let floor = number.floor();
+        let rounded = if number - floor > 0.5 || (number - floor == 0.5 && floor % 2.0 != 0.0) {
+            floor + 1.0
+        } else {
+            floor
+        };
+        rounded as u8
+    }
+}
+
+/// The internal representation of a typed array object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    buffer: GcObject,
+    byte_offset: usize,
+    length: usize,
+}
+
+impl TypedArray {
+    /// The length of the typed array constructors.
+    pub(crate) const LENGTH: usize = 3;
+
+    /// Gets the element type of the typed array.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// Gets the number of elements of the typed array.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Builds the constructor object of the typed arrays of the given element type.
+    fn init_constructor(
+        context: &mut Context,
+        kind: TypedArrayKind,
+        constructor: NativeFunction,
+    ) -> Value {
+        let bytes_per_element = kind.element_size();
+
+        ConstructorBuilder::new(context, constructor)
+            .name(kind.name())
+            .length(Self::LENGTH)
+            .property(
+                "BYTES_PER_ELEMENT",
+                bytes_per_element,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            )
+            .static_property(
+                "BYTES_PER_ELEMENT",
+                bytes_per_element,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            )
+            .callable(false)
+            .build()
+            .into()
+    }
+
+    /// Create a new typed array
+    ///
+    /// The first argument can be a length, an array buffer to view with an optional byte offset
+    /// and length, or an object to copy the elements of, either an iterable or an array-like.
+    fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+        kind: TypedArrayKind,
+    ) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Object(object)) if object.borrow().is_array_buffer() => {
+                let (byte_offset, length) = Self::view_bounds(object, args, kind, context)?;
+                Self::initialize(this, kind, object.clone(), byte_offset, length);
+            }
+            Some(Value::Object(object)) => {
+                let values = Self::values_from_object(object, context)?;
+                let buffer = Self::create_buffer(values.len(), kind, context)?;
+                Self::initialize(this, kind, buffer, 0, values.len());
+
+                let this_object = this.as_object().expect("this is typed array object");
+                for (index, value) in values.iter().enumerate() {
+                    Self::set_element(&this_object, index as f64, value, context)?;
+                }
+            }
+            length => {
+                let length = length
+                    .cloned()
+                    .unwrap_or_else(Value::undefined)
+                    .to_index(context)?;
+                let buffer = Self::create_buffer(length, kind, context)?;
+                Self::initialize(this, kind, buffer, 0, length);
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// Creates an array buffer large enough for `length` elements of the given type.
+    fn create_buffer(
+        length: usize,
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<GcObject> {
+        match length.checked_mul(kind.element_size()) {
+            Some(byte_length) => ArrayBuffer::create(byte_length, context),
+            None => Err(context.construct_range_error("Array buffer allocation failed")),
+        }
+    }
+
+    /// Computes the byte offset and the length of a typed array viewing the given buffer, from
+    /// the `byteOffset` and `length` arguments of the constructor.
+    fn view_bounds(
+        buffer: &GcObject,
+        args: &[Value],
+        kind: TypedArrayKind,
+        context: &mut Context,
+    ) -> Result<(usize, usize)> {
+        let element_size = kind.element_size();
+        let byte_offset = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_index(context)?;
+        if byte_offset % element_size != 0 {
+            return Err(context.construct_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                kind.name(),
+                element_size
+            )));
+        }
+
+        let buffer_byte_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("object is an array buffer")
+            .byte_length();
+        let byte_length = match args.get(2) {
+            Some(length) if !length.is_undefined() => {
+                let byte_length = length.to_index(context)? * element_size;
+                if byte_offset + byte_length > buffer_byte_length {
+                    return Err(context.construct_range_error(format!(
+                        "invalid {} length {}",
+                        kind.name(),
+                        byte_length / element_size
+                    )));
+                }
+                byte_length
+            }
+            _ => {
+                if buffer_byte_length % element_size != 0 {
+                    return Err(context.construct_range_error(format!(
+                        "byte length of {} should be a multiple of {}",
+                        kind.name(),
+                        element_size
+                    )));
+                }
+                if byte_offset > buffer_byte_length {
+                    return Err(context.construct_range_error(format!(
+                        "start offset {} is outside the bounds of the buffer",
+                        byte_offset
+                    )));
+                }
+                buffer_byte_length - byte_offset
+            }
+        };
+
+        Ok((byte_offset, byte_length / element_size))
+    }
+
+    /// Collects the values of the object that a typed array is created from.
+    fn values_from_object(object: &GcObject, context: &mut Context) -> Result<Vec<Value>> {
+        let typed_array = object.borrow().as_typed_array().cloned();
+        if let Some(typed_array) = typed_array {
+            return Ok((0..typed_array.length)
+                .map(|index| typed_array.get(index as f64))
+                .collect());
+        }
+
+        let source = Value::from(object.clone());
+        let iterator_symbol = context.well_known_symbols().iterator_symbol();
+        if object.get_method(context, iterator_symbol)?.is_some() {
+            let iterator = get_iterator(context, source)?;
+            let mut values = Vec::new();
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    return Ok(values);
+                }
+                values.push(next.value());
+            }
+        }
+
+        let length = source
+            .get_field_with_accessors("length", context)?
+            .to_length(context)?;
+        (0..length)
+            .map(|index| source.get_field_with_accessors(index, context))
+            .collect()
+    }
+
+    /// Turns the object into a typed array viewing the given part of the buffer.
+    fn initialize(
+        object: &Value,
+        kind: TypedArrayKind,
+        buffer: GcObject,
+        byte_offset: usize,
+        length: usize,
+    ) {
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+        object.set_property("length", DataDescriptor::new(length, attribute));
+        object.set_property(
+            "byteLength",
+            DataDescriptor::new(length * kind.element_size(), attribute),
+        );
+        object.set_property("byteOffset", DataDescriptor::new(byte_offset, attribute));
+        object.set_property("buffer", DataDescriptor::new(buffer.clone(), attribute));
+
+        object.set_data(ObjectData::TypedArray(Self {
+            kind,
+            buffer,
+            byte_offset,
+            length,
+        }));
+    }
+
+    /// Gets the range of bytes of the element at the given index in the buffer, if the index is
+    /// a valid integer index for the typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    fn element_range(&self, index: f64) -> Option<Range<usize>> {
+        if index.fract() != 0.0
+            || (index == 0.0 && index.is_sign_negative())
+            || index < 0.0
+            || index >= self.length as f64
+        {
+            return None;
+        }
+
+        let start = self.byte_offset + index as usize * self.kind.element_size();
+        Some(start..start + self.kind.element_size())
+    }
+
+    /// Reads the element at the given index, or `undefined` if the index is out of range.
+    fn get(&self, index: f64) -> Value {
+        match self.element_range(index) {
+            Some(range) => {
+                let buffer = self.buffer.borrow();
+                let buffer = buffer.as_array_buffer().expect("buffer is an array buffer");
+                self.kind.decode(&buffer.data()[range])
+            }
+            None => Value::undefined(),
+        }
+    }
+
+    /// The `[[Get]]` internal method of typed arrays, for a numeric key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementget
+    pub(crate) fn get_element(object: &GcObject, index: f64) -> Value {
+        object
+            .borrow()
+            .as_typed_array()
+            .expect("object is a typed array")
+            .get(index)
+    }
+
+    /// The `[[Set]]` internal method of typed arrays, for a numeric key.
+    ///
+    /// The value is converted to a number even if the index is out of range, in which case the
+    /// write is ignored.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
+    pub(crate) fn set_element(
+        object: &GcObject,
+        index: f64,
+        value: &Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let number = value.to_number(context)?;
+
+        let typed_array = object
+            .borrow()
+            .as_typed_array()
+            .cloned()
+            .expect("object is a typed array");
+        if let Some(range) = typed_array.element_range(index) {
+            let mut buffer = typed_array.buffer.borrow_mut();
+            let buffer = buffer
+                .as_array_buffer_mut()
+                .expect("buffer is an array buffer");
+            typed_array
+                .kind
+                .encode(number, &mut buffer.data_mut()[range]);
+        }
+
+        Ok(())
+    }
+}
+
+/// The abstract operation `CanonicalNumericIndexString`, for any property key.
+///
+/// Returns the number that the key stands for, if the key is a number or the canonical string
+/// representation of a number. Typed arrays handle such keys as element indices, even when they
+/// are out of range.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+pub(crate) fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    match key {
+        PropertyKey::Index(index) => Some(f64::from(*index)),
+        PropertyKey::String(string) if string.as_ref() == "-0" => Some(-0.0),
+        PropertyKey::String(string) => {
+            let number = string.parse().ok()?;
+            if Number::to_native_string(number) == string.as_ref() {
+                Some(number)
+            } else {
+                None
+            }
+        }
+        PropertyKey::Symbol(_) => None,
+    }
+}
+
+/// Defines the global constructor object of the typed arrays of an element type.
+macro_rules! typed_array_constructor {
+    ($constructor:ident, $kind:ident, $name:expr) => {
+        /// The global constructor of the typed arrays of an element type.
+        #[derive(Debug, Clone, Copy)]
+        pub(crate) struct $constructor;
+
+        impl BuiltIn for $constructor {
+            const NAME: &'static str = $name;
+
+            fn attribute() -> Attribute {
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+            }
+
+            fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+                let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+                let constructor =
+                    TypedArray::init_constructor(context, TypedArrayKind::$kind, Self::constructor);
+
+                (Self::NAME, constructor, Self::attribute())
+            }
+        }
+
+        impl $constructor {
+            /// Create a new typed array
+            pub(crate) fn constructor(
+                this: &Value,
+                args: &[Value],
+                context: &mut Context,
+            ) -> Result<Value> {
+                TypedArray::constructor(this, args, context, TypedArrayKind::$kind)
+            }
+        }
+    };
+}
+
+typed_array_constructor!(Int8Array, Int8, "Int8Array");
+typed_array_constructor!(Uint8Array, Uint8, "Uint8Array");
+typed_array_constructor!(Uint8ClampedArray, Uint8Clamped, "Uint8ClampedArray");
+typed_array_constructor!(Int16Array, Int16, "Int16Array");
+typed_array_constructor!(Uint16Array, Uint16, "Uint16Array");
+typed_array_constructor!(Int32Array, Int32, "Int32Array");
+typed_array_constructor!(Uint32Array, Uint32, "Uint32Array");
+typed_array_constructor!(Float32Array, Float32, "Float32Array");
+typed_array_constructor!(Float64Array, Float64, "Float64Array");
@@ -0,0 +1,154 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_from_length() {
+    let mut context = Context::new();
+    forward(&mut context, "let array = new Int32Array(4);");
+    assert_eq!(forward(&mut context, "array.length"), "4");
+    assert_eq!(forward(&mut context, "array.byteLength"), "16");
+    assert_eq!(forward(&mut context, "array.byteOffset"), "0");
+    assert_eq!(forward(&mut context, "array.buffer.byteLength"), "16");
+    assert_eq!(forward(&mut context, "array[3]"), "0");
+    assert_eq!(forward(&mut context, "Int32Array.BYTES_PER_ELEMENT"), "4");
+    assert_eq!(forward(&mut context, "array.BYTES_PER_ELEMENT"), "4");
+}
+
+#[test]
+fn construct_from_array_and_iterable() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let fromArray = new Float64Array([1.5, 2, 3]);
+        let fromSet = new Uint8Array(new Set([4, 5]));
+        let fromArrayLike = new Int8Array({ length: 2, 0: 6, 1: 7 });
+        let fromTypedArray = new Int32Array(fromArray);
+        "#,
+    );
+    assert_eq!(forward(&mut context, "fromArray.length"), "3");
+    assert_eq!(forward(&mut context, "fromArray[0]"), "1.5");
+    assert_eq!(forward(&mut context, "fromSet[0] + fromSet[1]"), "9");
+    assert_eq!(forward(&mut context, "fromArrayLike[1]"), "7");
+    assert_eq!(forward(&mut context, "fromTypedArray[0]"), "1");
+}
+
+#[test]
+fn element_round_trip() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let int8 = new Int8Array(2);
+        let uint8 = new Uint8Array(2);
+        let clamped = new Uint8ClampedArray(4);
+        let int32 = new Int32Array(1);
+        let uint32 = new Uint32Array(1);
+        let float32 = new Float32Array(1);
+        let float64 = new Float64Array(1);
+        int8[0] = 127; int8[1] = 128;
+        uint8[0] = 255; uint8[1] = 256 + 7;
+        clamped[0] = 300; clamped[1] = -5; clamped[2] = 2.5; clamped[3] = 3.5;
+        int32[0] = 2147483648;
+        uint32[0] = -1;
+        float32[0] = 0.5;
+        float64[0] = 0.1;
+        "#,
+    );
+    assert_eq!(forward(&mut context, "int8[0]"), "127");
+    assert_eq!(forward(&mut context, "int8[1]"), "-128");
+    assert_eq!(forward(&mut context, "uint8[0]"), "255");
+    assert_eq!(forward(&mut context, "uint8[1]"), "7");
+    assert_eq!(forward(&mut context, "clamped[0]"), "255");
+    assert_eq!(forward(&mut context, "clamped[1]"), "0");
+    assert_eq!(forward(&mut context, "clamped[2]"), "2");
+    assert_eq!(forward(&mut context, "clamped[3]"), "4");
+    assert_eq!(forward(&mut context, "int32[0]"), "-2147483648");
+    assert_eq!(forward(&mut context, "uint32[0]"), "4294967295");
+    assert_eq!(forward(&mut context, "float32[0]"), "0.5");
+    assert_eq!(forward(&mut context, "float64[0]"), "0.1");
+}
+
+#[test]
+fn out_of_range_indices() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let array = new Uint8Array(2);
+        array[2] = 1;
+        array[-1] = 1;
+        array[1.5] = 1;
+        "#,
+    );
+    assert_eq!(forward(&mut context, "array[2]"), "undefined");
+    assert_eq!(forward(&mut context, "array[-1]"), "undefined");
+    assert_eq!(forward(&mut context, "array[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "array.length"), "2");
+    assert_eq!(forward(&mut context, "array[0] + array[1]"), "0");
+}
+
+#[test]
+fn little_endian_storage() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let buffer = new ArrayBuffer(4);
+        let words = new Uint32Array(buffer);
+        let bytes = new Uint8Array(buffer);
+        words[0] = 0x01020304;
+        "#,
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[bytes[0], bytes[1], bytes[2], bytes[3]].join()"
+        ),
+        "\"4,3,2,1\""
+    );
+    forward(&mut context, "bytes[3] = 0xff;");
+    assert_eq!(forward(&mut context, "words[0]"), "4278321924");
+}
+
+#[test]
+fn view_over_shared_buffer_with_offset() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let buffer = new ArrayBuffer(16);
+        let all = new Int16Array(buffer);
+        let tail = new Int16Array(buffer, 4);
+        let middle = new Int16Array(buffer, 4, 2);
+        middle[0] = 42;
+        tail[1] = -7;
+        "#,
+    );
+    assert_eq!(forward(&mut context, "tail.length"), "6");
+    assert_eq!(forward(&mut context, "tail.byteOffset"), "4");
+    assert_eq!(forward(&mut context, "middle.length"), "2");
+    assert_eq!(forward(&mut context, "middle.byteLength"), "4");
+    assert_eq!(forward(&mut context, "middle.buffer === buffer"), "true");
+    assert_eq!(forward(&mut context, "all[2]"), "42");
+    assert_eq!(forward(&mut context, "all[3]"), "-7");
+    assert_eq!(forward(&mut context, "middle[1]"), "-7");
+    assert_eq!(forward(&mut context, "middle[2]"), "undefined");
+}
+
+#[test]
+fn invalid_view_bounds() {
+    let mut context = Context::new();
+    forward(&mut context, "let buffer = new ArrayBuffer(8);");
+    for js in &[
+        "new Int32Array(buffer, 2)",
+        "new Int32Array(buffer, 4, 2)",
+        "new Int32Array(buffer, 12)",
+        "new Float64Array(new ArrayBuffer(7))",
+    ] {
+        let js = format!(
+            "try {{ {}; false }} catch (e) {{ e instanceof RangeError }}",
+            js
+        );
+        assert_eq!(forward(&mut context, &js), "true", "{}", js);
+    }
+}
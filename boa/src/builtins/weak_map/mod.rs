@@ -0,0 +1,197 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! The keys of a `WeakMap` are not held weakly by this implementation: the garbage collector
+//! traces them like the keys of a `Map`. Since a `WeakMap` cannot be iterated and its keys must
+//! be objects, this is not observable except by memory usage.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+#![allow(clippy::mutable_key_type)]
+
+use crate::{
+    builtins::{iterable::get_iterator, map::ordered_map::OrderedMap, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_map_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::delete, "delete", 1)
+            .method(Self::get, "get", 1)
+            .method(Self::has, "has", 1)
+            .method(Self::set, "set", 2)
+            .callable(false)
+            .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new weak map
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // Set Prototype
+        let prototype = context
+            .global_object()
+            .get_field("WeakMap")
+            .get_field(PROTOTYPE);
+
+        this.as_object()
+            .expect("this is weak map object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::WeakMap(OrderedMap::new()));
+
+        // add our arguments in
+        if let Some(iterable) = args.get(0) {
+            if !iterable.is_null_or_undefined() {
+                let iterator = get_iterator(context, iterable.clone())?;
+                loop {
+                    let next = iterator.next(context)?;
+                    if next.is_done() {
+                        break;
+                    }
+
+                    let entry = next.value();
+                    if !entry.is_object() {
+                        return Err(context.construct_type_error(
+                            "iterable for WeakMap should have array-like objects",
+                        ));
+                    }
+                    Self::set(this, &[entry.get_field(0), entry.get_field(1)], context)?;
+                }
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the element associated with the key, if it exists. Returns true if there was an element, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let key = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(map) = object.as_weak_map_mut() {
+                return Ok((key.is_object() && map.remove(key).is_some()).into());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let key = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let object = object.borrow();
+            if let Some(map) = object.as_weak_map_ref() {
+                return Ok(map.get(key).cloned().unwrap_or_else(Value::undefined));
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if the weak map contains an entry with the given key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let key = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let object = object.borrow();
+            if let Some(map) = object.as_weak_map_ref() {
+                return Ok(map.contains_key(key).into());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object. Returns the weak map object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let key = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        let value = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(map) = object.as_weak_map_mut() {
+                if !key.is_object() {
+                    return Err(context.construct_type_error("Invalid value used as weak map key"));
+                }
+                map.insert(key, value);
+                return Ok(this.clone());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakMap"))
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{forward, Context};
+
+#[test]
+fn object_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let key = {};
+        let other = {};
+        let map = new WeakMap([[key, "one"]]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "map.get(key)");
+    assert_eq!(result, "\"one\"");
+    let result = forward(&mut context, "map.has(other)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "map.set(other, 2) === map");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "map.get(other)");
+    assert_eq!(result, "2");
+    let result = forward(&mut context, "map.delete(key)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "map.has(key)");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn primitive_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        "#;
+    forward(&mut context, init);
+    let result = forward(
+        &mut context,
+        "try { map.set(1, 'one') } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+    let result = forward(
+        &mut context,
+        "try { new WeakMap([['a', 1]]) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "map.get(1)");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "map.has(1)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "map.delete(1)");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn not_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "map.forEach");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "map.size");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "map[Symbol.iterator]");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "map.keys");
+    assert_eq!(result, "undefined");
+}
@@ -0,0 +1,165 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! The values of a `WeakSet` are not held weakly by this implementation: the garbage collector
+//! traces them like the values of a `Set`. Since a `WeakSet` cannot be iterated and its values
+//! must be objects, this is not observable except by memory usage.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+#![allow(clippy::mutable_key_type)]
+
+use crate::{
+    builtins::{iterable::get_iterator, set::ordered_set::OrderedSet, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let weak_set_object = ConstructorBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::add, "add", 1)
+            .method(Self::delete, "delete", 1)
+            .method(Self::has, "has", 1)
+            .callable(false)
+            .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new weak set
+    pub(crate) fn constructor(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // Set Prototype
+        let prototype = context
+            .global_object()
+            .get_field("WeakSet")
+            .get_field(PROTOTYPE);
+
+        this.as_object()
+            .expect("this is weak set object")
+            .set_prototype_instance(prototype);
+
+        this.set_data(ObjectData::WeakSet(OrderedSet::new()));
+
+        // add our arguments in
+        if let Some(iterable) = args.get(0) {
+            if !iterable.is_null_or_undefined() {
+                let iterator = get_iterator(context, iterable.clone())?;
+                loop {
+                    let next = iterator.next(context)?;
+                    if next.is_done() {
+                        break;
+                    }
+
+                    Self::add(this, &[next.value()], context)?;
+                }
+            }
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// This method appends the value, which must be an object, to the weak set. Returns the weak set object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_else(Value::undefined);
+
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_weak_set_mut() {
+                if !value.is_object() {
+                    return Err(context.construct_type_error("Invalid value used in weak set"));
+                }
+                set.insert(value);
+                return Ok(this.clone());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakSet"))
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// This method removes the value, if it exists. Returns true if there was such a value, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let value = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let mut object = object.borrow_mut();
+            if let Some(set) = object.as_weak_set_mut() {
+                return Ok((value.is_object() && set.remove(value)).into());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakSet"))
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// This method checks if the weak set contains the given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let undefined = Value::Undefined;
+        let value = args.get(0).unwrap_or(&undefined);
+
+        if let Value::Object(ref object) = this {
+            let object = object.borrow();
+            if let Some(set) = object.as_weak_set_ref() {
+                return Ok(set.contains(value).into());
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakSet"))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{forward, Context};
+
+#[test]
+fn object_values() {
+    let mut context = Context::new();
+    let init = r#"
+        let value = {};
+        let other = {};
+        let set = new WeakSet([value]);
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.has(value)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.has(other)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "set.add(other) === set");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.delete(value)");
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.has(value)");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn primitive_values() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new WeakSet();
+        "#;
+    forward(&mut context, init);
+    let result = forward(
+        &mut context,
+        "try { set.add(1) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+    let result = forward(
+        &mut context,
+        "try { new WeakSet(['a']) } catch (e) { e instanceof TypeError }",
+    );
+    assert_eq!(result, "true");
+    let result = forward(&mut context, "set.has(1)");
+    assert_eq!(result, "false");
+    let result = forward(&mut context, "set.delete(1)");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn not_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new WeakSet();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "set.forEach");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "set.size");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "set[Symbol.iterator]");
+    assert_eq!(result, "undefined");
+    let result = forward(&mut context, "set.values");
+    assert_eq!(result, "undefined");
+}
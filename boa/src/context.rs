@@ -3,13 +3,14 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        function::{BuiltInClosure, ClosureFunction, Function, FunctionFlags, NativeFunction},
         iterable::IteratorPrototypes,
+        proxy::Proxy,
         symbol::{Symbol, WellKnownSymbols},
     },
     class::{Class, ClassBuilder},
-    exec::Interpreter,
-    object::{GcObject, Object, ObjectData, PROTOTYPE},
+    exec::{Interpreter, InterpreterState},
+    object::{GcObject, Object, PROTOTYPE},
     property::{Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     syntax::{
@@ -25,7 +26,10 @@ use crate::{
     value::{RcString, RcSymbol, Value},
     BoaProfiler, Executable, Result,
 };
-use std::result::Result as StdResult;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug},
+};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
@@ -166,6 +170,23 @@ impl StandardObjects {
     }
 }
 
+/// The body of a job, run once with the context.
+type JobFunction = dyn FnOnce(&mut Context) -> Result<()>;
+
+/// A job of the job queue, such as a promise reaction.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobs
+struct Job(Box<JobFunction>);
+
+impl Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[job]")
+    }
+}
+
 /// Javascript context. It is the primary way to interact with the runtime.
 ///
 /// `Context`s constructed in a thread share the same runtime, therefore it
@@ -196,6 +217,9 @@ pub struct Context {
 
     /// Cached standard objects and their prototypes
     standard_objects: StandardObjects,
+
+    /// Jobs waiting to be run once the current script has finished running.
+    job_queue: VecDeque<Job>,
 }
 
 impl Default for Context {
@@ -212,6 +236,7 @@ impl Default for Context {
             well_known_symbols,
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
+            job_queue: VecDeque::new(),
         };
 
         // Add new builtIns to Context Realm
@@ -509,6 +534,37 @@ impl Context {
         Ok(function)
     }
 
+    /// Create a new builtin closure, which unlike a builtin function is called with the given
+    /// captured value.
+    pub fn create_builtin_closure<C>(
+        &mut self,
+        name: &str,
+        length: usize,
+        captures: C,
+        body: ClosureFunction,
+    ) -> GcObject
+    where
+        C: Into<Value>,
+    {
+        let function_prototype = self
+            .global_object()
+            .get_field("Function")
+            .get_field(PROTOTYPE);
+
+        let mut function = GcObject::new(Object::function(
+            Function::Closure {
+                function: BuiltInClosure(body),
+                captures: captures.into(),
+                flags: FunctionFlags::CALLABLE,
+            },
+            function_prototype,
+        ));
+        function.set("length".into(), length.into());
+        function.set("name".into(), name.into());
+
+        function
+    }
+
     /// Register a global function.
     #[inline]
     pub fn register_global_function(
@@ -522,73 +578,74 @@ impl Context {
         Ok(())
     }
 
-    /// Converts an array object into a rust vector of values.
-    ///
-    /// This is useful for the spread operator, for any other object an `Err` is returned
-    /// TODO: Not needed for spread of arrays. Check in the future for Map and remove if necessary
-    pub(crate) fn extract_array_properties(&mut self, value: &Value) -> StdResult<Vec<Value>, ()> {
-        if let Value::Object(ref x) = value {
-            // Check if object is array
-            if let ObjectData::Array = x.borrow().data {
-                let length = value.get_field("length").as_number().unwrap() as i32;
-                let values = (0..length)
-                    .map(|idx| value.get_field(idx.to_string()))
-                    .collect();
-                return Ok(values);
+    /// https://tc39.es/ecma262/#sec-hasproperty
+    #[inline]
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
+        match obj {
+            Value::Object(ref object) if object.is_proxy() => Proxy::has(object, key.clone(), self),
+            Value::Object(ref object) => Ok(object.has_property(key)),
+            _ => Ok(false),
+        }
+    }
+
+    /// https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    #[inline]
+    pub(crate) fn delete_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
+        match obj {
+            Value::Object(ref object) if object.is_proxy() => {
+                Proxy::delete_property(object, key.clone(), self)
             }
-            // Check if object is a Map
-            else if let ObjectData::Map(ref map) = x.borrow().data {
-                let values = map
-                    .iter()
-                    .map(|(key, value)| {
-                        // Construct a new array containing the key-value pair
-                        let array = Value::new_object(Some(
-                            &self
-                                .realm()
-                                .environment
-                                .get_global_object()
-                                .expect("Could not get global object"),
-                        ));
-                        array.set_data(ObjectData::Array);
-                        array.as_object().expect("object").set_prototype_instance(
-                            self.realm()
-                                .environment
-                                .get_binding_value("Array")
-                                .expect("Array was not initialized")
-                                .get_field(PROTOTYPE),
-                        );
-                        array.set_field("0", key);
-                        array.set_field("1", value);
-                        array.set_field("length", Value::from(2));
-                        array
-                    })
-                    .collect();
-                return Ok(values);
+            Value::Object(ref object) if object.is_mapped_arguments() => {
+                let deleted = object.clone().delete(key);
+                if let (true, PropertyKey::Index(index)) = (deleted, key) {
+                    if let Some(arguments) = object.borrow_mut().as_mapped_arguments_mut() {
+                        arguments.unmap(*index);
+                    }
+                }
+                Ok(deleted)
             }
-
-            return Err(());
+            Value::Object(ref object) => Ok(object.clone().delete(key)),
+            _ => Ok(true),
         }
-
-        Err(())
     }
 
-    /// https://tc39.es/ecma262/#sec-hasproperty
-    #[inline]
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
-        if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
-        } else {
-            false
+    /// Sets the value of the closest binding with the given name.
+    ///
+    /// Throws a `ReferenceError` if the binding is not initialized yet, and a `TypeError` if it
+    /// is immutable, like the binding of a `const` declaration.
+    pub(crate) fn set_mutable_binding(
+        &mut self,
+        name: &str,
+        value: Value,
+        strict: bool,
+    ) -> Result<()> {
+        let env = self
+            .realm
+            .environment
+            .environments()
+            .find(|env| env.borrow().has_binding(name))
+            .cloned();
+        if let Some(env) = env {
+            if !env.borrow().is_initialized_binding(name) {
+                return Err(self.construct_reference_error(name));
+            }
+            if !env.borrow().is_mutable_binding(name) {
+                return Err(self
+                    .construct_type_error(format!("assignment to constant variable '{}'", name)));
+            }
         }
+
+        self.realm
+            .environment
+            .set_mutable_binding(name, value, strict);
+        Ok(())
     }
 
     #[inline]
     pub(crate) fn set_value(&mut self, node: &Node, value: Value) -> Result<Value> {
         match node {
             Node::Identifier(ref name) => {
-                self.realm
-                    .environment
-                    .set_mutable_binding(name.as_ref(), value.clone(), true);
+                self.set_mutable_binding(name.as_ref(), value.clone(), true)?;
                 Ok(value)
             }
             Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
@@ -677,6 +734,27 @@ impl Context {
     /// ```
     #[allow(clippy::unit_arg, clippy::drop_copy)]
     #[inline]
+    /// Adds a job to the job queue, to be run once the current script has finished running.
+    pub(crate) fn enqueue_job<F>(&mut self, job: F)
+    where
+        F: FnOnce(&mut Context) -> Result<()> + 'static,
+    {
+        self.job_queue.push_back(Job(Box::new(job)));
+    }
+
+    /// Runs the jobs of the job queue in order, including the jobs that they enqueue, until the
+    /// queue is empty.
+    ///
+    /// This is done after every script run by [`eval`](#method.eval).
+    pub fn run_jobs(&mut self) -> Result<()> {
+        while let Some(Job(job)) = self.job_queue.pop_front() {
+            self.executor.set_current_state(InterpreterState::Executing);
+            job(self)?;
+        }
+
+        Ok(())
+    }
+
     pub fn eval(&mut self, src: &str) -> Result<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
 
@@ -685,7 +763,11 @@ impl Context {
             .map_err(|e| e.to_string());
 
         let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
+            Ok(statement_list) => {
+                let result = statement_list.run(self);
+                let jobs_result = self.run_jobs();
+                result.and_then(|value| jobs_result.map(|()| value))
+            }
             Err(e) => self.throw_syntax_error(e),
         };
 
@@ -41,6 +41,20 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         self.env_rec.contains_key(name)
     }
 
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        match self.env_rec.get(name) {
+            Some(binding) => binding.value.is_some(),
+            None => true,
+        }
+    }
+
+    fn is_mutable_binding(&self, name: &str) -> bool {
+        match self.env_rec.get(name) {
+            Some(binding) => binding.mutable,
+            None => true,
+        }
+    }
+
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
         if self.env_rec.contains_key(&name) {
             // TODO: change this when error handling comes into play
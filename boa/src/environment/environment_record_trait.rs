@@ -23,6 +23,14 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Determine if an Environment Record has a binding for the String value N. Return true if it does and false if it does not.
     fn has_binding(&self, name: &str) -> bool;
 
+    /// Determine if the binding for the String value N has been initialized.
+    /// Return false if the binding exists but is still uninitialized, and true otherwise.
+    fn is_initialized_binding(&self, name: &str) -> bool;
+
+    /// Determine if the binding for the String value N can be set.
+    /// Return false if the binding exists and is immutable, and true otherwise.
+    fn is_mutable_binding(&self, name: &str) -> bool;
+
     /// Create a new but uninitialized mutable binding in an Environment Record. The String value N is the text of the bound name.
     /// If the Boolean argument deletion is true the binding may be subsequently deleted.
     fn create_mutable_binding(&mut self, name: String, deletion: bool);
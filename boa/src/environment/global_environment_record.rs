@@ -101,6 +101,14 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         self.object_record.has_binding(name)
     }
 
+    fn is_initialized_binding(&self, name: &str) -> bool {
+        self.declarative_record.is_initialized_binding(name)
+    }
+
+    fn is_mutable_binding(&self, name: &str) -> bool {
+        self.declarative_record.is_mutable_binding(name)
+    }
+
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
         if self.declarative_record.has_binding(&name) {
             // TODO: change to exception
@@ -127,6 +135,10 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
             return self.declarative_record.initialize_binding(name, value);
         }
 
+        if self.object_record.has_binding(&name) {
+            return self.object_record.initialize_binding(name, value);
+        }
+
         panic!("Should not initialized binding without creating first.");
     }
 
@@ -14,6 +14,7 @@ use crate::{
         object_environment_record::ObjectEnvironmentRecord,
     },
     object::GcObject,
+    property::{Attribute, DataDescriptor},
     BoaProfiler, Value,
 };
 use gc::{Gc, GcCell};
@@ -138,6 +139,22 @@ impl LexicalEnvironment {
                     })
                     .expect("No function or global environment");
 
+                // Variables declared in the global scope are properties of the global object.
+                // <https://tc39.es/ecma262/#sec-createglobalvarbinding>
+                let env_type = env.borrow().get_environment_type();
+                if env_type == EnvironmentType::Global {
+                    let global_object = env.borrow().get_global_object().expect("global object");
+                    if !global_object.has_field(name.as_str()) {
+                        let mut property = DataDescriptor::new(
+                            Value::undefined(),
+                            Attribute::WRITABLE | Attribute::ENUMERABLE,
+                        );
+                        property.set_configurable(deletion);
+                        global_object.set_property(name, property);
+                    }
+                    return;
+                }
+
                 env.borrow_mut().create_mutable_binding(name, deletion);
             }
         }
@@ -212,10 +229,27 @@ impl LexicalEnvironment {
             .any(|env| env.borrow().has_binding(name))
     }
 
+    /// Returns `true` if the current environment has a binding with the given name that is not
+    /// initialized yet, like the binding of a `let` declaration before it is evaluated.
+    pub fn has_uninitialized_binding(&self, name: &str) -> bool {
+        let env = self.get_current_environment_ref().borrow();
+        env.has_binding(name) && !env.is_initialized_binding(name)
+    }
+
+    /// Gets the value of the closest binding with the given name.
+    ///
+    /// Returns `None` if there is no such binding, or if it is not initialized yet.
     pub fn get_binding_value(&self, name: &str) -> Option<Value> {
         self.environments()
             .find(|env| env.borrow().has_binding(name))
-            .map(|env| env.borrow().get_binding_value(name, false))
+            .and_then(|env| {
+                let env = env.borrow();
+                if env.is_initialized_binding(name) {
+                    Some(env.get_binding_value(name, false))
+                } else {
+                    None
+                }
+            })
     }
 }
 
@@ -264,6 +298,20 @@ pub fn new_object_environment(object: Value, environment: Option<Environment>) -
     })))
 }
 
+/// Creates the object environment used by a `with` statement, which has its `withEnvironment` flag set.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-with-statement-runtime-semantics-evaluation
+pub fn new_with_environment(object: Value, environment: Option<Environment>) -> Environment {
+    Gc::new(GcCell::new(Box::new(ObjectEnvironmentRecord {
+        bindings: object,
+        outer_env: environment,
+        with_environment: true,
+    })))
+}
+
 pub fn new_global_environment(global: Value, this_value: Value) -> Environment {
     let obj_rec = ObjectEnvironmentRecord {
         bindings: global,
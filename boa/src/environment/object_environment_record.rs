@@ -35,6 +35,14 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         }
     }
 
+    fn is_initialized_binding(&self, _name: &str) -> bool {
+        true
+    }
+
+    fn is_mutable_binding(&self, _name: &str) -> bool {
+        true
+    }
+
     fn create_mutable_binding(&mut self, name: String, deletion: bool) {
         // TODO: could save time here and not bother generating a new undefined object,
         // only for it to be replace with the real value later. We could just add the name to a Vector instead
@@ -60,15 +68,9 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         self.set_mutable_binding(name, value, false)
     }
 
-    fn set_mutable_binding(&mut self, name: &str, value: Value, strict: bool) {
-        debug_assert!(value.is_object() || value.is_function());
-
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
-        self.bindings
-            .as_object()
-            .expect("binding object")
-            .insert(name, property);
+    fn set_mutable_binding(&mut self, name: &str, value: Value, _strict: bool) {
+        // Bindings of an object environment are plain properties of the binding object.
+        self.bindings.set_field(name, value);
     }
 
     fn get_binding_value(&self, name: &str, strict: bool) -> Value {
@@ -122,7 +124,7 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     }
 
     fn get_environment_type(&self) -> EnvironmentType {
-        EnvironmentType::Function
+        EnvironmentType::Object
     }
 
     fn get_global_object(&self) -> Option<Value> {
@@ -10,7 +10,7 @@ pub trait Executable {
     fn run(&self, context: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -23,6 +23,8 @@ pub(crate) enum InterpreterState {
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+    /// Whether the code being executed is strict mode code.
+    strict: bool,
 }
 
 impl Default for Interpreter {
@@ -36,6 +38,7 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            strict: false,
         }
     }
 
@@ -48,4 +51,16 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Returns `true` if the code being executed is strict mode code.
+    #[inline]
+    pub(crate) fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the code being executed is strict mode code, returning the previous value.
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) -> bool {
+        std::mem::replace(&mut self.strict, strict)
+    }
 }
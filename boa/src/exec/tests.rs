@@ -72,6 +72,75 @@ fn empty_let_decl_undefined() {
     assert_eq!(&exec(scenario), "true");
 }
 
+#[test]
+fn let_decl_temporal_dead_zone() {
+    let scenario = r#"
+        let a = 1;
+        let result;
+        {
+            try {
+                result = a;
+            } catch (e) {
+                result = e.name;
+            }
+            let a = 2;
+        }
+        result;
+        "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r#"
+        function f() {
+            try {
+                b = 1;
+            } catch (e) {
+                return e.name;
+            }
+            const b = 2;
+        }
+        f();
+        "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+
+    let scenario = r#"
+        function f() {
+            return g();
+            function g() {
+                return c;
+            }
+            let c = 3;
+        }
+        try {
+            f();
+        } catch (e) {
+            e.name;
+        }
+        "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError\"");
+}
+
+#[test]
+fn const_decl_without_initializer() {
+    let mut context = Context::new();
+
+    let result = forward(&mut context, "const a;");
+    assert!(result.starts_with("Uncaught \"SyntaxError\": "));
+}
+
+#[test]
+fn const_decl_reassignment() {
+    let scenario = r#"
+        const a = 1;
+        let result = [];
+        try { a = 2; } catch (e) { result.push(e.name); }
+        try { a += 2; } catch (e) { result.push(e.name); }
+        try { a++; } catch (e) { result.push(e.name); }
+        result.push(a);
+        result.join();
+        "#;
+    assert_eq!(&exec(scenario), "\"TypeError,TypeError,TypeError,1\"");
+}
+
 #[test]
 fn semicolon_expression_stop() {
     let scenario = r#"
@@ -551,6 +620,49 @@ fn typeof_function() {
     assert_eq!(&exec(typeof_function), "\"function\"");
 }
 
+#[test]
+fn typeof_bigint() {
+    let typeof_bigint = r#"
+        let a = 1n;
+        typeof a;
+    "#;
+    assert_eq!(&exec(typeof_bigint), "\"bigint\"");
+}
+
+#[test]
+fn typeof_undeclared() {
+    let typeof_undeclared = r#"
+        typeof notDefined === "undefined";
+    "#;
+    assert_eq!(&exec(typeof_undeclared), "true");
+}
+
+#[test]
+fn typeof_undeclared_in_function() {
+    let typeof_undeclared = r#"
+        function f() {
+            return typeof notDefined;
+        }
+        f();
+    "#;
+    assert_eq!(&exec(typeof_undeclared), "\"undefined\"");
+}
+
+#[test]
+fn read_undeclared_throws() {
+    let scenario = r#"
+        try {
+            notDefined;
+        } catch(e) {
+            e.toString();
+        }
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: notDefined is not defined\""
+    );
+}
+
 #[test]
 fn unary_post() {
     let unary_inc = r#"
@@ -609,6 +721,21 @@ fn unary_void() {
         a + b
     "#;
     assert_eq!(&exec(void_invocation), "\"42undefined\"");
+
+    let void_precedence = r#"
+        void 0 === undefined
+    "#;
+    assert_eq!(&exec(void_precedence), "true");
+
+    let void_side_effect = r#"
+        var calls = 0;
+        function sideEffect() {
+            calls++;
+            return 'value';
+        }
+        [void sideEffect(), calls]
+    "#;
+    assert_eq!(&exec(void_side_effect), "[ undefined, 1 ]");
 }
 
 #[test]
@@ -661,6 +788,72 @@ fn unary_delete() {
         delete delete delete 1;
     "#;
     assert_eq!(&exec(delete_recursive), "true");
+
+    let delete_non_configurable = r#"
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5 });
+        const c = delete a.b + '';
+        a.b + c
+    "#;
+    assert_eq!(&exec(delete_non_configurable), "\"5false\"");
+}
+
+#[test]
+fn unary_delete_strict() {
+    let delete_non_configurable = r#"
+        'use strict';
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5 });
+        try {
+            delete a['b'];
+        } catch (e) {
+            e.toString();
+        }
+    "#;
+    assert_eq!(
+        &exec(delete_non_configurable),
+        "\"TypeError: property 'b' is non-configurable and cannot be deleted\""
+    );
+
+    let delete_in_strict_function = r#"
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5 });
+        function f() {
+            'use strict';
+            return delete a.b;
+        }
+        function g() {
+            return delete a.b;
+        }
+        let result;
+        try {
+            f();
+        } catch (e) {
+            result = e.name;
+        }
+        [result, g(), a.b]
+    "#;
+    assert_eq!(
+        &exec(delete_in_strict_function),
+        "[ \"TypeError\", false, 5 ]"
+    );
+
+    let delete_configurable = r#"
+        'use strict';
+        const a = { b: 5 };
+        [delete a.b, delete a.c, a.b]
+    "#;
+    assert_eq!(&exec(delete_configurable), "[ true, true, undefined ]");
+
+    let delete_variable = r#"
+        'use strict';
+        let a = 5;
+        delete a;
+    "#;
+    assert_eq!(
+        &exec(delete_variable),
+        "\"SyntaxError\": \"Syntax Error: Delete <variable> statements not allowed in strict mode at position: 4:9\""
+    );
 }
 
 #[cfg(test)]
@@ -687,6 +880,17 @@ mod in_operator {
         assert_eq!(&exec(p_in_o), "true");
     }
 
+    #[test]
+    fn property_in_constructor_prototype() {
+        let p_in_o = r#"
+            function Foo() {}
+            Foo.prototype.a = 'a';
+            var o = new Foo();
+            ['a' in o, 'b' in o]
+        "#;
+        assert_eq!(&exec(p_in_o), "[ true, false ]");
+    }
+
     #[test]
     fn property_not_in_object() {
         let p_not_in_o = r#"
@@ -737,6 +941,21 @@ mod in_operator {
         assert_eq!(forward(&mut context, "x"), "true");
     }
 
+    #[test]
+    fn should_type_error_message_when_rhs_not_object() {
+        let scenario = r#"
+            try {
+                'a' in 'abc'
+            } catch(e) {
+                e.toString()
+            }
+        "#;
+        assert_eq!(
+            &exec(scenario),
+            "\"TypeError: right-hand side of 'in' should be an object, got string\""
+        );
+    }
+
     #[test]
     fn should_set_this_value() {
         let mut context = Context::new();
@@ -786,6 +1005,85 @@ mod in_operator {
     }
 }
 
+#[test]
+fn new_member_precedence() {
+    let scenario = r#"
+        function Foo() { this.value = "instance"; }
+        Foo.prototype.bar = function () { return this.value; };
+        Foo.bar = function () { this.value = "static"; };
+        var ns = { inner: { C: Foo } };
+    "#;
+
+    let mut context = Context::new();
+    forward(&mut context, scenario);
+    assert_eq!(forward(&mut context, "new Foo().bar()"), "\"instance\"");
+    assert_eq!(forward(&mut context, "new Foo.bar().value"), "\"static\"");
+    assert_eq!(
+        forward(&mut context, "new Foo.bar() instanceof Foo"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "new ns.inner.C() instanceof Foo"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "new Foo instanceof Foo"), "true");
+}
+
+#[test]
+fn new_nested_and_arguments() {
+    let scenario = r#"
+        function Point(x, y) { this.x = x; this.y = y; }
+        function Factory() { return Point; }
+        var p = new new Factory()(1, 2);
+    "#;
+
+    let mut context = Context::new();
+    forward(&mut context, scenario);
+    assert_eq!(forward(&mut context, "p instanceof Point"), "true");
+    assert_eq!(forward(&mut context, "p.x + p.y"), "3");
+    assert_eq!(forward(&mut context, "new Point(4).y"), "undefined");
+}
+
+#[test]
+fn new_returned_object_overrides_instance() {
+    let scenario = r#"
+        var other = { replaced: true };
+        function ReturnsObject() { this.replaced = false; return other; }
+        function ReturnsPrimitive() { this.replaced = false; return 1; }
+        function EndsWithObject() { this.replaced = false; other; }
+        function Throws() { throw new RangeError("no"); }
+    "#;
+
+    let mut context = Context::new();
+    forward(&mut context, scenario);
+    assert_eq!(
+        forward(&mut context, "new ReturnsObject() === other"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new ReturnsPrimitive().replaced"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "new EndsWithObject().replaced"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Throws(); } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "ReturnsPrimitive.prototype = 1; Object.getPrototypeOf(new ReturnsPrimitive()) === Object.prototype"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn var_decl_hoisting_simple() {
     let scenario = r#"
@@ -1142,6 +1440,49 @@ fn to_object() {
         .is_object());
 }
 
+#[test]
+fn template_literal() {
+    let scenario = r#"
+        var a = 1;
+        var b = { toString() { return 'b'; } };
+        `${a} + ${a + 1} = ${a + a + 1}, ${b}${`-${'c'}-`}`
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1 + 2 = 3, b-c-\"");
+}
+
+#[test]
+fn tagged_template() {
+    let scenario = r#"
+        var obj = {
+            prefix: '>',
+            tag(strings, ...values) {
+                var result = this.prefix;
+                for (var i = 0; i < values.length; i++) {
+                    result += strings[i] + '[' + values[i] + ']';
+                }
+                return result + strings[strings.length - 1] + strings.raw.length;
+            },
+        };
+        obj.tag`a\n${1}b${2}`
+        "#;
+
+    assert_eq!(&exec(scenario), "\">a\n[1]b[2]3\"");
+}
+
+#[test]
+fn tagged_template_raw_property() {
+    let scenario = r#"
+        function tag(strings) {
+            var raw = Object.getOwnPropertyDescriptor(strings, 'raw');
+            return [raw.enumerable, raw.writable, Array.isArray(strings.raw)].join();
+        }
+        tag`a${1}b`
+        "#;
+
+    assert_eq!(&exec(scenario), "\"false,false,true\"");
+}
+
 #[test]
 fn check_this_binding_in_object_literal() {
     let mut context = Context::new();
@@ -1486,21 +1827,70 @@ fn test_strict_mode_reserved_name() {
 
 #[test]
 fn test_strict_mode_func_decl_in_block() {
-    // Checks that a function declaration in a block is an error in
-    // strict mode code as per https://tc39.es/ecma262/#early-error.
+    // Checks that a function declaration in a block is scoped to the block in strict mode code,
+    // as per https://tc39.es/ecma262/#sec-blockdeclarationinstantiation.
 
     let scenario = r#"
     'use strict';
     let a = 4;
     let b = 5;
-    if (a < b) { function f() {} }
+    let inside;
+    if (a < b) { function f() { return 1; } inside = f(); }
+    inside;
     "#;
+    assert_eq!(&exec(scenario), "1");
 
-    let mut context = Context::new();
+    let scenario = r#"
+    'use strict';
+    { function f() {} }
+    f;
+    "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError\": \"f is not defined\"");
 
-    let string = dbg!(forward(&mut context, scenario));
+    let scenario = r#"
+    function g() {
+        'use strict';
+        { function f() {} }
+        return typeof f;
+    }
+    g();
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined\"");
+}
 
-    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+#[test]
+fn func_decl_in_block_sloppy_mode() {
+    // Checks that a function declaration in a block is also assigned to a `var` binding of the
+    // enclosing function in sloppy mode code, as per
+    // https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics.
+
+    let scenario = r#"
+    { function f() { return 1; } }
+    f();
+    "#;
+    assert_eq!(&exec(scenario), "1");
+
+    let scenario = r#"
+    function g() {
+        { function f() { return 2; } }
+        return f();
+    }
+    g();
+    "#;
+    assert_eq!(&exec(scenario), "2");
+
+    // A `let` of an enclosing block keeps its value.
+    let scenario = r#"
+    function g() {
+        {
+            let f = 3;
+            { function f() {} }
+            return f;
+        }
+    }
+    g();
+    "#;
+    assert_eq!(&exec(scenario), "3");
 }
 
 #[test]
@@ -1519,3 +1909,29 @@ fn test_strict_mode_dup_func_parameters() {
 
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
+
+#[test]
+fn return_line_terminator_asi() {
+    let scenario = r#"
+        function f() {
+            return
+            5;
+        }
+        f()
+    "#;
+    assert_eq!(&exec(scenario), "undefined");
+}
+
+#[test]
+fn throw_line_terminator_syntax_error() {
+    let scenario = r#"
+        throw
+        new Error("message");
+    "#;
+
+    let mut context = Context::new();
+
+    let string = forward(&mut context, scenario);
+
+    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+}
@@ -99,10 +99,14 @@ pub(crate) fn forward(context: &mut Context, src: &str) -> String {
             );
         }
     };
-    expr.run(context).map_or_else(
-        |e| format!("Uncaught {}", e.display()),
-        |v| v.display().to_string(),
-    )
+    let result = expr.run(context);
+    let jobs_result = context.run_jobs();
+    result
+        .and_then(|value| jobs_result.map(|()| value))
+        .map_or_else(
+            |e| format!("Uncaught {}", e.display()),
+            |v| v.display().to_string(),
+        )
 }
 
 /// Execute the code using an existing Context.
@@ -120,7 +124,11 @@ pub(crate) fn forward_val(context: &mut Context, src: &str) -> Result<Value> {
                 .throw_syntax_error(e.to_string())
                 .expect_err("interpreter.throw_syntax_error() did not return an error")
         })
-        .and_then(|expr| expr.run(context));
+        .and_then(|expr| {
+            let result = expr.run(context);
+            let jobs_result = context.run_jobs();
+            result.and_then(|value| jobs_result.map(|()| value))
+        });
 
     // The main_timer needs to be dropped before the BoaProfiler is.
     drop(main_timer);
@@ -5,13 +5,15 @@
 use super::{NativeObject, Object, PROTOTYPE};
 use crate::{
     builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+        create_arguments_object, BuiltInClosure, BuiltInFunction, ClosureFunction, Function,
+        NativeFunction,
     },
     environment::{
         function_environment_record::BindingStatus, lexical_environment::new_function_environment,
     },
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
+    exec::InterpreterState,
+    property::{PartialDescriptor, PropertyKey},
+    syntax::ast::node::{FormalParameter, RcStatementList},
     value::PreferredType,
     Context, Executable, Result, Value,
 };
@@ -41,7 +43,9 @@ pub struct GcObject(Gc<GcCell<Object>>);
 /// already borrow it so we get the function body clone it then drop the borrow and run the body
 enum FunctionBody {
     BuiltIn(NativeFunction),
-    Ordinary(RcStatementList),
+    Closure(ClosureFunction, Value),
+    Ordinary(RcStatementList, Box<[FormalParameter]>),
+    Bound(GcObject, Value, Box<[Value]>),
 }
 
 impl GcObject {
@@ -122,21 +126,33 @@ impl GcObject {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
                     } => {
+                        // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                        let this = if flags.is_lexical_this_mode() {
+                            None
+                        } else if body.strict() {
+                            Some(this.clone())
+                        } else if this.is_null_or_undefined() {
+                            Some(context.global_object().clone())
+                        } else {
+                            Some(this.to_object(context)?.into())
+                        };
+
                         // Create a new Function environment who's parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
                         let local_env = new_function_environment(
                             this_function_object,
-                            if flags.is_lexical_this_mode() {
-                                None
-                            } else {
-                                Some(this.clone())
-                            },
+                            this,
                             Some(environment.clone()),
                             // Arrow functions do not have a this binding https://tc39.es/ecma262/#sec-function-environment-records
                             if flags.is_lexical_this_mode() {
@@ -146,30 +162,29 @@ impl GcObject {
                             },
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
+                        // Add arguments object, arrow functions use the one of the enclosing function
+                        // and a parameter named `arguments` replaces it.
+                        if !flags.is_lexical_this_mode()
+                            && params.iter().all(|param| param.name() != "arguments")
+                        {
+                            let arguments_obj =
+                                create_arguments_object(body, params, args, &local_env, context);
+                            local_env
+                                .borrow_mut()
+                                .create_mutable_binding("arguments".to_string(), false);
+                            local_env
+                                .borrow_mut()
+                                .initialize_binding("arguments", arguments_obj);
                         }
 
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
-
                         context.realm_mut().environment.push(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        // The parameters are bound once the function is no longer borrowed, as
+                        // their default values can use it.
+                        FunctionBody::Ordinary(body.clone(), params.clone())
+                    }
+                    Function::Bound { target, this, args } => {
+                        FunctionBody::Bound(target.clone(), this.clone(), args.clone())
                     }
                 }
             } else {
@@ -181,12 +196,18 @@ impl GcObject {
 
         match f_body {
             FunctionBody::BuiltIn(func) => func(this, args, context),
-            FunctionBody::Ordinary(body) => {
-                let result = body.run(context);
+            FunctionBody::Closure(func, captures) => func(this, args, &captures, context),
+            FunctionBody::Ordinary(body, params) => {
+                let result = Function::initialize_parameters(&params, args, context)
+                    .and_then(|()| body.run(context));
                 context.realm_mut().environment.pop();
 
                 result
             }
+            FunctionBody::Bound(target, bound_this, bound_args) => {
+                let args: Vec<Value> = bound_args.iter().chain(args).cloned().collect();
+                target.call(&bound_this, &args, context)
+            }
         }
     }
 
@@ -198,7 +219,17 @@ impl GcObject {
     // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist-newtarget>
     #[track_caller]
     pub fn construct(&self, args: &[Value], context: &mut Context) -> Result<Value> {
-        let this: Value = Object::create(self.get(&PROTOTYPE.into())).into();
+        // A constructor whose `prototype` is not an object creates objects that inherit from
+        // `Object.prototype`.
+        let prototype = match self.get(&PROTOTYPE.into()) {
+            prototype @ Value::Object(_) => prototype,
+            _ => context
+                .standard_objects()
+                .object_object()
+                .prototype()
+                .into(),
+        };
+        let this: Value = Object::create(prototype).into();
 
         let this_function_object = self.clone();
         let body = if let Some(function) = self.borrow().as_function() {
@@ -207,6 +238,11 @@ impl GcObject {
                     Function::BuiltIn(BuiltInFunction(function), _) => {
                         FunctionBody::BuiltIn(*function)
                     }
+                    Function::Closure {
+                        function: BuiltInClosure(function),
+                        captures,
+                        ..
+                    } => FunctionBody::Closure(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
@@ -227,30 +263,29 @@ impl GcObject {
                             },
                         );
 
-                        // Add argument bindings to the function environment
-                        for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
-                            if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
-                                break;
-                            }
-
-                            let value = args.get(i).cloned().unwrap_or_else(Value::undefined);
-                            function.add_arguments_to_environment(param, value, &local_env);
+                        // Add arguments object, arrow functions use the one of the enclosing function
+                        // and a parameter named `arguments` replaces it.
+                        if !flags.is_lexical_this_mode()
+                            && params.iter().all(|param| param.name() != "arguments")
+                        {
+                            let arguments_obj =
+                                create_arguments_object(body, params, args, &local_env, context);
+                            local_env
+                                .borrow_mut()
+                                .create_mutable_binding("arguments".to_string(), false);
+                            local_env
+                                .borrow_mut()
+                                .initialize_binding("arguments", arguments_obj);
                         }
 
-                        // Add arguments object
-                        let arguments_obj = create_unmapped_arguments_object(args);
-                        local_env
-                            .borrow_mut()
-                            .create_mutable_binding("arguments".to_string(), false);
-                        local_env
-                            .borrow_mut()
-                            .initialize_binding("arguments", arguments_obj);
-
                         context.realm_mut().environment.push(local_env);
 
-                        FunctionBody::Ordinary(body.clone())
+                        // The parameters are bound once the function is no longer borrowed, as
+                        // their default values can use it.
+                        FunctionBody::Ordinary(body.clone(), params.clone())
+                    }
+                    Function::Bound { target, this, args } => {
+                        FunctionBody::Bound(target.clone(), this.clone(), args.clone())
                     }
                 }
             } else {
@@ -266,12 +301,35 @@ impl GcObject {
                 function(&this, args, context)?;
                 Ok(this)
             }
-            FunctionBody::Ordinary(body) => {
-                let _ = body.run(context);
+            FunctionBody::Closure(function, captures) => {
+                function(&this, args, &captures, context)?;
+                Ok(this)
+            }
+            FunctionBody::Ordinary(body, params) => {
+                let result = Function::initialize_parameters(&params, args, context)
+                    .and_then(|()| body.run(context));
+                let returned = matches!(
+                    context.executor().get_current_state(),
+                    InterpreterState::Return
+                );
+                // unset the early return flag
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
 
-                // local_env gets dropped here, its no longer needed
                 let binding = context.realm_mut().environment.get_this_binding();
-                Ok(binding)
+                context.realm_mut().environment.pop();
+
+                // An object returned by the constructor is used instead of the new object.
+                match result? {
+                    value @ Value::Object(_) if returned => Ok(value),
+                    _ => Ok(binding),
+                }
+            }
+            // The bound `this` is replaced by the new object of the target.
+            FunctionBody::Bound(target, _, bound_args) => {
+                let args: Vec<Value> = bound_args.iter().chain(args).cloned().collect();
+                target.construct(&args, context)
             }
         }
     }
@@ -381,47 +439,42 @@ impl GcObject {
         }
     }
 
-    /// Convert the object to a `PropertyDescritptor`
+    /// Convert the object to a `PartialDescriptor`, keeping track of the absent fields.
     ///
     /// # Panics
     ///
     /// Panics if the object is currently mutably borrowed.
-    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
-        let mut attribute = Attribute::empty();
+    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PartialDescriptor> {
+        let mut desc = PartialDescriptor::new();
 
         let enumerable_key = PropertyKey::from("enumerable");
-        if self.has_property(&enumerable_key) && self.get(&enumerable_key).to_boolean() {
-            attribute |= Attribute::ENUMERABLE;
+        if self.has_property(&enumerable_key) {
+            desc.set_enumerable(self.get(&enumerable_key).to_boolean());
         }
 
         let configurable_key = PropertyKey::from("configurable");
-        if self.has_property(&configurable_key) && self.get(&configurable_key).to_boolean() {
-            attribute |= Attribute::CONFIGURABLE;
+        if self.has_property(&configurable_key) {
+            desc.set_configurable(self.get(&configurable_key).to_boolean());
         }
 
-        let mut value = None;
         let value_key = PropertyKey::from("value");
         if self.has_property(&value_key) {
-            value = Some(self.get(&value_key));
+            desc.set_value(self.get(&value_key));
         }
 
-        let mut has_writable = false;
         let writable_key = PropertyKey::from("writable");
         if self.has_property(&writable_key) {
-            has_writable = true;
-            if self.get(&writable_key).to_boolean() {
-                attribute |= Attribute::WRITABLE;
-            }
+            desc.set_writable(self.get(&writable_key).to_boolean());
         }
 
-        let mut get = None;
         let get_key = PropertyKey::from("get");
         if self.has_property(&get_key) {
             let getter = self.get(&get_key);
             match getter {
                 Value::Object(ref object) if object.is_callable() => {
-                    get = Some(object.clone());
+                    desc.set_getter(Some(object.clone()));
                 }
+                Value::Undefined => desc.set_getter(None),
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor getter must be callable")
@@ -430,14 +483,14 @@ impl GcObject {
             }
         }
 
-        let mut set = None;
         let set_key = PropertyKey::from("set");
         if self.has_property(&set_key) {
             let setter = self.get(&set_key);
             match setter {
                 Value::Object(ref object) if object.is_callable() => {
-                    set = Some(object.clone());
+                    desc.set_setter(Some(object.clone()));
                 }
+                Value::Undefined => desc.set_setter(None),
                 _ => {
                     return Err(
                         context.construct_type_error("Property descriptor setter must be callable")
@@ -446,15 +499,11 @@ impl GcObject {
             };
         }
 
-        if get.is_some() || set.is_some() {
-            if value.is_some() || has_writable {
-                return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
-            }
-
-            Ok(AccessorDescriptor::new(get, set, attribute).into())
-        } else {
-            Ok(DataDescriptor::new(value.unwrap_or_else(Value::undefined), attribute).into())
+        if desc.is_accessor_descriptor() && desc.is_data_descriptor() {
+            return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
         }
+
+        Ok(desc)
     }
 
     /// Reeturn `true` if it is a native object and the native type is `T`.
@@ -567,6 +616,61 @@ impl GcObject {
         self.borrow().is_map()
     }
 
+    /// Checks if it is a `Set` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_set(&self) -> bool {
+        self.borrow().is_set()
+    }
+
+    /// Checks if it is a `Promise` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_promise(&self) -> bool {
+        self.borrow().is_promise()
+    }
+
+    /// Checks if it is a typed array object, such as an `Uint8Array`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_typed_array(&self) -> bool {
+        self.borrow().is_typed_array()
+    }
+
+    /// Checks if it is a mapped `arguments` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_mapped_arguments(&self) -> bool {
+        self.borrow().is_mapped_arguments()
+    }
+
+    /// Checks if it is a `Proxy` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_proxy(&self) -> bool {
+        self.borrow().is_proxy()
+    }
+
     /// Checks if it a `String` object.
     ///
     /// # Panics
@@ -714,9 +818,9 @@ impl GcObject {
             return Ok(false);
         }
 
-        // TODO: If C has a [[BoundTargetFunction]] internal slot, then
-        //           Let BC be C.[[BoundTargetFunction]].
-        //           Return ? InstanceofOperator(O, BC).
+        if let Some(Function::Bound { target, .. }) = self.borrow().as_function() {
+            return target.ordinary_has_instance(context, value);
+        }
 
         if let Some(object) = value.as_object() {
             if let Some(prototype) = self.get(&"prototype".into()).as_object() {
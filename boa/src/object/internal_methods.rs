@@ -7,7 +7,7 @@
 
 use crate::{
     object::{GcObject, Object},
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    property::{Attribute, DataDescriptor, PartialDescriptor, PropertyDescriptor, PropertyKey},
     value::{same_value, Value},
     BoaProfiler, Context, Result,
 };
@@ -173,37 +173,48 @@ impl GcObject {
                     }
                 }
             }
+            // A configurable property can change between a data and an accessor property, the
+            // descriptor replaces it below.
             (PropertyDescriptor::Data(current), PropertyDescriptor::Accessor(_)) => {
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = AccessorDescriptor::new(None, None, current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Data(_)) => {
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = DataDescriptor::new(Value::undefined(), current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
                 if !current.configurable() {
-                    if let (Some(current_get), Some(desc_get)) = (current.getter(), desc.getter()) {
-                        if !GcObject::equals(&current_get, &desc_get) {
-                            return false;
-                        }
+                    let same_function = |a: Option<&GcObject>, b: Option<&GcObject>| match (a, b) {
+                        (Some(a), Some(b)) => GcObject::equals(a, b),
+                        (None, None) => true,
+                        _ => false,
+                    };
+
+                    if !same_function(current.getter(), desc.getter())
+                        || !same_function(current.setter(), desc.setter())
+                    {
+                        return false;
                     }
+                }
+            }
+        }
 
-                    if let (Some(current_set), Some(desc_set)) = (current.setter(), desc.setter()) {
-                        if !GcObject::equals(&current_set, &desc_set) {
-                            return false;
-                        }
+        // A mapped index of an `arguments` object passes its value on to its parameter, and is no
+        // longer mapped once it becomes an accessor or read-only.
+        if let PropertyKey::Index(index) = key {
+            if let Some(arguments) = self.borrow_mut().as_mapped_arguments_mut() {
+                match &desc {
+                    PropertyDescriptor::Data(desc) if desc.writable() => {
+                        arguments.set(index, desc.value())
+                    }
+                    PropertyDescriptor::Data(desc) => {
+                        arguments.set(index, desc.value());
+                        arguments.unmap(index);
                     }
+                    PropertyDescriptor::Accessor(_) => arguments.unmap(index),
                 }
             }
         }
@@ -212,6 +223,22 @@ impl GcObject {
         true
     }
 
+    /// Define an own property from a descriptor with absent fields, which keep the values of the
+    /// current property or get their default values.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+    pub fn define_own_partial_property<K>(&mut self, key: K, desc: PartialDescriptor) -> bool
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        let current = self.get_own_property(&key);
+        self.define_own_property(key, desc.complete(current.as_ref()))
+    }
+
     /// The specification returns a Property Descriptor or Undefined.
     ///
     /// These are 2 separate types and we can't do that here.
@@ -231,18 +258,87 @@ impl GcObject {
             PropertyKey::Symbol(ref symbol) => object.symbol_properties.get(symbol),
         };
 
-        property.cloned()
+        match (property, key, object.as_string()) {
+            (None, PropertyKey::Index(index), Some(string)) => {
+                Self::string_get_own_property(&string, *index)
+            }
+            // The mapped indices of an `arguments` object hold the values of their parameters.
+            (Some(PropertyDescriptor::Data(desc)), PropertyKey::Index(index), _) => {
+                match object
+                    .as_mapped_arguments()
+                    .and_then(|arguments| arguments.get(*index))
+                {
+                    Some(value) => Some(DataDescriptor::new(value, desc.attributes()).into()),
+                    None => Some(desc.clone().into()),
+                }
+            }
+            (property, _, _) => property.cloned(),
+        }
+    }
+
+    /// The abstract operation StringGetOwnProperty
+    ///
+    /// The code units of a `String` object are exposed as read-only, enumerable index properties.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringgetownproperty
+    fn string_get_own_property(string: &str, index: u32) -> Option<PropertyDescriptor> {
+        let code_unit = string.encode_utf16().nth(index as usize)?;
+        Some(
+            DataDescriptor::new(
+                String::from_utf16_lossy(&[code_unit]),
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            )
+            .into(),
+        )
     }
 
     /// Essential internal method OwnPropertyKeys
     ///
+    /// The integer index keys come first in ascending order, then the string keys and the
+    /// symbol keys, each in insertion order. `String` objects list the indices of their code
+    /// units before any other integer index keys.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [ECMAScript reference for `String` objects][string]
     ///
-    /// [spec](https://tc39.es/ecma262/#table-essential-internal-methods)
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
+    /// [string]: https://tc39.es/ecma262/#sec-string-exotic-objects-ownpropertykeys
     #[inline]
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
-        self.borrow().keys().collect()
+        let object = self.borrow();
+        let string_length = object
+            .as_string()
+            .map_or(0, |string| string.encode_utf16().count() as u32);
+        let mut index_keys: Vec<u32> = (0..string_length)
+            .chain(
+                object
+                    .index_property_keys()
+                    .copied()
+                    .filter(|index| *index >= string_length),
+            )
+            .collect();
+        index_keys.sort_unstable();
+
+        index_keys
+            .into_iter()
+            .map(PropertyKey::from)
+            .chain(
+                object
+                    .string_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .chain(
+                object
+                    .symbol_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .collect()
     }
 
     /// The abstract operation ObjectDefineProperties
@@ -255,7 +351,7 @@ impl GcObject {
     pub fn define_properties(&mut self, props: Value, context: &mut Context) -> Result<()> {
         let props = props.to_object(context)?;
         let keys = props.own_property_keys();
-        let mut descriptors: Vec<(PropertyKey, PropertyDescriptor)> = Vec::new();
+        let mut descriptors: Vec<(PropertyKey, PartialDescriptor)> = Vec::new();
 
         for next_key in keys {
             if let Some(prop_desc) = props.get_own_property(&next_key) {
@@ -267,9 +363,13 @@ impl GcObject {
             }
         }
 
-        descriptors.into_iter().for_each(|(p, d)| {
-            self.define_own_property(p, d);
-        });
+        for (key, desc) in descriptors {
+            if !self.define_own_partial_property(key.clone(), desc) {
+                return Err(
+                    context.construct_type_error(format!("cannot redefine property: {}", key))
+                );
+            }
+        }
 
         Ok(())
     }
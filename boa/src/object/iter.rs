@@ -1,5 +1,6 @@
 use super::{Object, PropertyDescriptor, PropertyKey};
 use crate::value::{RcString, RcSymbol};
+use indexmap::map as index_map;
 use std::{collections::hash_map, iter::FusedIterator};
 
 impl Object {
@@ -31,7 +32,7 @@ impl Object {
         Values(self.iter())
     }
 
-    /// An iterator visiting all symbol key-value pairs in arbitrary order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
+    /// An iterator visiting all symbol key-value pairs in insertion order. The iterator element type is `(&'a RcSymbol, &'a Property)`.
     ///
     ///
     /// This iterator does not recurse down the prototype chain.
@@ -40,7 +41,7 @@ impl Object {
         SymbolProperties(self.symbol_properties.iter())
     }
 
-    /// An iterator visiting all symbol keys in arbitrary order. The iterator element type is `&'a RcSymbol`.
+    /// An iterator visiting all symbol keys in insertion order. The iterator element type is `&'a RcSymbol`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -48,7 +49,7 @@ impl Object {
         SymbolPropertyKeys(self.symbol_properties.keys())
     }
 
-    /// An iterator visiting all symbol values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all symbol values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -80,7 +81,7 @@ impl Object {
         IndexPropertyValues(self.indexed_properties.values())
     }
 
-    /// An iterator visiting all string key-value pairs in arbitrary order. The iterator element type is `(&'a RcString, &'a Property)`.
+    /// An iterator visiting all string key-value pairs in insertion order. The iterator element type is `(&'a RcString, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -88,7 +89,7 @@ impl Object {
         StringProperties(self.string_properties.iter())
     }
 
-    /// An iterator visiting all string keys in arbitrary order. The iterator element type is `&'a RcString`.
+    /// An iterator visiting all string keys in insertion order. The iterator element type is `&'a RcString`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -96,7 +97,7 @@ impl Object {
         StringPropertyKeys(self.string_properties.keys())
     }
 
-    /// An iterator visiting all string values in arbitrary order. The iterator element type is `&'a Property`.
+    /// An iterator visiting all string values in insertion order. The iterator element type is `&'a Property`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
@@ -109,8 +110,8 @@ impl Object {
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
     indexed_properties: hash_map::Iter<'a, u32, PropertyDescriptor>,
-    string_properties: hash_map::Iter<'a, RcString, PropertyDescriptor>,
-    symbol_properties: hash_map::Iter<'a, RcSymbol, PropertyDescriptor>,
+    string_properties: index_map::Iter<'a, RcString, PropertyDescriptor>,
+    symbol_properties: index_map::Iter<'a, RcSymbol, PropertyDescriptor>,
 }
 
 impl<'a> Iterator for Iter<'a> {
@@ -180,7 +181,7 @@ impl FusedIterator for Values<'_> {}
 
 /// An iterator over the `Symbol` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct SymbolProperties<'a>(hash_map::Iter<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolProperties<'a>(index_map::Iter<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolProperties<'a> {
     type Item = (&'a RcSymbol, &'a PropertyDescriptor);
@@ -207,7 +208,7 @@ impl FusedIterator for SymbolProperties<'_> {}
 
 /// An iterator over the keys (`RcSymbol`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyKeys<'a>(hash_map::Keys<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyKeys<'a>(index_map::Keys<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyKeys<'a> {
     type Item = &'a RcSymbol;
@@ -234,7 +235,7 @@ impl FusedIterator for SymbolPropertyKeys<'_> {}
 
 /// An iterator over the `Symbol` values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct SymbolPropertyValues<'a>(hash_map::Values<'a, RcSymbol, PropertyDescriptor>);
+pub struct SymbolPropertyValues<'a>(index_map::Values<'a, RcSymbol, PropertyDescriptor>);
 
 impl<'a> Iterator for SymbolPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -342,7 +343,7 @@ impl FusedIterator for IndexPropertyValues<'_> {}
 
 /// An iterator over the `String` property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct StringProperties<'a>(hash_map::Iter<'a, RcString, PropertyDescriptor>);
+pub struct StringProperties<'a>(index_map::Iter<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringProperties<'a> {
     type Item = (&'a RcString, &'a PropertyDescriptor);
@@ -369,7 +370,7 @@ impl FusedIterator for StringProperties<'_> {}
 
 /// An iterator over the string keys (`RcString`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyKeys<'a>(hash_map::Keys<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyKeys<'a>(index_map::Keys<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringPropertyKeys<'a> {
     type Item = &'a RcString;
@@ -396,7 +397,7 @@ impl FusedIterator for StringPropertyKeys<'_> {}
 
 /// An iterator over the string values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct StringPropertyValues<'a>(hash_map::Values<'a, RcString, PropertyDescriptor>);
+pub struct StringPropertyValues<'a>(index_map::Values<'a, RcString, PropertyDescriptor>);
 
 impl<'a> Iterator for StringPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
@@ -3,11 +3,15 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        function::{BuiltInFunction, Function, FunctionFlags, MappedArguments, NativeFunction},
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
+        regexp::regexp_string_iterator::RegExpStringIterator,
+        set::ordered_set::OrderedSet,
+        set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
-        BigInt, Date, RegExp,
+        typed_array::TypedArray,
+        ArrayBuffer, BigInt, Date, Promise, Proxy, RegExp,
     },
     context::StandardConstructor,
     gc::{Finalize, Trace},
@@ -15,10 +19,11 @@ use crate::{
     value::{RcBigInt, RcString, RcSymbol, Value},
     BoaProfiler, Context,
 };
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHasher};
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
+    hash::BuildHasherDefault,
     ops::{Deref, DerefMut},
 };
 
@@ -58,16 +63,19 @@ impl<T: Any + Debug + Trace> NativeObject for T {
     }
 }
 
+/// A map of properties that keeps the insertion order of its keys.
+type PropertyMap<K> = OrderedMap<K, PropertyDescriptor, BuildHasherDefault<FxHasher>>;
+
 /// The internal representation of an JavaScript object.
 #[derive(Debug, Trace, Finalize)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
     indexed_properties: FxHashMap<u32, PropertyDescriptor>,
-    /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
-    /// Symbol Properties
-    symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
+    /// Properties, in insertion order
+    string_properties: PropertyMap<RcString>,
+    /// Symbol Properties, in insertion order
+    symbol_properties: PropertyMap<RcSymbol>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Whether it can have new properties added to it.
@@ -82,6 +90,11 @@ pub enum ObjectData {
     Map(OrderedMap<Value, Value>),
     MapIterator(MapIterator),
     RegExp(Box<RegExp>),
+    RegExpStringIterator(RegExpStringIterator),
+    Set(OrderedSet<Value>),
+    SetIterator(SetIterator),
+    WeakMap(OrderedMap<Value, Value>),
+    WeakSet(OrderedSet<Value>),
     BigInt(RcBigInt),
     Boolean(bool),
     Function(Function),
@@ -92,6 +105,11 @@ pub enum ObjectData {
     Error,
     Ordinary,
     Date(Date),
+    Promise(Promise),
+    Proxy(Proxy),
+    ArrayBuffer(ArrayBuffer),
+    TypedArray(TypedArray),
+    Arguments(MappedArguments),
     Global,
     NativeObject(Box<dyn NativeObject>),
 }
@@ -106,8 +124,13 @@ impl Display for ObjectData {
                 Self::ArrayIterator(_) => "ArrayIterator",
                 Self::Function(_) => "Function",
                 Self::RegExp(_) => "RegExp",
+                Self::RegExpStringIterator(_) => "RegExpStringIterator",
                 Self::Map(_) => "Map",
                 Self::MapIterator(_) => "MapIterator",
+                Self::Set(_) => "Set",
+                Self::SetIterator(_) => "SetIterator",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
@@ -117,6 +140,11 @@ impl Display for ObjectData {
                 Self::Number(_) => "Number",
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
+                Self::Promise(_) => "Promise",
+                Self::Proxy(_) => "Proxy",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
+                Self::TypedArray(_) => "TypedArray",
+                Self::Arguments(_) => "Arguments",
                 Self::Global => "Global",
                 Self::NativeObject(_) => "NativeObject",
             }
@@ -131,8 +159,8 @@ impl Default for Object {
         Self {
             data: ObjectData::Ordinary,
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -153,8 +181,8 @@ impl Object {
         Self {
             data: ObjectData::Function(function),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype,
             extensible: true,
         }
@@ -180,8 +208,8 @@ impl Object {
         Self {
             data: ObjectData::Boolean(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -193,8 +221,8 @@ impl Object {
         Self {
             data: ObjectData::Number(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -209,8 +237,8 @@ impl Object {
         Self {
             data: ObjectData::String(value.into()),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -222,8 +250,8 @@ impl Object {
         Self {
             data: ObjectData::BigInt(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -238,8 +266,8 @@ impl Object {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
         }
@@ -311,6 +339,22 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_regexp_string_iterator(&self) -> Option<&RegExpStringIterator> {
+        match self.data {
+            ObjectData::RegExpStringIterator(ref iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_regexp_string_iterator_mut(&mut self) -> Option<&mut RegExpStringIterator> {
+        match &mut self.data {
+            ObjectData::RegExpStringIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Map` object.pub
     #[inline]
     pub fn is_map(&self) -> bool {
@@ -341,6 +385,162 @@ impl Object {
         }
     }
 
+    /// Checks if it is a `Set` object.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self.data, ObjectData::Set(_))
+    }
+
+    #[inline]
+    pub fn as_set_ref(&self) -> Option<&OrderedSet<Value>> {
+        match self.data {
+            ObjectData::Set(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_mut(&mut self) -> Option<&mut OrderedSet<Value>> {
+        match &mut self.data {
+            ObjectData::Set(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
+        match &mut self.data {
+            ObjectData::SetIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_ref(&self) -> Option<&OrderedMap<Value, Value>> {
+        match self.data {
+            ObjectData::WeakMap(ref map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut OrderedMap<Value, Value>> {
+        match &mut self.data {
+            ObjectData::WeakMap(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_ref(&self) -> Option<&OrderedSet<Value>> {
+        match self.data {
+            ObjectData::WeakSet(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set_mut(&mut self) -> Option<&mut OrderedSet<Value>> {
+        match &mut self.data {
+            ObjectData::WeakSet(set) => Some(set),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData::Promise(ref promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Proxy` object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(self.data, ObjectData::Proxy(_))
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is an `ArrayBuffer` object.
+    #[inline]
+    pub fn is_array_buffer(&self) -> bool {
+        matches!(self.data, ObjectData::ArrayBuffer(_))
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match self.data {
+            ObjectData::ArrayBuffer(ref buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match &mut self.data {
+            ObjectData::ArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a typed array object, such as an `Uint8Array`.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(self.data, ObjectData::TypedArray(_))
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArray> {
+        match self.data {
+            ObjectData::TypedArray(ref typed_array) => Some(typed_array),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a mapped `arguments` object.
+    #[inline]
+    pub fn is_mapped_arguments(&self) -> bool {
+        matches!(self.data, ObjectData::Arguments(_))
+    }
+
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&MappedArguments> {
+        match self.data {
+            ObjectData::Arguments(ref arguments) => Some(arguments),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut MappedArguments> {
+        match &mut self.data {
+            ObjectData::Arguments(arguments) => Some(arguments),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
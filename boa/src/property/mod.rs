@@ -296,6 +296,133 @@ impl PropertyDescriptor {
     }
 }
 
+/// A property descriptor as converted from an object by `ToPropertyDescriptor`, in which any
+/// field may be absent.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-topropertydescriptor
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub struct PartialDescriptor {
+    value: Option<Value>,
+    writable: Option<bool>,
+    /// The getter, which is `Some(None)` if it is given as `undefined`.
+    get: Option<Option<GcObject>>,
+    /// The setter, which is `Some(None)` if it is given as `undefined`.
+    set: Option<Option<GcObject>>,
+    enumerable: Option<bool>,
+    configurable: Option<bool>,
+}
+
+impl PartialDescriptor {
+    /// Create a new `PartialDescriptor` with all fields absent.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the `value` field.
+    #[inline]
+    pub fn set_value(&mut self, value: Value) {
+        self.value = Some(value);
+    }
+
+    /// Set the `writable` field.
+    #[inline]
+    pub fn set_writable(&mut self, writable: bool) {
+        self.writable = Some(writable);
+    }
+
+    /// Set the `get` field.
+    #[inline]
+    pub fn set_getter(&mut self, get: Option<GcObject>) {
+        self.get = Some(get);
+    }
+
+    /// Set the `set` field.
+    #[inline]
+    pub fn set_setter(&mut self, set: Option<GcObject>) {
+        self.set = Some(set);
+    }
+
+    /// Set the `enumerable` field.
+    #[inline]
+    pub fn set_enumerable(&mut self, enumerable: bool) {
+        self.enumerable = Some(enumerable);
+    }
+
+    /// Set the `configurable` field.
+    #[inline]
+    pub fn set_configurable(&mut self, configurable: bool) {
+        self.configurable = Some(configurable);
+    }
+
+    /// Check whether the descriptor has a `get` or a `set` field.
+    #[inline]
+    pub fn is_accessor_descriptor(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+
+    /// Check whether the descriptor has a `value` or a `writable` field.
+    #[inline]
+    pub fn is_data_descriptor(&self) -> bool {
+        self.value.is_some() || self.writable.is_some()
+    }
+
+    /// Fill in the absent fields from the `current` property, as a property is updated by
+    /// `ValidateAndApplyPropertyDescriptor`, or with their default values for a new property.
+    ///
+    /// A descriptor with neither data nor accessor fields keeps the kind of the current property.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor
+    pub fn complete(self, current: Option<&PropertyDescriptor>) -> PropertyDescriptor {
+        let mut attributes = Attribute::empty();
+        if self
+            .enumerable
+            .unwrap_or_else(|| current.map_or(false, PropertyDescriptor::enumerable))
+        {
+            attributes |= Attribute::ENUMERABLE;
+        }
+        if self
+            .configurable
+            .unwrap_or_else(|| current.map_or(false, PropertyDescriptor::configurable))
+        {
+            attributes |= Attribute::CONFIGURABLE;
+        }
+
+        let is_accessor = self.is_accessor_descriptor()
+            || (!self.is_data_descriptor()
+                && current.map_or(false, PropertyDescriptor::is_accessor_descriptor));
+
+        if is_accessor {
+            let current = current.and_then(PropertyDescriptor::as_accessor_descriptor);
+            let get = self
+                .get
+                .unwrap_or_else(|| current.and_then(|c| c.getter().cloned()));
+            let set = self
+                .set
+                .unwrap_or_else(|| current.and_then(|c| c.setter().cloned()));
+            AccessorDescriptor::new(get, set, attributes).into()
+        } else {
+            let current = current.and_then(PropertyDescriptor::as_data_descriptor);
+            if self
+                .writable
+                .unwrap_or_else(|| current.map_or(false, DataDescriptor::writable))
+            {
+                attributes |= Attribute::WRITABLE;
+            }
+            let value = self
+                .value
+                .unwrap_or_else(|| current.map_or_else(Value::undefined, DataDescriptor::value));
+            DataDescriptor::new(value, attributes).into()
+        }
+    }
+}
+
 /// This abstracts away the need for IsPropertyKey by transforming the PropertyKey
 /// values into an enum with both valid types: String and Symbol
 ///
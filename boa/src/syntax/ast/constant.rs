@@ -166,10 +166,32 @@ impl From<bool> for Const {
 impl Display for Const {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match *self {
-            Self::String(ref st) => write!(f, "\"{}\"", st),
+            Self::String(ref st) => {
+                f.write_str("\"")?;
+                for c in st.chars() {
+                    match c {
+                        '"' => f.write_str("\\\"")?,
+                        '\\' => f.write_str("\\\\")?,
+                        '\n' => f.write_str("\\n")?,
+                        '\r' => f.write_str("\\r")?,
+                        '\t' => f.write_str("\\t")?,
+                        '\u{2028}' => f.write_str("\\u2028")?,
+                        '\u{2029}' => f.write_str("\\u2029")?,
+                        c if c.is_control() && (c as u32) < 0x100 => {
+                            write!(f, "\\x{:02x}", c as u32)?
+                        }
+                        c => write!(f, "{}", c)?,
+                    }
+                }
+                f.write_str("\"")
+            }
+            Self::Num(num) if num.is_nan() => write!(f, "NaN"),
+            Self::Num(num) if num.is_infinite() => {
+                write!(f, "{}Infinity", if num < 0.0 { "-" } else { "" })
+            }
             Self::Num(num) => write!(f, "{}", num),
             Self::Int(num) => write!(f, "{}", num),
-            Self::BigInt(ref num) => write!(f, "{}", num),
+            Self::BigInt(ref num) => write!(f, "{}n", num),
             Self::Bool(v) => write!(f, "{}", v),
             Self::Null => write!(f, "null"),
             Self::Undefined => write!(f, "undefined"),
@@ -513,6 +513,7 @@ impl TryInto<BinOp> for Keyword {
     }
 }
 
+/// The error returned when parsing a string that is not a keyword, such as an identifier.
 #[derive(Debug, Clone, Copy)]
 pub struct KeywordError;
 impl fmt::Display for KeywordError {
@@ -584,3 +585,33 @@ impl fmt::Display for Keyword {
         fmt::Display::fmt(self.as_str(), f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Keyword;
+
+    /// Checks that keywords are parsed from their string representation.
+    #[test]
+    fn keyword_from_str() {
+        assert_eq!("function".parse::<Keyword>().ok(), Some(Keyword::Function));
+        assert_eq!(
+            "instanceof".parse::<Keyword>().ok(),
+            Some(Keyword::InstanceOf)
+        );
+    }
+
+    /// Checks that identifiers are not parsed as keywords.
+    #[test]
+    fn identifier_is_not_keyword() {
+        assert!("foo".parse::<Keyword>().is_err());
+        assert!("Function".parse::<Keyword>().is_err());
+    }
+
+    /// Checks that `as_str` gives back the string the keyword is parsed from.
+    #[test]
+    fn keyword_as_str_round_trip() {
+        let keyword = Keyword::Function;
+        assert_eq!(keyword.as_str().parse::<Keyword>().ok(), Some(keyword));
+        assert_eq!(Keyword::TypeOf.as_str(), "typeof");
+    }
+}
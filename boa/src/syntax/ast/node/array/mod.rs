@@ -1,10 +1,11 @@
 //! Array declaration node.
 
-use super::{join_nodes, Node};
+use super::{display_nodes, Node};
 use crate::{
     builtins::{iterable, Array},
     exec::Executable,
     gc::{Finalize, Trace},
+    syntax::ast::Const,
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -39,29 +40,32 @@ impl Executable for ArrayDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
         let array = Array::new_array(context)?;
-        let mut elements = Vec::new();
+        let mut next_index: u32 = 0;
         for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
-                // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
-                // let mut next_index = 0;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
+            match elem {
+                Node::Spread(ref x) => {
+                    let val = x.run(context)?;
+                    let iterator_record = iterable::get_iterator(context, val)?;
+                    loop {
+                        let next = iterator_record.next(context)?;
+                        if next.is_done() {
+                            break;
+                        }
+                        array.set_field(next_index, next.value());
+                        next_index += 1;
                     }
-                    let next_value = next.value();
-                    //next_index += 1;
-                    elements.push(next_value.clone());
                 }
-            } else {
-                elements.push(elem.run(context)?);
+                // An elision leaves a hole, it only moves the next index forward.
+                Node::Const(Const::Undefined) => next_index += 1,
+                _ => {
+                    let val = elem.run(context)?;
+                    array.set_field(next_index, val);
+                    next_index += 1;
+                }
             }
         }
 
-        Array::add_to_array_object(&array, &elements)?;
+        array.set_field("length", next_index);
         Ok(array)
     }
 }
@@ -81,14 +85,25 @@ where
     }
 }
 
-impl fmt::Display for ArrayDecl {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ArrayDecl {
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
         f.write_str("[")?;
-        join_nodes(f, &self.arr)?;
+        display_nodes(f, &self.arr, indentation)?;
         f.write_str("]")
     }
 }
 
+impl fmt::Display for ArrayDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
 impl From<ArrayDecl> for Node {
     fn from(arr: ArrayDecl) -> Self {
         Self::ArrayDecl(arr)
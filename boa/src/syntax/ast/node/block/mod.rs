@@ -44,27 +44,29 @@ impl Block {
 
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        writeln!(f, "{{")?;
-        self.statements.display(f, indentation + 1)?;
-        write!(f, "{}}}", "    ".repeat(indentation))
+        self.statements.display_block(f, indentation)
     }
-}
 
-impl Executable for Block {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
+    /// Runs the statements of the block, once the strictness of the code has been set.
+    fn run_statements(&self, context: &mut Context) -> Result<Value> {
+        // A block without lexical declarations has nothing to put in its own environment.
+        let has_scope = self.statements.has_lexical_declarations();
+        if has_scope {
             let env = &mut context.realm_mut().environment;
             env.push(new_declarative_environment(Some(
                 env.get_current_environment_ref().clone(),
             )));
+            self.statements.create_lexical_bindings(context);
         }
 
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
         for statement in self.statements() {
-            obj = statement.run(context)?;
+            obj = match statement {
+                Node::FunctionDecl(decl) => decl.run_in_block(context, self.statements.strict())?,
+                _ => statement.run(context)?,
+            };
 
             match context.executor().get_current_state() {
                 InterpreterState::Return => {
@@ -88,12 +90,24 @@ impl Executable for Block {
         }
 
         // pop the block env
-        let _ = context.realm_mut().environment.pop();
+        if has_scope {
+            let _ = context.realm_mut().environment.pop();
+        }
 
         Ok(obj)
     }
 }
 
+impl Executable for Block {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Block", "exec");
+        let strict = context.executor().set_strict(self.statements.strict());
+        let result = self.run_statements(context);
+        context.executor().set_strict(strict);
+        result
+    }
+}
+
 impl<T> From<T> for Block
 where
     T: Into<StatementList>,
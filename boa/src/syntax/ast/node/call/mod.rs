@@ -1,8 +1,9 @@
 use crate::{
+    builtins::iterable,
     exec::Executable,
     exec::InterpreterState,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Node},
+    syntax::ast::node::{display_nodes, Node},
     value::{Type, Value},
     BoaProfiler, Context, Result,
 };
@@ -54,6 +55,18 @@ impl Call {
     pub fn args(&self) -> &[Node] {
         &self.args
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.expr.display_member_object(f, indentation)?;
+        f.write_str("(")?;
+        display_nodes(f, &self.args, indentation)?;
+        f.write_str(")")
+    }
 }
 
 impl Executable for Call {
@@ -65,12 +78,15 @@ impl Executable for Call {
                 if obj.get_type() != Type::Object {
                     obj = Value::Object(obj.to_object(context)?);
                 }
-                (obj.clone(), obj.get_field(get_const_field.field()))
+                let func = obj.get_field_with_accessors(get_const_field.field(), context)?;
+                (obj, func)
             }
             Node::GetField(ref get_field) => {
                 let obj = get_field.obj().run(context)?;
                 let field = get_field.field().run(context)?;
-                (obj.clone(), obj.get_field(field.to_property_key(context)?))
+                let key = field.to_property_key(context)?;
+                let func = obj.get_field_with_accessors(key, context)?;
+                (obj, func)
             }
             _ => (context.global_object().clone(), self.expr().run(context)?), // 'this' binding should come from the function's self-contained environment
         };
@@ -78,9 +94,15 @@ impl Executable for Call {
         for arg in self.args() {
             if let Node::Spread(ref x) = arg {
                 let val = x.run(context)?;
-                let mut vals = context.extract_array_properties(&val).unwrap();
-                v_args.append(&mut vals);
-                break; // after spread we don't accept any new arguments
+                let iterator_record = iterable::get_iterator(context, val)?;
+                loop {
+                    let next = iterator_record.next(context)?;
+                    if next.is_done() {
+                        break;
+                    }
+                    v_args.push(next.value());
+                }
+                continue;
             }
             v_args.push(arg.run(context)?);
         }
@@ -99,9 +121,7 @@ impl Executable for Call {
 
 impl fmt::Display for Call {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}(", self.expr)?;
-        join_nodes(f, &self.args)?;
-        f.write_str(")")
+        self.display(f, 0)
     }
 }
 
@@ -57,6 +57,19 @@ impl ConditionalOp {
             if_false: Box::new(if_false.into()),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.condition.display_operand(f, indentation)?;
+        f.write_str(" ? ")?;
+        self.if_true.display_assignment_expr(f, indentation)?;
+        f.write_str(" : ")?;
+        self.if_false.display_assignment_expr(f, indentation)
+    }
 }
 
 impl Executable for ConditionalOp {
@@ -71,13 +84,7 @@ impl Executable for ConditionalOp {
 
 impl fmt::Display for ConditionalOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} ? {} : {}",
-            self.cond(),
-            self.if_true(),
-            self.if_false()
-        )
+        self.display(f, 0)
     }
 }
 
@@ -66,15 +66,15 @@ impl If {
         f: &mut fmt::Formatter<'_>,
         indent: usize,
     ) -> fmt::Result {
-        write!(f, "if ({}) ", self.cond())?;
-        match self.else_node() {
-            Some(else_e) => {
-                self.body().display(f, indent)?;
-                f.write_str(" else ")?;
-                else_e.display(f, indent)
-            }
-            None => self.body().display(f, indent),
+        f.write_str("if (")?;
+        self.cond().display(f, indent)?;
+        f.write_str(") ")?;
+        self.body().display_statement(f, indent)?;
+        if let Some(else_e) = self.else_node() {
+            f.write_str(" else ")?;
+            else_e.display_statement(f, indent)?;
         }
+        Ok(())
     }
 }
 
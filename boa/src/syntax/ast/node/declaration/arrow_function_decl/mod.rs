@@ -62,7 +62,7 @@ impl ArrowFunctionDecl {
         write!(f, "(")?;
         join_nodes(f, &self.params)?;
         f.write_str(") => ")?;
-        self.body.display(f, indentation)
+        self.body.display_block(f, indentation)
     }
 }
 
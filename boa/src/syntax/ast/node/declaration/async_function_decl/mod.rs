@@ -68,15 +68,12 @@ impl AsyncFunctionDecl {
                 write!(f, "async function {}(", name)?;
             }
             None => {
-                write!(f, "async function (")?;
+                write!(f, "async function(")?;
             }
         }
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
-
-        self.body.display(f, indentation + 1)?;
-
-        writeln!(f, "}}")
+        f.write_str(") ")?;
+        self.body.display_block(f, indentation)
     }
 }
 
@@ -2,7 +2,7 @@ use crate::{
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{Identifier, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -44,16 +44,15 @@ impl Executable for ConstDeclList {
                 return context.throw_syntax_error("missing = in const declaration");
             };
 
-            context.realm_mut().environment.create_immutable_binding(
-                decl.name().to_owned(),
-                false,
-                VariableScope::Block,
-            );
-
-            context
-                .realm_mut()
-                .environment
-                .initialize_binding(decl.name(), val);
+            let environment = &mut context.realm_mut().environment;
+            if !environment.has_uninitialized_binding(decl.name()) {
+                environment.create_immutable_binding(
+                    decl.name().to_owned(),
+                    false,
+                    VariableScope::Block,
+                );
+            }
+            environment.initialize_binding(decl.name(), val);
         }
         Ok(Value::undefined())
     }
@@ -82,14 +81,31 @@ impl AsRef<[ConstDecl]> for ConstDeclList {
     }
 }
 
-impl fmt::Display for ConstDeclList {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl ConstDeclList {
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
         if !self.list.is_empty() {
             write!(f, "const ")?;
-            join_nodes(f, &self.list)
-        } else {
-            Ok(())
+            let mut first = true;
+            for decl in self.list.iter() {
+                if !first {
+                    f.write_str(", ")?;
+                }
+                first = false;
+                decl.display(f, indentation)?;
+            }
         }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ConstDeclList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
     }
 }
 
@@ -109,11 +125,7 @@ pub struct ConstDecl {
 
 impl fmt::Display for ConstDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
-        if let Some(ref init) = self.init {
-            write!(f, " = {}", init)?;
-        }
-        Ok(())
+        self.display(f, 0)
     }
 }
 
@@ -139,4 +151,18 @@ impl ConstDecl {
     pub fn init(&self) -> &Option<Node> {
         &self.init
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        fmt::Display::fmt(&self.name, f)?;
+        if let Some(ref init) = self.init {
+            f.write_str(" = ")?;
+            init.display_assignment_expr(f, indentation)?;
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     builtins::function::FunctionFlags,
-    environment::lexical_environment::VariableScope,
+    environment::lexical_environment::{EnvironmentType, VariableScope},
     exec::Executable,
     gc::{Finalize, Trace},
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
@@ -67,6 +67,71 @@ impl FunctionDecl {
         self.body.statements()
     }
 
+    /// Creates the function object of this declaration.
+    fn create_function(&self, context: &mut Context) -> Value {
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
+        );
+        val.set_field("name", self.name());
+        val
+    }
+
+    /// Evaluates a function declaration that is directly inside of a block.
+    ///
+    /// The function is bound in the scope of the block. In sloppy mode code, it is also assigned
+    /// to a `var` binding of the enclosing function once the declaration is evaluated, unless an
+    /// enclosing block declares the same name.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics
+    pub(in crate::syntax::ast::node) fn run_in_block(
+        &self,
+        context: &mut Context,
+        strict: bool,
+    ) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
+        let val = self.create_function(context);
+
+        let environment = &mut context.realm_mut().environment;
+        environment.create_mutable_binding(self.name().to_owned(), false, VariableScope::Block);
+        environment.initialize_binding(self.name(), val.clone());
+
+        if strict {
+            return Ok(Value::undefined());
+        }
+
+        // The first environment is the one of the block itself.
+        let mut enclosing = environment.environments().skip(1);
+        let var_env = loop {
+            match enclosing.next() {
+                Some(env)
+                    if matches!(
+                        env.borrow().get_environment_type(),
+                        EnvironmentType::Function | EnvironmentType::Global
+                    ) =>
+                {
+                    break env.clone();
+                }
+                Some(env) if env.borrow().has_binding(self.name()) => {
+                    return Ok(Value::undefined());
+                }
+                Some(_) => {}
+                None => return Ok(Value::undefined()),
+            }
+        };
+
+        let mut var_env = var_env.borrow_mut();
+        if !var_env.has_binding(self.name()) || var_env.is_initialized_binding(self.name()) {
+            var_env.set_mutable_binding(self.name(), val, false);
+        }
+
+        Ok(Value::undefined())
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -75,25 +140,17 @@ impl FunctionDecl {
     ) -> fmt::Result {
         write!(f, "function {}(", self.name)?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
-
-        self.body.display(f, indentation + 1)?;
-
-        writeln!(f, "}}")
+        f.write_str(") ")?;
+        self.body.display_block(f, indentation)
     }
 }
 
 impl Executable for FunctionDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
-        let val = context.create_function(
-            self.parameters().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
-        );
+        let val = self.create_function(context);
 
-        // Set the name and assign it in the current environment
-        val.set_field("name", self.name());
+        // Assign it in the current environment
         context.realm_mut().environment.create_mutable_binding(
             self.name().to_owned(),
             false,
@@ -20,6 +20,35 @@ use serde::{Deserialize, Serialize};
 /// By default, functions return `undefined`. To return any other value, the function must have
 /// a return statement that specifies the value to return.
 ///
+/// Methods of object literals are also represented as function expressions.
+///
+/// # Examples
+///
+/// ```
+/// use boa::syntax::ast::node::{Node, PropertyDefinition};
+///
+/// let statements = boa::parse("var o = { add(a, b) { return a + b; } };", false).unwrap();
+/// let object = match &statements.statements()[0] {
+///     Node::VarDeclList(list) => list.as_ref()[0].init().unwrap(),
+///     _ => unreachable!(),
+/// };
+/// let method = match object {
+///     Node::Object(object) => &object.properties()[0],
+///     _ => unreachable!(),
+/// };
+///
+/// if let PropertyDefinition::MethodDefinition(_, name, function) = method {
+///     assert_eq!(name.as_ref(), "add");
+///     assert_eq!(function.name(), None);
+///     let parameters: Vec<_> = function.parameters().iter().map(|p| p.name()).collect();
+///     assert_eq!(parameters, ["a", "b"]);
+///     assert_eq!(function.body().len(), 1);
+///     assert!(matches!(function.body()[0], Node::Return(_)));
+/// } else {
+///     unreachable!();
+/// }
+/// ```
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -49,17 +78,17 @@ impl FunctionExpr {
         }
     }
 
-    /// Gets the name of the function declaration.
+    /// Gets the name of the function expression, if it has one.
     pub fn name(&self) -> Option<&str> {
         self.name.as_ref().map(Box::as_ref)
     }
 
-    /// Gets the list of parameters of the function declaration.
+    /// Gets the list of parameters of the function expression.
     pub fn parameters(&self) -> &[FormalParameter] {
         &self.parameters
     }
 
-    /// Gets the body of the function declaration.
+    /// Gets the statements of the body of the function expression.
     pub fn body(&self) -> &[Node] {
         self.body.statements()
     }
@@ -76,11 +105,21 @@ impl FunctionExpr {
         }
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
-
-        self.body.display(f, indentation + 1)?;
+        f.write_str(") ")?;
+        self.body.display_block(f, indentation)
+    }
 
-        writeln!(f, "}}")
+    /// Implements the display formatting of the function as an object literal method, which
+    /// leaves out the `function` keyword and the name.
+    pub(in crate::syntax::ast::node) fn display_method(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("(")?;
+        join_nodes(f, &self.parameters)?;
+        f.write_str(") ")?;
+        self.body.display_block(f, indentation)
     }
 }
 
@@ -88,7 +127,7 @@ impl Executable for FunctionExpr {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let val = context.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         );
 
@@ -2,7 +2,7 @@ use crate::{
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{Identifier, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -41,15 +41,15 @@ impl Executable for LetDeclList {
                 Some(v) => v.run(context)?,
                 None => Value::undefined(),
             };
-            context.realm_mut().environment.create_mutable_binding(
-                var.name().to_owned(),
-                false,
-                VariableScope::Block,
-            );
-            context
-                .realm_mut()
-                .environment
-                .initialize_binding(var.name(), val);
+            let environment = &mut context.realm_mut().environment;
+            if !environment.has_uninitialized_binding(var.name()) {
+                environment.create_mutable_binding(
+                    var.name().to_owned(),
+                    false,
+                    VariableScope::Block,
+                );
+            }
+            environment.initialize_binding(var.name(), val);
         }
         Ok(Value::undefined())
     }
@@ -78,14 +78,31 @@ impl AsRef<[LetDecl]> for LetDeclList {
     }
 }
 
-impl fmt::Display for LetDeclList {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl LetDeclList {
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
         if !self.list.is_empty() {
             write!(f, "let ")?;
-            join_nodes(f, &self.list)
-        } else {
-            Ok(())
+            let mut first = true;
+            for decl in self.list.iter() {
+                if !first {
+                    f.write_str(", ")?;
+                }
+                first = false;
+                decl.display(f, indentation)?;
+            }
         }
+        Ok(())
+    }
+}
+
+impl fmt::Display for LetDeclList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
     }
 }
 
@@ -105,11 +122,7 @@ pub struct LetDecl {
 
 impl fmt::Display for LetDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
-        if let Some(ref init) = self.init {
-            write!(f, " = {}", init)?;
-        }
-        Ok(())
+        self.display(f, 0)
     }
 }
 
@@ -135,4 +148,18 @@ impl LetDecl {
     pub fn init(&self) -> Option<&Node> {
         self.init.as_ref()
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        fmt::Display::fmt(&self.name, f)?;
+        if let Some(ref init) = self.init {
+            f.write_str(" = ")?;
+            init.display_assignment_expr(f, indentation)?;
+        }
+        Ok(())
+    }
 }
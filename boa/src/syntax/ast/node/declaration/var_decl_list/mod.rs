@@ -2,7 +2,7 @@ use crate::{
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Identifier, Node},
+    syntax::ast::node::{Identifier, Node},
     Context, Result, Value,
 };
 use std::fmt;
@@ -84,14 +84,31 @@ impl AsRef<[VarDecl]> for VarDeclList {
     }
 }
 
-impl fmt::Display for VarDeclList {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl VarDeclList {
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
         if !self.vars.is_empty() {
             write!(f, "var ")?;
-            join_nodes(f, &self.vars)
-        } else {
-            Ok(())
+            let mut first = true;
+            for decl in self.vars.iter() {
+                if !first {
+                    f.write_str(", ")?;
+                }
+                first = false;
+                decl.display(f, indentation)?;
+            }
         }
+        Ok(())
+    }
+}
+
+impl fmt::Display for VarDeclList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
     }
 }
 
@@ -111,11 +128,7 @@ pub struct VarDecl {
 
 impl fmt::Display for VarDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
-        if let Some(ref init) = self.init {
-            write!(f, " = {}", init)?;
-        }
-        Ok(())
+        self.display(f, 0)
     }
 }
 
@@ -141,4 +154,18 @@ impl VarDecl {
     pub fn init(&self) -> Option<&Node> {
         self.init.as_ref()
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        fmt::Display::fmt(&self.name, f)?;
+        if let Some(ref init) = self.init {
+            f.write_str(" = ")?;
+            init.display_assignment_expr(f, indentation)?;
+        }
+        Ok(())
+    }
 }
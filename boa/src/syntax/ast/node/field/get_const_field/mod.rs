@@ -60,6 +60,16 @@ impl GetConstField {
     pub fn field(&self) -> &str {
         &self.field
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.obj.display_member_object(f, indentation)?;
+        write!(f, ".{}", self.field)
+    }
 }
 
 impl Executable for GetConstField {
@@ -69,13 +79,13 @@ impl Executable for GetConstField {
             obj = Value::Object(obj.to_object(context)?);
         }
 
-        Ok(obj.get_field(self.field()))
+        obj.get_field_with_accessors(self.field(), context)
     }
 }
 
 impl fmt::Display for GetConstField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}.{}", self.obj(), self.field())
+        self.display(f, 0)
     }
 }
 
@@ -59,6 +59,18 @@ impl GetField {
             field: Box::new(field.into()),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.obj.display_member_object(f, indentation)?;
+        f.write_str("[")?;
+        self.field.display(f, indentation)?;
+        f.write_str("]")
+    }
 }
 
 impl Executable for GetField {
@@ -69,13 +81,14 @@ impl Executable for GetField {
         }
         let field = self.field().run(context)?;
 
-        Ok(obj.get_field(field.to_property_key(context)?))
+        let key = field.to_property_key(context)?;
+        obj.get_field_with_accessors(key, context)
     }
 }
 
 impl fmt::Display for GetField {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}[{}]", self.obj(), self.field())
+        self.display(f, 0)
     }
 }
 
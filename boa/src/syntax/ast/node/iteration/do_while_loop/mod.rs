@@ -60,9 +60,11 @@ impl DoWhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "do")?;
-        self.body().display(f, indentation)?;
-        write!(f, "while ({})", self.cond())
+        f.write_str("do ")?;
+        self.body().display_statement(f, indentation)?;
+        f.write_str(" while (")?;
+        self.cond().display(f, indentation)?;
+        f.write_str(")")
     }
 }
 
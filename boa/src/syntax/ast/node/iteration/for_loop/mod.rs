@@ -69,23 +69,25 @@ impl ForLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         f.write_str("for (")?;
         if let Some(init) = self.init() {
-            fmt::Display::fmt(init, f)?;
+            init.display(f, indentation)?;
         }
         f.write_str(";")?;
         if let Some(condition) = self.condition() {
-            fmt::Display::fmt(condition, f)?;
+            f.write_str(" ")?;
+            condition.display(f, indentation)?;
         }
         f.write_str(";")?;
         if let Some(final_expr) = self.final_expr() {
-            fmt::Display::fmt(final_expr, f)?;
+            f.write_str(" ")?;
+            final_expr.display(f, indentation)?;
         }
-        writeln!(f, ") {{")?;
-
-        self.inner.body().display(f, indentation + 1)?;
-
-        write!(f, "}}")
+        f.write_str(") ")?;
+        self.inner.body().display_statement(f, indentation)
     }
 
     pub fn label(&self) -> Option<&str> {
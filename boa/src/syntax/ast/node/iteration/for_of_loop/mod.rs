@@ -46,9 +46,12 @@ impl ForOfLoop {
     }
 
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        write!(f, "for ({} of {}) {{", self.variable, self.iterable)?;
-        self.body().display(f, indentation + 1)?;
-        f.write_str("}")
+        f.write_str("for (")?;
+        self.variable.display(f, indentation)?;
+        f.write_str(" of ")?;
+        self.iterable.display_assignment_expr(f, indentation)?;
+        f.write_str(") ")?;
+        self.body().display_statement(f, indentation)
     }
 }
 
@@ -178,7 +181,10 @@ impl Executable for ForOfLoop {
                 }
             }
 
-            result = self.body().run(context)?;
+            result = match self.body().run(context) {
+                Ok(result) => result,
+                Err(error) => return iterator.close(Err(error), context),
+            };
             match context.executor().get_current_state() {
                 InterpreterState::Break(_label) => {
                     // TODO break to label.
@@ -187,6 +193,7 @@ impl Executable for ForOfLoop {
                     context
                         .executor()
                         .set_current_state(InterpreterState::Executing);
+                    result = iterator.close(Ok(result), context)?;
                     break;
                 }
                 InterpreterState::Continue(_label) => {
@@ -196,7 +203,14 @@ impl Executable for ForOfLoop {
                         .set_current_state(InterpreterState::Executing);
                     // after breaking out of the block, continue execution of the loop
                 }
-                InterpreterState::Return => return Ok(result),
+                InterpreterState::Return => {
+                    // Closing the iterator unsets the early return flag, so it is set again after.
+                    let result = iterator.close(Ok(result), context);
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Return);
+                    return result;
+                }
                 InterpreterState::Executing => {
                     // Continue execution.
                 }
@@ -192,6 +192,16 @@ fn do_while_loop_continue() {
     assert_eq!(&exec(scenario), "[ 1, 2 ]");
 }
 
+#[test]
+fn do_while_loop_semicolon_insertion() {
+    let scenario = r#"
+        var a = 0;
+        do a++; while (false) a++
+        a
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
+
 #[test]
 fn for_of_loop_declaration() {
     let mut context = Context::new();
@@ -220,6 +230,19 @@ fn for_of_loop_var() {
     assert_eq!(&forward(&mut context, "i"), "3");
 }
 
+#[test]
+fn for_of_loop_string() {
+    let mut context = Context::new();
+    let scenario = r#"
+        var result = "";
+        for (var c of "xy") {
+            result += c + ",";
+        }
+    "#;
+    context.eval(scenario).unwrap();
+    assert_eq!(&forward(&mut context, "result"), "\"x,y,\"");
+}
+
 #[test]
 fn for_of_loop_let() {
     let mut context = Context::new();
@@ -319,6 +342,107 @@ fn for_of_loop_return() {
     assert_eq!(&forward(&mut context, "foo()"), "2");
 }
 
+#[test]
+fn for_of_loop_user_defined_iterable() {
+    let scenario = r#"
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                count: 0,
+                next() {
+                    this.count++;
+                    return { value: this.count, done: this.count > 3 };
+                }
+            };
+        };
+        var result = "";
+        for (let value of iterable) {
+            result = result + value;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "\"123\"");
+}
+
+#[test]
+fn for_of_loop_break_calls_return() {
+    let mut context = Context::new();
+    let scenario = r#"
+        var closed = 0;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                count: 0,
+                next() {
+                    this.count++;
+                    return { value: this.count, done: false };
+                },
+                return() {
+                    closed++;
+                    return {};
+                }
+            };
+        };
+        var last;
+        for (var value of iterable) {
+            last = value;
+            if (value === 2) {
+                break;
+            }
+        }
+    "#;
+    context.eval(scenario).unwrap();
+    assert_eq!(&forward(&mut context, "closed"), "1");
+    assert_eq!(&forward(&mut context, "last"), "2");
+}
+
+#[test]
+fn for_of_loop_throw_calls_return() {
+    let scenario = r#"
+        var closed = 0;
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next() {
+                    return { value: 1, done: false };
+                },
+                return() {
+                    closed++;
+                    return {};
+                }
+            };
+        };
+        try {
+            for (var value of iterable) {
+                throw "error";
+            }
+        } catch (err) {}
+        closed
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn spread_user_defined_iterable() {
+    let scenario = r#"
+        var iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                count: 0,
+                next() {
+                    this.count++;
+                    return { value: this.count, done: this.count > 3 };
+                }
+            };
+        };
+        function sum(a, b, c, d) {
+            return a + b + c + d;
+        }
+        [...iterable].length + sum(...iterable, 10)
+    "#;
+    assert_eq!(&exec(scenario), "19");
+}
+
 #[test]
 fn for_loop_break_label() {
     let scenario = r#"
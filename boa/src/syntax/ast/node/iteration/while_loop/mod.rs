@@ -59,8 +59,10 @@ impl WhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "while ({}) ", self.cond())?;
-        self.expr().display(f, indentation)
+        f.write_str("while (")?;
+        self.cond().display(f, indentation)?;
+        f.write_str(") ")?;
+        self.expr().display_statement(f, indentation)
     }
 }
 
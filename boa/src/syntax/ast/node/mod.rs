@@ -17,8 +17,10 @@ pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
 pub mod switch;
+pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
 
 pub use self::{
     array::ArrayDecl,
@@ -41,10 +43,12 @@ pub use self::{
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
     switch::{Case, Switch},
+    template::{TaggedTemplate, TemplateElement, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
 };
-use super::Const;
+use super::{op, Const};
 use crate::{
     exec::Executable,
     gc::{empty_trace, Finalize, Trace},
@@ -58,6 +62,10 @@ use std::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// A node of the JavaScript abstract syntax tree.
+///
+/// Every node owns its children, so cloning a `Node` produces an independent deep copy of the
+/// whole subtree, which can be modified and reinserted without affecting the original.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub enum Node {
@@ -157,6 +165,12 @@ pub enum Node {
     /// A spread (...x) statement. [More information](./spread/struct.Spread.html).
     Spread(Spread),
 
+    /// A tagged template expression. [More information](./template/struct.TaggedTemplate.html).
+    TaggedTemplate(TaggedTemplate),
+
+    /// A template literal. [More information](./template/struct.TemplateLit.html).
+    TemplateLit(TemplateLit),
+
     /// A throw statement. [More information](./throw/struct.Throw.html).
     Throw(Throw),
 
@@ -185,6 +199,9 @@ pub enum Node {
 
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
+
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
 }
 
 impl Display for Node {
@@ -216,52 +233,249 @@ impl Node {
         Self::This
     }
 
-    /// Implements the display formatting with indentation.
-    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        let indent = "    ".repeat(indentation);
-        match *self {
-            Self::Block(_) => {}
-            _ => write!(f, "{}", indent)?,
+    /// The precedence returned for nodes that are not expressions, lower than any expression.
+    pub const STATEMENT_PRECEDENCE: u8 = 0;
+
+    /// The precedence of the comma operator, the lowest of the expressions.
+    pub const COMMA_PRECEDENCE: u8 = 1;
+
+    /// The precedence of assignments, arrow functions and spreads.
+    pub const ASSIGNMENT_PRECEDENCE: u8 = 2;
+
+    /// The precedence of postfix increments and decrements.
+    pub const POSTFIX_PRECEDENCE: u8 = 16;
+
+    /// The precedence of literals, identifiers and other primary expressions, the highest one.
+    pub const PRIMARY_PRECEDENCE: u8 = 19;
+
+    /// Returns the precedence of the expression, higher for expressions that bind tighter.
+    ///
+    /// An operand with a lower precedence than its operator needs parentheses, such as `a + b` in
+    /// `(a + b) * c`. Statements and declarations return `Node::STATEMENT_PRECEDENCE`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Operator_Precedence
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::BinOp(bin_op) => match bin_op.op() {
+                op::BinOp::Comma => Self::COMMA_PRECEDENCE,
+                op::BinOp::Assign(_) => Self::ASSIGNMENT_PRECEDENCE,
+                op::BinOp::Log(op::LogOp::Or) => 4,
+                op::BinOp::Log(op::LogOp::And) => 5,
+                op::BinOp::Bit(op::BitOp::Or) => 6,
+                op::BinOp::Bit(op::BitOp::Xor) => 7,
+                op::BinOp::Bit(op::BitOp::And) => 8,
+                op::BinOp::Comp(op::CompOp::Equal)
+                | op::BinOp::Comp(op::CompOp::NotEqual)
+                | op::BinOp::Comp(op::CompOp::StrictEqual)
+                | op::BinOp::Comp(op::CompOp::StrictNotEqual) => 9,
+                op::BinOp::Comp(_) => 10,
+                op::BinOp::Bit(_) => 11,
+                op::BinOp::Num(op::NumOp::Add) | op::BinOp::Num(op::NumOp::Sub) => 12,
+                op::BinOp::Num(op::NumOp::Exp) => 14,
+                op::BinOp::Num(_) => 13,
+            },
+            Self::Assign(_) | Self::ArrowFunctionDecl(_) | Self::Spread(_) => {
+                Self::ASSIGNMENT_PRECEDENCE
+            }
+            Self::ConditionalOp(_) => 3,
+            Self::UnaryOp(unary_op) => match unary_op.op() {
+                op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => Self::POSTFIX_PRECEDENCE,
+                _ => 15,
+            },
+            Self::AwaitExpr(_) => 15,
+            Self::Call(_)
+            | Self::GetConstField(_)
+            | Self::GetField(_)
+            | Self::New(_)
+            | Self::TaggedTemplate(_) => 18,
+            Self::ArrayDecl(_)
+            | Self::AsyncFunctionExpr(_)
+            | Self::Const(_)
+            | Self::FunctionExpr(_)
+            | Self::Identifier(_)
+            | Self::Object(_)
+            | Self::TemplateLit(_)
+            | Self::This => Self::PRIMARY_PRECEDENCE,
+            _ => Self::STATEMENT_PRECEDENCE,
+        }
+    }
+
+    /// Returns the source code of the node.
+    ///
+    /// Nested statements are indented by four spaces per level, starting at `indentation`
+    /// levels. The first line is not indented, so that the output can be placed after other
+    /// code on the same line.
+    pub fn to_indented_string(&self, indentation: usize) -> String {
+        struct Indented<'a>(&'a Node, usize);
+
+        impl Display for Indented<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.display(f, self.1)
+            }
         }
 
+        Indented(self, indentation).to_string()
+    }
+
+    /// Implements the display formatting with indentation.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         match *self {
-            Self::Call(ref expr) => Display::fmt(expr, f),
+            Self::Call(ref expr) => expr.display(f, indentation),
             Self::Const(ref c) => write!(f, "{}", c),
-            Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
+            Self::ConditionalOp(ref cond_op) => cond_op.display(f, indentation),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::This => write!(f, "this"),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
             Self::Break(ref break_smt) => Display::fmt(break_smt, f),
             Self::Continue(ref cont) => Display::fmt(cont, f),
-            Self::Spread(ref spread) => Display::fmt(spread, f),
+            Self::Spread(ref spread) => spread.display(f, indentation),
+            Self::TaggedTemplate(ref template) => template.display(f, indentation),
+            Self::TemplateLit(ref template) => template.display(f, indentation),
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
-            Self::New(ref expr) => Display::fmt(expr, f),
-            Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
-            Self::GetField(ref get_field) => Display::fmt(get_field, f),
+            Self::New(ref expr) => expr.display(f, indentation),
+            Self::GetConstField(ref get_const_field) => get_const_field.display(f, indentation),
+            Self::GetField(ref get_field) => get_field.display(f, indentation),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
-            Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
-            Self::VarDeclList(ref list) => Display::fmt(list, f),
+            Self::ArrayDecl(ref arr) => arr.display(f, indentation),
+            Self::VarDeclList(ref list) => list.display(f, indentation),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
-            Self::BinOp(ref op) => Display::fmt(op, f),
-            Self::UnaryOp(ref op) => Display::fmt(op, f),
-            Self::Return(ref ret) => Display::fmt(ret, f),
-            Self::Throw(ref throw) => Display::fmt(throw, f),
-            Self::Assign(ref op) => Display::fmt(op, f),
-            Self::LetDeclList(ref decl) => Display::fmt(decl, f),
-            Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
+            Self::BinOp(ref op) => op.display(f, indentation),
+            Self::UnaryOp(ref op) => op.display(f, indentation),
+            Self::Return(ref ret) => ret.display(f, indentation),
+            Self::Throw(ref throw) => throw.display(f, indentation),
+            Self::Assign(ref op) => op.display(f, indentation),
+            Self::LetDeclList(ref decl) => decl.display(f, indentation),
+            Self::ConstDeclList(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => expr.display(f, indentation),
         }
     }
+
+    /// Displays the node as a statement, followed by a semicolon if it needs one.
+    pub(in crate::syntax::ast::node) fn display_statement(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self {
+            Self::AsyncFunctionDecl(_)
+            | Self::Block(_)
+            | Self::ForLoop(_)
+            | Self::ForOfLoop(_)
+            | Self::FunctionDecl(_)
+            | Self::If(_)
+            | Self::Switch(_)
+            | Self::Try(_)
+            | Self::WhileLoop(_)
+            | Self::With(_) => self.display(f, indentation),
+            _ => {
+                // An expression statement that starts with `{` or `function` would be parsed
+                // as a block or a declaration.
+                let source = self.to_indented_string(indentation);
+                if source.starts_with('{')
+                    || source.starts_with("function ")
+                    || source.starts_with("function(")
+                    || source.starts_with("async function")
+                {
+                    write!(f, "({});", source)
+                } else {
+                    write!(f, "{};", source)
+                }
+            }
+        }
+    }
+
+    /// Displays the node wrapped in parentheses if `parenthesize` is true.
+    fn display_parenthesized(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+        parenthesize: bool,
+    ) -> fmt::Result {
+        if parenthesize {
+            f.write_str("(")?;
+            self.display(f, indentation)?;
+            f.write_str(")")
+        } else {
+            self.display(f, indentation)
+        }
+    }
+
+    /// Displays the node as the operand of an operator.
+    ///
+    /// Operands that are themselves built from operators are parenthesized, so that the output
+    /// is parsed back to the same tree regardless of precedence and associativity.
+    pub(in crate::syntax::ast::node) fn display_operand(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        let parenthesize = self.precedence() <= Self::POSTFIX_PRECEDENCE;
+        self.display_parenthesized(f, indentation, parenthesize)
+    }
+
+    /// Displays the node as the object of a property access, or the function of a call.
+    pub(in crate::syntax::ast::node) fn display_member_object(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        let parenthesize = !matches!(
+            self,
+            Self::ArrayDecl(_)
+                | Self::Call(_)
+                | Self::Const(Const::String(_))
+                | Self::Const(Const::Bool(_))
+                | Self::Const(Const::Null)
+                | Self::Const(Const::Undefined)
+                | Self::GetConstField(_)
+                | Self::GetField(_)
+                | Self::Identifier(_)
+                | Self::New(_)
+                | Self::Object(_)
+                | Self::TaggedTemplate(_)
+                | Self::TemplateLit(_)
+                | Self::This
+        );
+        self.display_parenthesized(f, indentation, parenthesize)
+    }
+
+    /// Displays the node where an assignment expression is expected, such as a function
+    /// argument or an initializer.
+    pub(in crate::syntax::ast::node) fn display_assignment_expr(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        let parenthesize = self.precedence() < Self::ASSIGNMENT_PRECEDENCE;
+        self.display_parenthesized(f, indentation, parenthesize)
+    }
+}
+
+/// Displays a list of nodes as assignment expressions separated by commas.
+fn display_nodes(f: &mut fmt::Formatter<'_>, nodes: &[Node], indentation: usize) -> fmt::Result {
+    let mut first = true;
+    for node in nodes {
+        if !first {
+            f.write_str(", ")?;
+        }
+        first = false;
+        node.display_assignment_expr(f, indentation)?;
+    }
+    Ok(())
 }
 
 impl Executable for Node {
@@ -287,6 +501,7 @@ impl Executable for Node {
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
             Node::GetField(ref get_field) => get_field.run(context),
             Node::WhileLoop(ref while_loop) => while_loop.run(context),
+            Node::With(ref with) => with.run(context),
             Node::DoWhileLoop(ref do_while) => do_while.run(context),
             Node::ForLoop(ref for_loop) => for_loop.run(context),
             Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(context),
@@ -310,6 +525,8 @@ impl Executable for Node {
             Node::LetDeclList(ref decl) => decl.run(context),
             Node::ConstDeclList(ref decl) => decl.run(context),
             Node::Spread(ref spread) => spread.run(context),
+            Node::TaggedTemplate(ref template) => template.run(context),
+            Node::TemplateLit(ref template) => template.run(context),
             Node::This => {
                 // Will either return `this` binding or undefined
                 Ok(context.realm().environment.get_this_binding())
@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{Call, Node},
+    syntax::ast::node::{display_nodes, Call, Node},
     value::Value,
     BoaProfiler, Context, Result,
 };
@@ -41,6 +41,28 @@ impl New {
     pub fn args(&self) -> &[Node] {
         &self.call.args()
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("new ")?;
+        // The parentheses keep the arguments of a call in the constructor expression from
+        // being taken as the arguments of `new`.
+        match self.expr() {
+            Node::Call(_) => {
+                f.write_str("(")?;
+                self.expr().display(f, indentation)?;
+                f.write_str(")")?;
+            }
+            expr => expr.display_member_object(f, indentation)?,
+        }
+        f.write_str("(")?;
+        display_nodes(f, self.args(), indentation)?;
+        f.write_str(")")
+    }
 }
 
 impl Executable for New {
@@ -69,7 +91,7 @@ impl From<Call> for New {
 
 impl fmt::Display for New {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "new {}", self.call)
+        self.display(f, 0)
     }
 }
 
@@ -3,7 +3,14 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    syntax::{
+        ast::{
+            node::{MethodDefinitionKind, Node, PropertyDefinition},
+            Const,
+        },
+        lexer::{is_identifier_part, is_identifier_start},
+    },
     Context, Result, Value,
 };
 use std::fmt;
@@ -11,6 +18,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// Objects in JavaScript may be defined as an unordered collection of related data, of
 /// primitive or reference types, in the form of “key: value” pairs.
 ///
@@ -48,25 +58,52 @@ impl Object {
         f: &mut fmt::Formatter<'_>,
         indent: usize,
     ) -> fmt::Result {
+        if self.properties().is_empty() {
+            return f.write_str("{}");
+        }
+
+        let property_indent = "    ".repeat(indent + 1);
         f.write_str("{\n")?;
         for property in self.properties().iter() {
+            f.write_str(&property_indent)?;
             match property {
                 PropertyDefinition::IdentifierReference(key) => {
-                    write!(f, "{}    {},", indent, key)?;
+                    f.write_str(key)?;
                 }
                 PropertyDefinition::Property(key, value) => {
-                    write!(f, "{}    {}: {},", indent, key, value)?;
+                    display_property_name(f, key)?;
+                    f.write_str(": ")?;
+                    value.display_assignment_expr(f, indent + 1)?;
                 }
                 PropertyDefinition::SpreadObject(key) => {
-                    write!(f, "{}    ...{},", indent, key)?;
+                    f.write_str("...")?;
+                    key.display_assignment_expr(f, indent + 1)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, node) => {
+                    match kind {
+                        MethodDefinitionKind::Get => f.write_str("get ")?,
+                        MethodDefinitionKind::Set => f.write_str("set ")?,
+                        MethodDefinitionKind::Ordinary => {}
+                    }
+                    display_property_name(f, key)?;
+                    node.display_method(f, indent + 1)?;
                 }
             }
+            f.write_str(",\n")?;
         }
-        f.write_str("}")
+        write!(f, "{}}}", "    ".repeat(indent))
+    }
+}
+
+/// Writes a property name, quoting it if it is not a valid identifier name.
+fn display_property_name(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    let mut chars = name.chars();
+    let is_identifier =
+        matches!(chars.next(), Some(c) if is_identifier_start(c)) && chars.all(is_identifier_part);
+    if is_identifier {
+        f.write_str(name)
+    } else {
+        fmt::Display::fmt(&Const::from(name), f)
     }
 }
 
@@ -83,14 +120,35 @@ impl Executable for Object {
         for property in self.properties().iter() {
             match property {
                 PropertyDefinition::Property(key, value) => {
-                    obj.set_field(key.clone(), value.run(context)?);
+                    obj.set_property(key.clone(), data_property(value.run(context)?));
                 }
                 PropertyDefinition::MethodDefinition(kind, name, func) => {
-                    if let MethodDefinitionKind::Ordinary = kind {
-                        obj.set_field(name.clone(), func.run(context)?);
-                    } else {
-                        // TODO: Implement other types of MethodDefinitionKinds.
-                        //unimplemented!("other types of property method definitions.");
+                    let func = func.run(context)?;
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {
+                            obj.set_property(name.clone(), data_property(func));
+                        }
+                        MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                            // A getter and a setter with the same name are merged into a single
+                            // accessor property, while any earlier data property is replaced.
+                            let existing = obj.as_object().and_then(|object| {
+                                object.get_own_property(&PropertyKey::from(name.clone()))
+                            });
+                            let mut accessor = match existing {
+                                Some(PropertyDescriptor::Accessor(accessor)) => accessor,
+                                _ => AccessorDescriptor::new(
+                                    None,
+                                    None,
+                                    Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                                ),
+                            };
+                            if let MethodDefinitionKind::Get = kind {
+                                accessor.set_getter(func.as_object());
+                            } else {
+                                accessor.set_setter(func.as_object());
+                            }
+                            obj.set_property(name.clone(), accessor);
+                        }
                     }
                 }
                 _ => {} //unimplemented!("{:?} type of property", i),
@@ -101,6 +159,14 @@ impl Executable for Object {
     }
 }
 
+/// Creates the descriptor of a property defined in an object literal.
+fn data_property(value: Value) -> DataDescriptor {
+    DataDescriptor::new(
+        value,
+        Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+    )
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
@@ -0,0 +1,96 @@
+use crate::{
+    exec,
+    syntax::ast::{
+        node::{Node, Object, PropertyDefinition},
+        Const,
+    },
+};
+
+#[test]
+fn deep_clone_object_literal() {
+    let original: Node = Object::from(vec![
+        PropertyDefinition::property("a", Const::from(1)),
+        PropertyDefinition::property(
+            "b",
+            Object::from(vec![PropertyDefinition::property("c", Const::from(2))]),
+        ),
+    ])
+    .into();
+
+    let mut clone = original.clone();
+    assert_eq!(clone, original);
+
+    // Replace the nested object of the clone, leaving the original untouched.
+    if let Node::Object(ref mut object) = clone {
+        let mut properties = object.properties().to_vec();
+        properties[1] = PropertyDefinition::property(
+            "b",
+            Object::from(vec![PropertyDefinition::property("c", Const::from(3))]),
+        );
+        *object = Object::from(properties);
+    }
+
+    assert_ne!(clone, original);
+    assert_eq!(
+        original,
+        Object::from(vec![
+            PropertyDefinition::property("a", Const::from(1)),
+            PropertyDefinition::property(
+                "b",
+                Object::from(vec![PropertyDefinition::property("c", Const::from(2))]),
+            ),
+        ])
+        .into()
+    );
+}
+
+#[test]
+fn getter_and_setter_pair() {
+    let scenario = r#"
+        var v = 1;
+        var obj = { get x() { return v; }, set x(n) { v = n; } };
+        obj.x = 5;
+        obj.x + v;
+    "#;
+    assert_eq!(&exec(scenario), "10");
+}
+
+#[test]
+fn setter_before_getter() {
+    let scenario = r#"
+        var v = 1;
+        var obj = { set x(n) { v = n; }, get x() { return v; } };
+        obj["x"] = 7;
+        obj["x"];
+    "#;
+    assert_eq!(&exec(scenario), "7");
+}
+
+#[test]
+fn getter_without_setter_ignores_assignment() {
+    let scenario = r#"
+        var obj = { get x() { return 1; } };
+        obj.x = 5;
+        obj.x;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn accessor_replaces_data_property() {
+    let scenario = r#"
+        var obj = { x: 1, get x() { return 2; } };
+        obj.x;
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn data_property_replaces_accessor() {
+    let scenario = r#"
+        var obj = { get x() { return 2; }, x: 1 };
+        obj.x = 3;
+        obj.x;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
@@ -50,6 +50,17 @@ impl Assign {
     pub fn rhs(&self) -> &Node {
         &self.rhs
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.lhs.display(f, indentation)?;
+        f.write_str(" = ")?;
+        self.rhs.display_assignment_expr(f, indentation)
+    }
 }
 
 impl Executable for Assign {
@@ -58,12 +69,11 @@ impl Executable for Assign {
         let val = self.rhs().run(context)?;
         match self.lhs() {
             Node::Identifier(ref name) => {
-                let environment = &mut context.realm_mut().environment;
-
-                if environment.has_binding(name.as_ref()) {
+                if context.realm().environment.has_binding(name.as_ref()) {
                     // Binding already exists
-                    environment.set_mutable_binding(name.as_ref(), val.clone(), true);
+                    context.set_mutable_binding(name.as_ref(), val.clone(), true)?;
                 } else {
+                    let environment = &mut context.realm_mut().environment;
                     environment.create_mutable_binding(
                         name.as_ref().to_owned(),
                         true,
@@ -74,13 +84,13 @@ impl Executable for Assign {
             }
             Node::GetConstField(ref get_const_field) => {
                 let val_obj = get_const_field.obj().run(context)?;
-                val_obj.set_field(get_const_field.field(), val.clone());
+                val_obj.set_field_with_accessors(get_const_field.field(), val.clone(), context)?;
             }
             Node::GetField(ref get_field) => {
                 let object = get_field.obj().run(context)?;
                 let field = get_field.field().run(context)?;
                 let key = field.to_property_key(context)?;
-                object.set_field(key, val.clone());
+                object.set_field_with_accessors(key, val.clone(), context)?;
             }
             _ => (),
         }
@@ -90,7 +100,7 @@ impl Executable for Assign {
 
 impl fmt::Display for Assign {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} = {}", self.lhs, self.rhs)
+        self.display(f, 0)
     }
 }
 
@@ -73,6 +73,20 @@ impl BinOp {
             AssignOp::Ushr => x.ushr(&y, context),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.lhs.display_operand(f, indentation)?;
+        match self.op {
+            op::BinOp::Comma => f.write_str(", ")?,
+            op => write!(f, " {} ", op)?,
+        }
+        self.rhs.display_operand(f, indentation)
+    }
 }
 
 impl Executable for BinOp {
@@ -122,7 +136,7 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(context)?;
-                        context.has_property(&y, &key)
+                        context.has_property(&y, &key)?
                     }
                     CompOp::InstanceOf => {
                         if let Some(object) = y.as_object() {
@@ -171,19 +185,19 @@ impl Executable for BinOp {
                         .ok_or_else(|| context.construct_reference_error(name.as_ref()))?;
                     let v_b = self.rhs().run(context)?;
                     let value = Self::run_assign(op, v_a, v_b, context)?;
-                    context.realm_mut().environment.set_mutable_binding(
-                        name.as_ref(),
-                        value.clone(),
-                        true,
-                    );
+                    context.set_mutable_binding(name.as_ref(), value.clone(), true)?;
                     Ok(value)
                 }
                 Node::GetConstField(ref get_const_field) => {
                     let v_r_a = get_const_field.obj().run(context)?;
-                    let v_a = v_r_a.get_field(get_const_field.field());
+                    let v_a = v_r_a.get_field_with_accessors(get_const_field.field(), context)?;
                     let v_b = self.rhs().run(context)?;
                     let value = Self::run_assign(op, v_a, v_b, context)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone());
+                    v_r_a.set_field_with_accessors(
+                        get_const_field.field(),
+                        value.clone(),
+                        context,
+                    )?;
                     Ok(value)
                 }
                 _ => Ok(Value::undefined()),
@@ -198,7 +212,7 @@ impl Executable for BinOp {
 
 impl fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.lhs, self.op, self.rhs)
+        self.display(f, 0)
     }
 }
 
@@ -60,3 +60,79 @@ fn instanceofoperator_rhs_not_callable() {
         "\"TypeError: right-hand side of 'instanceof' is not callable\""
     );
 }
+
+#[test]
+fn instanceofoperator_custom_has_instance() {
+    let scenario = r#"
+        var Even = {};
+        Even[Symbol.hasInstance] = function(n) {
+          return n % 2 === 0;
+        };
+        [2 instanceof Even, 3 instanceof Even]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, false ]");
+}
+
+#[test]
+fn instanceofoperator_has_instance_overrides_prototype_chain() {
+    let scenario = r#"
+        function F() {}
+        Object.defineProperty(F, Symbol.hasInstance, {
+          value: function(v) { return v === 1; }
+        });
+        [new F() instanceof F, 1 instanceof F]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ false, true ]");
+}
+
+#[test]
+fn instanceofoperator_default_has_instance() {
+    let scenario = r#"
+        function F() {}
+        var hasInstance = Function.prototype[Symbol.hasInstance];
+        [
+          new F() instanceof F,
+          ({}) instanceof F,
+          hasInstance.call(F, new F()),
+          hasInstance.call({}, new F()),
+          hasInstance.name,
+          hasInstance.length
+        ]
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "[ true, false, true, false, \"[Symbol.hasInstance]\", 1 ]"
+    );
+}
+
+#[test]
+fn update_expression_return_values() {
+    let scenario = r#"
+        let a = 1;
+        let b = 1;
+        [a++, ++b, a, b, a--, --b, a, b]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 2, 2, 2, 2, 1, 1, 1 ]");
+}
+
+#[test]
+fn update_expression_to_numeric() {
+    let scenario = r#"
+        let a = "5";
+        let old = a++;
+        [old, typeof old, a]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 5, \"number\", 6 ]");
+
+    let scenario = r#"
+        let a = 10n;
+        [a++, a, --a]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 10n, 11n, 10n ]");
+}
@@ -1,7 +1,10 @@
 use crate::{
+    builtins::BigInt,
     exec::Executable,
     gc::{Finalize, Trace},
+    property::PropertyKey,
     syntax::ast::{node::Node, op},
+    value::Numeric,
     Context, Result, Value,
 };
 use std::fmt;
@@ -45,34 +48,94 @@ impl UnaryOp {
     pub fn target(&self) -> &Node {
         self.target.as_ref()
     }
+
+    /// Adds `delta` to the numeric value of an update expression (`++` or `--`).
+    fn update(old_value: Numeric, delta: i32) -> Value {
+        match old_value {
+            Numeric::Number(number) => Value::from(number + f64::from(delta)),
+            Numeric::BigInt(bigint) => Value::from(bigint.as_inner().clone() + BigInt::from(delta)),
+        }
+    }
+
+    /// Deletes the property `key` of the base of a `delete` reference.
+    ///
+    /// Returns `false` if the property is not configurable, or throws a `TypeError` in strict
+    /// mode code.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-delete-operator-runtime-semantics-evaluation
+    fn delete_property(base: &Value, key: PropertyKey, context: &mut Context) -> Result<bool> {
+        let object = base.to_object(context)?;
+        let deleted = context.delete_property(&object.into(), &key)?;
+        if !deleted && context.executor().strict() {
+            return Err(context.construct_type_error(format!(
+                "property '{}' is non-configurable and cannot be deleted",
+                key
+            )));
+        }
+        Ok(deleted)
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self.op {
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => {
+                self.target.display_operand(f, indentation)?;
+                write!(f, "{}", self.op)
+            }
+            op::UnaryOp::Delete | op::UnaryOp::TypeOf | op::UnaryOp::Void => {
+                write!(f, "{} ", self.op)?;
+                self.target.display_operand(f, indentation)
+            }
+            _ => {
+                write!(f, "{}", self.op)?;
+                self.target.display_operand(f, indentation)
+            }
+        }
+    }
 }
 
 impl Executable for UnaryOp {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        // `typeof` on an unresolvable reference does not throw.
+        //
+        // https://tc39.es/ecma262/#sec-typeof-operator-runtime-semantics-evaluation
+        if let (op::UnaryOp::TypeOf, Node::Identifier(ident)) = (self.op(), self.target()) {
+            if !context.realm().environment.has_binding(ident.as_ref()) {
+                return Ok(Value::from("undefined"));
+            }
+        }
+
         let x = self.target().run(context)?;
 
         Ok(match self.op() {
             op::UnaryOp::Minus => x.neg(context)?,
             op::UnaryOp::Plus => Value::from(x.to_number(context)?),
             op::UnaryOp::IncrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
+                let old_value = x.to_numeric(context)?;
+                let new_value = Self::update(old_value.clone(), 1);
+                context.set_value(self.target(), new_value)?;
+                old_value.into()
             }
             op::UnaryOp::IncrementPre => {
-                let result = x.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?
+                let new_value = Self::update(x.to_numeric(context)?, 1);
+                context.set_value(self.target(), new_value)?
             }
             op::UnaryOp::DecrementPost => {
-                let ret = x.clone();
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
+                let old_value = x.to_numeric(context)?;
+                let new_value = Self::update(old_value.clone(), -1);
+                context.set_value(self.target(), new_value)?;
+                old_value.into()
             }
             op::UnaryOp::DecrementPre => {
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?
+                let new_value = Self::update(x.to_numeric(context)?, -1);
+                context.set_value(self.target(), new_value)?
             }
             op::UnaryOp::Not => x.not(context)?.into(),
             op::UnaryOp::Tilde => {
@@ -86,17 +149,16 @@ impl Executable for UnaryOp {
             }
             op::UnaryOp::Void => Value::undefined(),
             op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(context)?
-                        .remove_property(get_const_field.field()),
-                ),
+                Node::GetConstField(ref get_const_field) => {
+                    let obj = get_const_field.obj().run(context)?;
+                    let key = get_const_field.field().into();
+                    Value::boolean(Self::delete_property(&obj, key, context)?)
+                }
                 Node::GetField(ref get_field) => {
                     let obj = get_field.obj().run(context)?;
                     let field = &get_field.field().run(context)?;
-                    let res = obj.remove_property(field.to_string(context)?.as_str());
-                    return Ok(Value::boolean(res));
+                    let key = field.to_property_key(context)?;
+                    Value::boolean(Self::delete_property(&obj, key, context)?)
                 }
                 Node::Identifier(_) => Value::boolean(false),
                 Node::ArrayDecl(_)
@@ -116,7 +178,7 @@ impl Executable for UnaryOp {
 
 impl fmt::Display for UnaryOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.op, self.target)
+        self.display(f, 0)
     }
 }
 
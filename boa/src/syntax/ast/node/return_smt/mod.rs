@@ -55,6 +55,21 @@ impl Return {
             label: label.into(),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self.expr() {
+            Some(ex) => {
+                f.write_str("return ")?;
+                ex.display(f, indentation)
+            }
+            None => f.write_str("return"),
+        }
+    }
 }
 
 impl Executable for Return {
@@ -79,9 +94,6 @@ impl From<Return> for Node {
 
 impl fmt::Display for Return {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.expr() {
-            Some(ex) => write!(f, "return {}", ex),
-            None => write!(f, "return"),
-        }
+        self.display(f, 0)
     }
 }
@@ -46,6 +46,16 @@ impl Spread {
             val: Box::new(val.into()),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("...")?;
+        self.val.display_assignment_expr(f, indentation)
+    }
 }
 
 impl Executable for Spread {
@@ -57,7 +67,7 @@ impl Executable for Spread {
 
 impl fmt::Display for Spread {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "...{}", self.val())
+        self.display(f, 0)
     }
 }
 
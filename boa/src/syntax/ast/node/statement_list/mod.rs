@@ -1,6 +1,7 @@
 //! Statement list node.
 
 use crate::{
+    environment::lexical_environment::VariableScope,
     exec::{Executable, InterpreterState},
     gc::{empty_trace, Finalize, Trace},
     syntax::ast::node::Node,
@@ -24,6 +25,8 @@ use serde::{Deserialize, Serialize};
 pub struct StatementList {
     #[cfg_attr(feature = "serde", serde(flatten))]
     statements: Box<[Node]>,
+    has_lexical_declarations: bool,
+    strict: bool,
 }
 
 impl StatementList {
@@ -32,6 +35,75 @@ impl StatementList {
         &self.statements
     }
 
+    /// Returns `true` if the list directly contains lexical declarations, which need a new
+    /// declarative environment when the list is the body of a block.
+    ///
+    /// These are `let` and `const` declarations and function declarations. Declarations in
+    /// nested blocks or functions are not taken into account.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-block-static-semantics-lexicallyscopeddeclarations
+    #[inline]
+    pub fn has_lexical_declarations(&self) -> bool {
+        self.has_lexical_declarations
+    }
+
+    /// Returns `true` if the list is the body of a function or a block whose code is strict mode
+    /// code, either by its own `"use strict"` directive or by the one of the code around it.
+    #[inline]
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Marks the list as the body of a function or a block with strict mode code.
+    #[inline]
+    pub(in crate::syntax) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Creates the bindings of the `let` and `const` declarations of the list in the current
+    /// environment.
+    ///
+    /// The bindings stay uninitialized until their declaration is evaluated, so using them before
+    /// throws a `ReferenceError` instead of reaching a binding of an outer scope.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-blockdeclarationinstantiation
+    pub(in crate::syntax::ast::node) fn create_lexical_bindings(&self, context: &mut Context) {
+        if !self.has_lexical_declarations {
+            return;
+        }
+
+        let environment = &mut context.realm_mut().environment;
+        for node in self.statements.iter() {
+            match node {
+                Node::LetDeclList(list) => {
+                    for decl in list.as_ref() {
+                        environment.create_mutable_binding(
+                            decl.name().to_owned(),
+                            false,
+                            VariableScope::Block,
+                        );
+                    }
+                }
+                Node::ConstDeclList(list) => {
+                    for decl in list.as_ref() {
+                        environment.create_immutable_binding(
+                            decl.name().to_owned(),
+                            false,
+                            VariableScope::Block,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -42,22 +114,43 @@ impl StatementList {
         // Print statements
         for node in self.statements.iter() {
             f.write_str(&indent)?;
-            node.display(f, indentation + 1)?;
-
-            match node {
-                Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
-                _ => write!(f, ";")?,
-            }
+            node.display_statement(f, indentation)?;
             writeln!(f)?;
         }
         Ok(())
     }
-}
 
-impl Executable for StatementList {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("StatementList", "exec");
+    /// Displays the statements inside braces, like the body of a block or a function.
+    pub(in crate::syntax::ast::node) fn display_block(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        if self.statements.is_empty() {
+            return f.write_str("{}");
+        }
+        writeln!(f, "{{")?;
+        self.display(f, indentation + 1)?;
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
 
+    /// Runs the statements of the list, with the bindings of its lexical declarations already
+    /// created in the current environment.
+    ///
+    /// The clauses of a `switch` statement are run this way, as the bindings of all of them are
+    /// created when entering the case block.
+    pub(in crate::syntax::ast::node) fn run_in_environment(
+        &self,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let strict = context.executor().set_strict(self.strict);
+        let result = self.run_statements(context);
+        context.executor().set_strict(strict);
+        result
+    }
+
+    /// Runs the statements of the list, once the strictness of the code has been set.
+    fn run_statements(&self, context: &mut Context) -> Result<Value> {
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
@@ -92,13 +185,34 @@ impl Executable for StatementList {
     }
 }
 
+impl Executable for StatementList {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("StatementList", "exec");
+        self.create_lexical_bindings(context);
+        self.run_in_environment(context)
+    }
+}
+
 impl<T> From<T> for StatementList
 where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
+        let statements = stm.into();
+        let has_lexical_declarations = statements.iter().any(|node| {
+            matches!(
+                node,
+                Node::LetDeclList(_)
+                    | Node::ConstDeclList(_)
+                    | Node::FunctionDecl(_)
+                    | Node::AsyncFunctionDecl(_)
+            )
+        });
+
         Self {
-            statements: stm.into(),
+            statements,
+            has_lexical_declarations,
+            strict: false,
         }
     }
 }
@@ -1,10 +1,11 @@
 //! Switch node.
 //!
 use crate::{
+    environment::lexical_environment::new_declarative_environment,
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
-    Context, Result, Value,
+    BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
 
@@ -69,19 +70,25 @@ pub struct Switch {
     val: Box<Node>,
     cases: Box<[Case]>,
     default: Option<StatementList>,
+    default_position: usize,
 }
 
 impl Switch {
     /// Creates a `Switch` AST node.
+    ///
+    /// The default clause, if any, is placed after all the cases. Use `set_default_position` to
+    /// place it between them.
     pub fn new<V, C, D>(val: V, cases: C, default: Option<D>) -> Self
     where
         V: Into<Node>,
         C: Into<Box<[Case]>>,
         D: Into<StatementList>,
     {
+        let cases = cases.into();
         Self {
             val: Box::new(val.into()),
-            cases: cases.into(),
+            default_position: cases.len(),
+            cases,
             default: default.map(D::into),
         }
     }
@@ -101,101 +108,156 @@ impl Switch {
         self.default.as_ref().map(StatementList::statements)
     }
 
+    /// Gets the number of cases that come before the default clause.
+    pub fn default_position(&self) -> usize {
+        self.default_position
+    }
+
+    /// Places the default clause after the first `position` cases.
+    pub fn set_default_position(&mut self, position: usize) {
+        assert!(
+            position <= self.cases.len(),
+            "default clause position out of bounds"
+        );
+        self.default_position = position;
+    }
+
+    /// Gets the bodies of all the clauses in source order, including the default clause.
+    fn clauses(&self) -> Vec<&StatementList> {
+        let mut clauses: Vec<_> = self.cases.iter().map(Case::body).collect();
+        if let Some(ref default) = self.default {
+            clauses.insert(self.default_position, default);
+        }
+        clauses
+    }
+
+    /// Runs the clauses of the switch statement, starting at the clause that matches `val`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-caseblockevaluation
+    fn run_clauses(&self, val: &Value, context: &mut Context) -> Result<Value> {
+        // The cases are compared in source order, skipping the default clause, which is only
+        // where the execution starts if no case matches.
+        let mut start = None;
+        for (i, case) in self.cases.iter().enumerate() {
+            if val.strict_equals(&case.condition().run(context)?) {
+                start = Some(if self.default.is_some() && i >= self.default_position {
+                    i + 1
+                } else {
+                    i
+                });
+                break;
+            }
+        }
+        let start = match start {
+            Some(start) => start,
+            None if self.default.is_some() => self.default_position,
+            None => return Ok(Value::undefined()),
+        };
+
+        // If a clause does not end with a break statement then the following clauses are run
+        // without checking their conditions until a break is encountered.
+        let mut result = Value::undefined();
+        for clause in &self.clauses()[start..] {
+            if clause.statements().is_empty() {
+                continue;
+            }
+            result = clause.run_in_environment(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    break;
+                }
+                InterpreterState::Break(_label) => {
+                    // TODO, break to a label.
+                    // Break statement encountered so therefore end switch statement.
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    break;
+                }
+                InterpreterState::Continue(_label) => {
+                    // TODO, continue to a label.
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continuing execution / falling through to next case statement(s).
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
         indent: usize,
     ) -> fmt::Result {
-        writeln!(f, "switch ({}) {{", self.val())?;
-        for e in self.cases().iter() {
-            writeln!(f, "{}case {}:", indent, e.condition())?;
-            e.body().display(f, indent)?;
+        let case_indent = "    ".repeat(indent + 1);
+
+        f.write_str("switch (")?;
+        self.val().display(f, indent)?;
+        writeln!(f, ") {{")?;
+        for (i, e) in self.cases().iter().enumerate() {
+            if i == self.default_position {
+                self.display_default(f, indent)?;
+            }
+            write!(f, "{}case ", case_indent)?;
+            e.condition().display(f, indent + 1)?;
+            writeln!(f, ":")?;
+            e.body().display(f, indent + 2)?;
         }
+        if self.default_position == self.cases.len() {
+            self.display_default(f, indent)?;
+        }
+        write!(f, "{}}}", "    ".repeat(indent))
+    }
 
+    /// Displays the default clause, if any.
+    fn display_default(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
         if let Some(ref default) = self.default {
-            writeln!(f, "{}default:", indent)?;
-            default.display(f, indent + 1)?;
+            writeln!(f, "{}default:", "    ".repeat(indent + 1))?;
+            default.display(f, indent + 2)?;
         }
-        writeln!(f, "{}}}", indent)
+        Ok(())
     }
 }
 
 impl Executable for Switch {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Switch", "exec");
         let val = self.val().run(context)?;
-        let mut result = Value::null();
-        let mut matched = false;
         context
             .executor()
             .set_current_state(InterpreterState::Executing);
 
-        // If a case block does not end with a break statement then subsequent cases will be run without
-        // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
-
-        for case in self.cases().iter() {
-            let cond = case.condition();
-            let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(context)?) {
-                matched = true;
-                let result = block.run(context)?;
-                match context.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        context
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
-                }
+        // Lexical declarations in any of the clauses are scoped to the whole case block, so their
+        // bindings are created before any clause runs.
+        let clauses = self.clauses();
+        let has_scope = clauses
+            .iter()
+            .any(|clause| clause.has_lexical_declarations());
+        if has_scope {
+            let env = &mut context.realm_mut().environment;
+            env.push(new_declarative_environment(Some(
+                env.get_current_environment_ref().clone(),
+            )));
+            for clause in clauses {
+                clause.create_lexical_bindings(context);
             }
         }
 
-        if !matched {
-            if let Some(default) = self.default() {
-                context
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(context)?;
-                    match context.executor().get_current_state() {
-                        InterpreterState::Return => {
-                            // Early return.
-                            result = val;
-                            break;
-                        }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
-                            // Early break.
-                            break;
-                        }
-                        _ => {
-                            // Continue execution
-                        }
-                    }
-                    if i == default.len() - 1 {
-                        result = val;
-                    }
-                }
-            }
+        let result = self.run_clauses(&val, context);
+
+        if has_scope {
+            let _ = context.realm_mut().environment.pop();
         }
 
-        Ok(result)
+        result
     }
 }
 
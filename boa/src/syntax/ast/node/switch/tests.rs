@@ -119,6 +119,88 @@ fn default_taken_switch() {
     assert_eq!(&exec(scenario), "70");
 }
 
+#[test]
+fn fall_through_into_default_switch() {
+    let scenario = r#"
+        let a = "";
+
+        switch (1) {
+            case 1:
+                a += "one";
+            default:
+                a += "default";
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "\"onedefault\"");
+}
+
+#[test]
+fn default_between_cases_switch() {
+    let scenario = r#"
+        function test(value) {
+            let a = "";
+            switch (value) {
+                case 1:
+                    a += "one";
+                default:
+                    a += "default";
+                case 2:
+                    a += "two";
+                    break;
+                case 3:
+                    a += "three";
+            }
+            return a;
+        }
+
+        [test(1), test(2), test(3), test(4)].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"onedefaulttwo,two,three,defaulttwo\"");
+}
+
+#[test]
+fn lexical_declaration_switch_scope() {
+    let scenario = r#"
+        let a = "outer";
+        let b;
+
+        switch (1) {
+            case 1:
+                let a = "inner";
+            case 2:
+                b = a;
+        }
+
+        [a, b].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"outer,inner\"");
+}
+
+#[test]
+fn lexical_declaration_skipped_clause() {
+    let scenario = r#"
+        let x = "outer";
+
+        try {
+            switch (1) {
+                case 0:
+                    let x;
+                case 1:
+                    x = 1;
+            }
+        } catch (e) {
+            e.toString();
+        }
+    "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError: x is not defined\"");
+    assert_eq!(
+        &exec("let x = 'outer'; switch (1) { case 0: let x; default: } x"),
+        "\"outer\""
+    );
+}
+
 #[test]
 fn default_not_taken_switch() {
     let scenario = r#"
@@ -0,0 +1,285 @@
+//! Template literal node.
+
+use crate::{
+    builtins::Array,
+    exec::{Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    property::{Attribute, DataDescriptor},
+    syntax::ast::node::Node,
+    value::{Type, Value},
+    BoaProfiler, Context, Result,
+};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Template literals are string literals allowing embedded expressions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TemplateLit {
+    elements: Box<[TemplateElement]>,
+}
+
+impl TemplateLit {
+    /// Creates a new `TemplateLit` AST node.
+    pub fn new<E>(elements: E) -> Self
+    where
+        E: Into<Box<[TemplateElement]>>,
+    {
+        Self {
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the strings and substitutions of the template, in source order.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("`")?;
+        for element in self.elements.iter() {
+            match element {
+                TemplateElement::String(s) => display_cooked(f, s)?,
+                TemplateElement::Expr(expr) => {
+                    f.write_str("${")?;
+                    expr.display(f, indentation)?;
+                    f.write_str("}")?;
+                }
+            }
+        }
+        f.write_str("`")
+    }
+}
+
+impl Executable for TemplateLit {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "exec");
+        let mut result = String::new();
+
+        for element in self.elements.iter() {
+            match element {
+                TemplateElement::String(s) => result.push_str(s),
+                TemplateElement::Expr(expr) => {
+                    let value = expr.run(context)?;
+                    result.push_str(&value.to_string(context)?);
+                }
+            }
+        }
+
+        Ok(result.into())
+    }
+}
+
+impl fmt::Display for TemplateLit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<TemplateLit> for Node {
+    fn from(template: TemplateLit) -> Self {
+        Self::TemplateLit(template)
+    }
+}
+
+/// A string or a substitution of a template literal.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum TemplateElement {
+    /// The cooked value of a string chunk.
+    String(Box<str>),
+    /// A substitution expression.
+    Expr(Node),
+}
+
+/// A tagged template calls the tag function with the strings and the substitution values of
+/// a template literal.
+///
+/// The strings are passed as an array of the cooked strings, with a `raw` property holding the
+/// raw strings. The cooked string of a chunk with an invalid escape sequence is `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct TaggedTemplate {
+    tag: Box<Node>,
+    raws: Box<[Box<str>]>,
+    cookeds: Box<[Option<Box<str>>]>,
+    exprs: Box<[Node]>,
+}
+
+impl TaggedTemplate {
+    /// Creates a new `TaggedTemplate` AST node.
+    ///
+    /// There must be one more raw and cooked string than substitution expressions.
+    pub fn new<T, R, C, E>(tag: T, raws: R, cookeds: C, exprs: E) -> Self
+    where
+        T: Into<Node>,
+        R: Into<Box<[Box<str>]>>,
+        C: Into<Box<[Option<Box<str>>]>>,
+        E: Into<Box<[Node]>>,
+    {
+        Self {
+            tag: Box::new(tag.into()),
+            raws: raws.into(),
+            cookeds: cookeds.into(),
+            exprs: exprs.into(),
+        }
+    }
+
+    /// Gets the tag function expression.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the raw strings of the template.
+    pub fn raws(&self) -> &[Box<str>] {
+        &self.raws
+    }
+
+    /// Gets the cooked strings of the template, `None` for the ones with invalid escapes.
+    pub fn cookeds(&self) -> &[Option<Box<str>>] {
+        &self.cookeds
+    }
+
+    /// Gets the substitution expressions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        self.tag.display_member_object(f, indentation)?;
+        f.write_str("`")?;
+        for (i, raw) in self.raws.iter().enumerate() {
+            f.write_str(raw)?;
+            if let Some(expr) = self.exprs.get(i) {
+                f.write_str("${")?;
+                expr.display(f, indentation)?;
+                f.write_str("}")?;
+            }
+        }
+        f.write_str("`")
+    }
+
+    /// Creates the template object passed as the first argument to the tag function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    fn template_object(&self, context: &mut Context) -> Result<Value> {
+        let cooked_strings: Vec<Value> = self
+            .cookeds
+            .iter()
+            .map(|cooked| cooked.as_deref().map_or_else(Value::undefined, Value::from))
+            .collect();
+        let raw_strings: Vec<Value> = self.raws.iter().map(|raw| Value::from(&**raw)).collect();
+
+        let template = Array::new_array(context)?;
+        Array::construct_array(&template, &cooked_strings)?;
+        let raw = Array::new_array(context)?;
+        Array::construct_array(&raw, &raw_strings)?;
+
+        template.set_property(
+            "raw",
+            DataDescriptor::new(
+                raw,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+
+        Ok(template)
+    }
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+
+        let (this, func) = match self.tag() {
+            Node::GetConstField(ref get_const_field) => {
+                let mut obj = get_const_field.obj().run(context)?;
+                if obj.get_type() != Type::Object {
+                    obj = Value::Object(obj.to_object(context)?);
+                }
+                let func = obj.get_field_with_accessors(get_const_field.field(), context)?;
+                (obj, func)
+            }
+            Node::GetField(ref get_field) => {
+                let obj = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                let key = field.to_property_key(context)?;
+                let func = obj.get_field_with_accessors(key, context)?;
+                (obj, func)
+            }
+            _ => (context.global_object().clone(), self.tag().run(context)?),
+        };
+
+        let mut args = Vec::with_capacity(self.exprs.len() + 1);
+        args.push(self.template_object(context)?);
+        for expr in self.exprs.iter() {
+            args.push(expr.run(context)?);
+        }
+
+        let result = context.call(&func, &this, &args);
+
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
+    }
+}
+
+impl fmt::Display for TaggedTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<TaggedTemplate> for Node {
+    fn from(template: TaggedTemplate) -> Self {
+        Self::TaggedTemplate(template)
+    }
+}
+
+/// Writes a cooked template string, escaping the characters that would change its value when
+/// read back as a template chunk.
+fn display_cooked(f: &mut fmt::Formatter<'_>, cooked: &str) -> fmt::Result {
+    let mut chars = cooked.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '`' => f.write_str("\\`")?,
+            '\\' => f.write_str("\\\\")?,
+            '\r' => f.write_str("\\r")?,
+            '$' if chars.peek() == Some(&'{') => f.write_str("\\$")?,
+            ch => write!(f, "{}", ch)?,
+        }
+    }
+    Ok(())
+}
@@ -43,6 +43,16 @@ impl Throw {
             expr: Box::new(val.into()),
         }
     }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("throw ")?;
+        self.expr.display(f, indentation)
+    }
 }
 
 impl Executable for Throw {
@@ -54,7 +64,7 @@ impl Executable for Throw {
 
 impl fmt::Display for Throw {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "throw {}", self.expr)
+        self.display(f, 0)
     }
 }
 
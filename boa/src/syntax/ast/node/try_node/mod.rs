@@ -1,6 +1,6 @@
 use crate::{
     environment::lexical_environment::{new_declarative_environment, VariableScope},
-    exec::Executable,
+    exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
     syntax::ast::node::{Block, Identifier, Node},
     BoaProfiler, Context, Result, Value,
@@ -77,7 +77,7 @@ impl Try {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "{}try ", "    ".repeat(indentation))?;
+        f.write_str("try ")?;
         self.block.display(f, indentation)?;
 
         if let Some(ref catch) = self.catch {
@@ -128,7 +128,22 @@ impl Executable for Try {
         );
 
         if let Some(finally) = self.finally() {
-            finally.run(context)?;
+            // The `finally` block also runs fully after a `return`, `break` or `continue`.
+            let state = context.executor().get_current_state().clone();
+            context
+                .executor()
+                .set_current_state(InterpreterState::Executing);
+
+            // An abrupt completion of the `finally` block overrides the one of the `try` block,
+            // while a normal one keeps it.
+            let finally_res = finally.run(context);
+            if finally_res.is_err()
+                || context.executor().get_current_state() != &InterpreterState::Executing
+            {
+                return finally_res;
+            }
+
+            context.executor().set_current_state(state);
         }
 
         res
@@ -183,7 +198,7 @@ impl Catch {
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         f.write_str(" catch")?;
         if let Some(ref param) = self.parameter {
-            write!(f, "({})", param)?;
+            write!(f, " ({})", param)?;
         }
         f.write_str(" ")?;
         self.block.display(f, indentation)
@@ -93,3 +93,94 @@ fn catch_binding_finally() {
     "#;
     assert_eq!(&exec(scenario), "30");
 }
+
+#[test]
+fn finally_return_overrides_try_return() {
+    let scenario = r#"
+        function f() {
+            try {
+                return 10;
+            } finally {
+                return 20;
+            }
+        }
+
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "20");
+}
+
+#[test]
+fn finally_return_overrides_exception() {
+    let scenario = r#"
+        function f() {
+            try {
+                throw "error";
+            } finally {
+                return 20;
+            }
+        }
+
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "20");
+}
+
+#[test]
+fn finally_runs_fully_after_return() {
+    let scenario = r#"
+        let a = 10;
+        function f() {
+            try {
+                return a;
+            } finally {
+                a = 20;
+                a = a + 10;
+            }
+        }
+
+        f() + a;
+    "#;
+    assert_eq!(&exec(scenario), "40");
+}
+
+#[test]
+fn finally_runs_before_exception_propagates() {
+    let scenario = r#"
+        let log = "";
+        function f() {
+            try {
+                throw "error";
+            } finally {
+                log = log + "finally ";
+            }
+        }
+
+        try {
+            f();
+        } catch (e) {
+            log = log + e;
+        }
+
+        log;
+    "#;
+    assert_eq!(&exec(scenario), "\"finally error\"");
+}
+
+#[test]
+fn finally_break_overrides_try_completion() {
+    let scenario = r#"
+        let a = 0;
+        while (true) {
+            try {
+                throw "error";
+            } finally {
+                a = 10;
+                break;
+            }
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "10");
+}
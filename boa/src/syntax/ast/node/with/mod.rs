@@ -0,0 +1,102 @@
+use crate::{
+    environment::lexical_environment::new_with_environment,
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `with` statement extends the scope chain for a statement.
+///
+/// Syntax: `with (expression) statement`
+///
+/// The properties of the object the expression evaluates to become bindings that can be
+/// accessed by name inside the statement. It is not allowed in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    object: Box<Node>,
+    body: Box<Node>,
+}
+
+impl With {
+    pub fn object(&self) -> &Node {
+        &self.object
+    }
+
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Creates a `With` AST node.
+    pub fn new<O, B>(object: O, body: B) -> Self
+    where
+        O: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            object: Box::new(object.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("with (")?;
+        self.object().display(f, indentation)?;
+        f.write_str(") ")?;
+        self.body().display_statement(f, indentation)
+    }
+}
+
+impl Executable for With {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+        let object = self.object().run(context)?.to_object(context)?;
+
+        {
+            let env = &mut context.realm_mut().environment;
+            env.push(new_with_environment(
+                object.into(),
+                Some(env.get_current_environment_ref().clone()),
+            ));
+        }
+
+        let result = self.body().run(context);
+
+        // The object environment must be removed even if the body completed abruptly.
+        let _ = context.realm_mut().environment.pop();
+
+        result
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Self {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::exec;
+
+#[test]
+fn with_property_lookup() {
+    let scenario = r#"
+        var obj = { a: 1, b: 2 };
+        var result;
+        with (obj) {
+            result = a + b;
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn with_falls_back_to_outer_scope() {
+    let scenario = r#"
+        var outer = 10;
+        var obj = { a: 1 };
+        with (obj) {
+            a + outer;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "11");
+}
+
+#[test]
+fn with_assigns_to_object_property() {
+    let scenario = r#"
+        var obj = { a: 1 };
+        with (obj) {
+            a = 5;
+        }
+        obj.a;
+    "#;
+    assert_eq!(&exec(scenario), "5");
+}
+
+#[test]
+fn with_scope_ends_after_statement() {
+    let scenario = r#"
+        var obj = { inner: 1 };
+        with (obj) inner;
+        try {
+            inner;
+        } catch (e) {
+            e instanceof ReferenceError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn with_null_object() {
+    let scenario = r#"
+        try {
+            with (null) {}
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn with_strict_mode() {
+    let scenario = r#"'use strict';
+with ({}) {}"#;
+    assert_eq!(
+        &exec(scenario),
+        "\"SyntaxError\": \"Syntax Error: using 'with' statement not allowed in strict mode at position: 2:1\""
+    );
+}
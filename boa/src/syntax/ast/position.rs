@@ -85,6 +85,14 @@ impl Span {
         self.end
     }
 
+    /// Checks if the span has no width, i.e. it starts and ends at the same position.
+    ///
+    /// Empty spans point at a place between two characters, such as an insertion point.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+
     /// Checks if this span inclusively contains another span or position.
     #[inline]
     pub fn contains<S>(self, other: S) -> bool
@@ -238,6 +246,17 @@ mod tests {
         assert_eq!(span.end(), b);
     }
 
+    /// Checks that only spans starting and ending at the same position are empty.
+    #[test]
+    fn span_is_empty() {
+        let a = Position::new(10, 50);
+        let b = Position::new(10, 52);
+
+        assert!(Span::from(a).is_empty());
+        assert!(Span::new(a, a).is_empty());
+        assert!(!Span::new(a, b).is_empty());
+    }
+
     /// Checks that the `Span::contains()` method works properly.
     #[test]
     fn span_contains() {
@@ -0,0 +1,78 @@
+//! Non-fatal diagnostics reported while lexing and parsing.
+//!
+//! Diagnostics don't stop the parsing; they are collected by the parser and can be retrieved
+//! with [`Parser::take_diagnostics`](../parser/struct.Parser.html#method.take_diagnostics).
+
+use crate::syntax::ast::Span;
+use std::fmt;
+
+/// The kind of a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A `with` statement.
+    WithStatement,
+
+    /// A legacy octal integer literal, such as `017`.
+    LegacyOctalLiteral,
+
+    /// A legacy octal escape sequence in a string literal, such as `"\017"`.
+    LegacyOctalEscape,
+
+    /// An access to `arguments.caller`.
+    ArgumentsCaller,
+}
+
+impl DiagnosticKind {
+    /// Returns the message describing the diagnostic.
+    pub fn message(self) -> &'static str {
+        match self {
+            Self::WithStatement => "'with' statements are deprecated",
+            Self::LegacyOctalLiteral => {
+                "legacy octal literals are deprecated, use the '0o' prefix instead"
+            }
+            Self::LegacyOctalEscape => {
+                "octal escape sequences are deprecated, use hexadecimal escapes instead"
+            }
+            Self::ArgumentsCaller => "'arguments.caller' is deprecated",
+        }
+    }
+}
+
+/// A deprecation diagnostic, pointing at the legacy code that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostic {
+    kind: DiagnosticKind,
+    span: Span,
+}
+
+impl Diagnostic {
+    /// Creates a new diagnostic.
+    #[inline]
+    pub(crate) fn new(kind: DiagnosticKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    /// Gets the kind of the diagnostic.
+    #[inline]
+    pub fn kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+
+    /// Gets the span of the code that caused the diagnostic.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, col {}",
+            self.kind.message(),
+            self.span.start().line_number(),
+            self.span.start().column_number()
+        )
+    }
+}
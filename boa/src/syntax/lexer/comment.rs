@@ -31,11 +31,12 @@ impl<R> Tokenizer<R> for SingleLineComment {
 
         // Skip either to the end of the line or to the end of the input
         while let Some(ch) = cursor.peek()? {
-            if ch == '\n' {
-                break;
-            } else {
+            match ch {
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => break,
                 // Consume char.
-                cursor.next_char()?.expect("Comment character vansihed");
+                _ => {
+                    cursor.next_char()?.expect("Comment character vansihed");
+                }
             }
         }
         Ok(Token::new(
@@ -69,7 +70,7 @@ impl<R> Tokenizer<R> for MultiLineComment {
             if let Some(ch) = cursor.next_char()? {
                 if ch == '*' && cursor.next_is('/')? {
                     break;
-                } else if ch == '\n' {
+                } else if let '\n' | '\r' | '\u{2028}' | '\u{2029}' = ch {
                     new_line = true;
                 }
             } else {
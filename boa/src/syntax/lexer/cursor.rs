@@ -1,7 +1,16 @@
 //! Module implementing the lexer cursor. This is used for managing the input byte stream.
 
-use crate::{profiler::BoaProfiler, syntax::ast::Position};
-use std::io::{self, Bytes, Error, ErrorKind, Read};
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{Position, Span},
+        diagnostic::{Diagnostic, DiagnosticKind},
+    },
+};
+use std::io::{self, Error, ErrorKind, Read};
+
+/// Size of the buffer used to read the source code in chunks.
+const READ_BUFFER_SIZE: usize = 4096;
 
 /// Cursor over the source code.
 #[derive(Debug)]
@@ -9,6 +18,11 @@ pub(super) struct Cursor<R> {
     iter: InnerIter<R>,
     pos: Position,
     strict_mode: bool,
+    /// Whether deprecated legacy features are reported as diagnostics.
+    report_deprecations: bool,
+    /// Whether the early errors of strict mode code are reported.
+    early_errors: bool,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl<R> Cursor<R> {
@@ -41,6 +55,36 @@ impl<R> Cursor<R> {
     pub(super) fn set_strict_mode(&mut self, strict_mode: bool) {
         self.strict_mode = strict_mode
     }
+
+    /// Returns whether legacy features are rejected as early errors of strict mode code.
+    #[inline]
+    pub(super) fn strict_early_errors(&self) -> bool {
+        self.strict_mode && self.early_errors
+    }
+
+    #[inline]
+    pub(super) fn set_early_errors(&mut self, early_errors: bool) {
+        self.early_errors = early_errors
+    }
+
+    #[inline]
+    pub(super) fn set_report_deprecations(&mut self, report_deprecations: bool) {
+        self.report_deprecations = report_deprecations
+    }
+
+    /// Reports the use of a deprecated legacy feature, if deprecations are reported.
+    #[inline]
+    pub(super) fn report_deprecation(&mut self, kind: DiagnosticKind, span: Span) {
+        if self.report_deprecations {
+            self.diagnostics.push(Diagnostic::new(kind, span));
+        }
+    }
+
+    /// Takes the diagnostics reported so far.
+    #[inline]
+    pub(super) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
 }
 
 impl<R> Cursor<R>
@@ -51,9 +95,12 @@ where
     #[inline]
     pub(super) fn new(inner: R) -> Self {
         Self {
-            iter: InnerIter::new(inner.bytes()),
+            iter: InnerIter::new(inner),
             pos: Position::new(1, 1),
             strict_mode: false,
+            report_deprecations: false,
+            early_errors: true,
+            diagnostics: Vec::new(),
         }
     }
 
@@ -176,18 +223,27 @@ where
 }
 
 /// Inner iterator for a cursor.
+///
+/// The source is read in chunks of at most `READ_BUFFER_SIZE` bytes, as the characters are
+/// needed, so the whole input never has to be in memory at once.
 #[derive(Debug)]
 struct InnerIter<R> {
-    iter: Bytes<R>,
+    reader: R,
+    buffer: Box<[u8]>,
+    buffer_pos: usize,
+    buffer_len: usize,
     peeked_char: Option<Option<char>>,
 }
 
 impl<R> InnerIter<R> {
     /// Creates a new inner iterator.
     #[inline]
-    fn new(iter: Bytes<R>) -> Self {
+    fn new(reader: R) -> Self {
         Self {
-            iter,
+            reader,
+            buffer: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+            buffer_pos: 0,
+            buffer_len: 0,
             peeked_char: None,
         }
     }
@@ -214,6 +270,28 @@ where
         Ok(())
     }
 
+    /// Retrieves the next byte, reading the next chunk of the source if the buffer is empty.
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        if self.buffer_pos == self.buffer_len {
+            self.buffer_len = loop {
+                match self.reader.read(&mut self.buffer) {
+                    Ok(len) => break len,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            };
+            self.buffer_pos = 0;
+
+            if self.buffer_len == 0 {
+                return Ok(None);
+            }
+        }
+
+        let byte = self.buffer[self.buffer_pos];
+        self.buffer_pos += 1;
+        Ok(Some(byte))
+    }
+
     /// Peeks the next UTF-8 checked character.
     #[inline]
     pub(super) fn peek_char(&mut self) -> Result<Option<char>, Error> {
@@ -232,7 +310,7 @@ where
             return Ok(v);
         }
 
-        let first_byte = match self.iter.next().transpose()? {
+        let first_byte = match self.next_byte()? {
             Some(b) => b,
             None => return Ok(None),
         };
@@ -254,9 +332,8 @@ where
             };
 
             for b in buf.iter_mut().take(num_bytes).skip(1) {
-                let next = match self.iter.next() {
-                    Some(Ok(b)) => b,
-                    Some(Err(e)) => return Err(e),
+                let next = match self.next_byte()? {
+                    Some(b) => b,
                     None => {
                         return Err(io::Error::new(
                             io::ErrorKind::InvalidData,
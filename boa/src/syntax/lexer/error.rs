@@ -8,6 +8,24 @@
 use super::Position;
 use std::{error::Error as StdError, fmt, io};
 
+/// Message of the error for legacy octal integer literals in strict mode code.
+pub(crate) const IMPLICIT_OCTAL_IN_STRICT_MODE: &str =
+    "implicit octal literals are not allowed in strict mode";
+
+/// Message of the error for legacy octal escape sequences in strict mode code.
+pub(crate) const OCTAL_ESCAPE_IN_STRICT_MODE: &str =
+    "octal escape sequences are not allowed in strict mode";
+
+/// Message of the error for `\8` and `\9` escape sequences in strict mode code.
+pub(crate) const NON_OCTAL_DECIMAL_ESCAPE_IN_STRICT_MODE: &str =
+    "\\8 and \\9 are not allowed in strict mode";
+
+/// Message of the error for template literals missing their closing backtick.
+pub(crate) const UNTERMINATED_TEMPLATE_LITERAL: &str = "Unterminated template literal";
+
+/// Start of the message of the error for characters that cannot begin any token.
+pub(crate) const UNEXPECTED_CHARACTER: &str = "unexpected character";
+
 #[derive(Debug)]
 pub enum Error {
     /// An IO error is raised to indicate an issue when the lexer is reading data that isn't
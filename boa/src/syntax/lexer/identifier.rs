@@ -9,10 +9,9 @@ use crate::{
     },
 };
 use std::io::Read;
+use unicode_xid::UnicodeXID;
 
-const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
-    "eval",
-    "arguments",
+const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 9] = [
     "implements",
     "interface",
     "let",
@@ -24,6 +23,41 @@ const STRICT_FORBIDDEN_IDENTIFIERS: [&str; 11] = [
     "yield",
 ];
 
+/// Checks if a character can start an identifier.
+///
+/// This is the case for `$`, `_` and the characters with the Unicode `XID_Start` property.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierStart
+#[inline]
+pub fn is_identifier_start(ch: char) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphabetic() || ch == '$' || ch == '_'
+    } else {
+        UnicodeXID::is_xid_start(ch)
+    }
+}
+
+/// Checks if a character can be part of an identifier, after its first character.
+///
+/// This is the case for `$`, the characters with the Unicode `XID_Continue` property (which
+/// includes `_` and the digits), and the zero width non-joiner and joiner (U+200C and U+200D).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-IdentifierPart
+#[inline]
+pub fn is_identifier_part(ch: char) -> bool {
+    if ch.is_ascii() {
+        ch.is_ascii_alphanumeric() || ch == '$' || ch == '_'
+    } else {
+        ch == '\u{200C}' || ch == '\u{200D}' || UnicodeXID::is_xid_continue(ch)
+    }
+}
+
 /// Identifier lexing.
 ///
 /// More information:
@@ -53,9 +87,7 @@ impl<R> Tokenizer<R> for Identifier {
 
         let mut buf = self.init.to_string();
 
-        cursor.take_while_pred(&mut buf, &|c: char| {
-            c.is_alphabetic() || c.is_digit(10) || c == '_'
-        })?;
+        cursor.take_while_pred(&mut buf, &is_identifier_part)?;
 
         let tk = match buf.as_str() {
             "true" => TokenKind::BooleanLiteral(true),
@@ -63,7 +95,7 @@ impl<R> Tokenizer<R> for Identifier {
             "null" => TokenKind::NullLiteral,
             slice => {
                 if let Ok(keyword) = slice.parse() {
-                    if cursor.strict_mode() && keyword == Keyword::With {
+                    if cursor.strict_early_errors() && keyword == Keyword::With {
                         return Err(Error::Syntax(
                             "using 'with' statement not allowed in strict mode".into(),
                             start_pos,
@@ -71,7 +103,8 @@ impl<R> Tokenizer<R> for Identifier {
                     }
                     TokenKind::Keyword(keyword)
                 } else {
-                    if cursor.strict_mode() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice) {
+                    if cursor.strict_early_errors() && STRICT_FORBIDDEN_IDENTIFIERS.contains(&slice)
+                    {
                         return Err(Error::Syntax(
                             format!(
                                 "using future reserved keyword '{}' not allowed in strict mode",
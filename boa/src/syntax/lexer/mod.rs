@@ -40,9 +40,14 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
-use crate::syntax::ast::{Punctuator, Span};
+use crate::syntax::{
+    ast::{Punctuator, Span},
+    diagnostic::Diagnostic,
+};
 pub use crate::{profiler::BoaProfiler, syntax::ast::Position};
 pub use error::Error;
+use error::UNEXPECTED_CHARACTER;
+pub use identifier::{is_identifier_part, is_identifier_start};
 use std::io::Read;
 pub use token::{Token, TokenKind};
 
@@ -100,6 +105,24 @@ impl<R> Lexer<R> {
         self.cursor.set_strict_mode(strict_mode)
     }
 
+    /// Sets whether strict mode code rejects legacy features, such as legacy octal literals.
+    #[inline]
+    pub(super) fn set_early_errors(&mut self, early_errors: bool) {
+        self.cursor.set_early_errors(early_errors)
+    }
+
+    /// Sets whether deprecated legacy features, such as legacy octal literals, are reported.
+    #[inline]
+    pub(super) fn set_report_deprecations(&mut self, report_deprecations: bool) {
+        self.cursor.set_report_deprecations(report_deprecations)
+    }
+
+    /// Takes the diagnostics reported so far.
+    #[inline]
+    pub(super) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.cursor.take_diagnostics()
+    }
+
     /// Creates a new lexer.
     #[inline]
     pub fn new(reader: R) -> Self
@@ -169,6 +192,15 @@ impl<R> Lexer<R> {
         }
     }
 
+    /// Lexes the rest of a template literal after a substitution, with the closing `}` of the
+    /// substitution already being consumed.
+    pub(crate) fn lex_template(&mut self, start: Position) -> Result<Token, Error>
+    where
+        R: Read,
+    {
+        TemplateLiteral.lex(&mut self.cursor, start)
+    }
+
     /// Retrieves the next token from the lexer.
     // We intentionally don't implement Iterator trait as Result<Option> is cleaner to handle.
     #[allow(clippy::should_implement_trait)]
@@ -198,7 +230,7 @@ impl<R> Lexer<R> {
             '"' | '\'' => StringLiteral::new(next_chr).lex(&mut self.cursor, start),
             '`' => TemplateLiteral.lex(&mut self.cursor, start),
             _ if next_chr.is_digit(10) => NumberLiteral::new(next_chr).lex(&mut self.cursor, start),
-            _ if next_chr.is_alphabetic() || next_chr == '$' || next_chr == '_' => {
+            _ if is_identifier_start(next_chr) => {
                 Identifier::new(next_chr).lex(&mut self.cursor, start)
             }
             ';' => Ok(Token::new(
@@ -248,7 +280,8 @@ impl<R> Lexer<R> {
             }
             _ => {
                 let details = format!(
-                    "unexpected '{}' at line {}, column {}",
+                    "{} '{}' at line {}, column {}",
+                    UNEXPECTED_CHARACTER,
                     next_chr,
                     start.line_number(),
                     start.column_number()
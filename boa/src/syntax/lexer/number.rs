@@ -1,11 +1,15 @@
 //! This module implements lexing for number literals (123, 787) used in the JavaScript programing language.
 
-use super::{Cursor, Error, TokenKind, Tokenizer};
+use super::{
+    error::IMPLICIT_OCTAL_IN_STRICT_MODE, is_identifier_part, is_identifier_start, Cursor, Error,
+    TokenKind, Tokenizer,
+};
 use crate::{
     builtins::BigInt,
     profiler::BoaProfiler,
     syntax::{
         ast::{Position, Span},
+        diagnostic::DiagnosticKind,
         lexer::{token::Numeric, Token},
     },
 };
@@ -112,6 +116,34 @@ where
     Ok(())
 }
 
+/// Utility function for giving a helpful error when an integer literal ending in a decimal point
+/// is directly followed by an identifier, as in `0.toFixed()`.
+///
+/// The exponent indicators `e` and `E` are left to the caller, since `1.e3` is a valid literal.
+fn check_member_access_after_integer<R>(buf: &str, cursor: &mut Cursor<R>) -> Result<(), Error>
+where
+    R: Read,
+{
+    if cursor.next_is_pred(&|ch: char| ch != 'e' && ch != 'E' && is_identifier_start(ch))? {
+        let pos = cursor.pos();
+        let mut identifier = String::new();
+        cursor.take_while_pred(&mut identifier, &is_identifier_part)?;
+        let call = if cursor.next_is('(')? { "()" } else { "" };
+
+        Err(Error::syntax(
+            format!(
+                "unexpected identifier after number; did you mean ({}).{}{}?",
+                buf.trim_end_matches('.'),
+                identifier,
+                call
+            ),
+            pos,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 /// Utility function for checking the NumericLiteral is not followed by an `IdentifierStart` or `DecimalDigit` character.
 ///
 /// More information:
@@ -145,6 +177,9 @@ impl<R> Tokenizer<R> for NumberLiteral {
         // Default assume the number is a base 10 integer.
         let mut kind = NumericKind::Integer(10);
 
+        // Whether this is a LegacyOctalIntegerLiteral, which is reported as deprecated.
+        let mut legacy_octal = false;
+
         let c = cursor.peek();
 
         if self.init == '0' {
@@ -216,10 +251,10 @@ impl<R> Tokenizer<R> for NumberLiteral {
                     ch => {
                         if ch.is_digit(8) {
                             // LegacyOctalIntegerLiteral
-                            if cursor.strict_mode() {
+                            if cursor.strict_early_errors() {
                                 // LegacyOctalIntegerLiteral is forbidden with strict mode true.
                                 return Err(Error::syntax(
-                                    "implicit octal literals are not allowed in strict mode",
+                                    IMPLICIT_OCTAL_IN_STRICT_MODE,
                                     start_pos,
                                 ));
                             } else {
@@ -229,12 +264,13 @@ impl<R> Tokenizer<R> for NumberLiteral {
                                 buf.push(cursor.next_char()?.expect("'0' character vanished"));
 
                                 kind = NumericKind::Integer(8);
+                                legacy_octal = true;
                             }
                         } else if ch.is_digit(10) {
                             // Indicates a numerical digit comes after then 0 but it isn't an octal digit
                             // so therefore this must be a number with an unneeded leading 0. This is
                             // forbidden in strict mode.
-                            if cursor.strict_mode() {
+                            if cursor.strict_early_errors() {
                                 return Err(Error::syntax(
                                     "leading 0's are not allowed in strict mode",
                                     start_pos,
@@ -284,6 +320,12 @@ impl<R> Tokenizer<R> for NumberLiteral {
                     // Consume digits until a non-digit character is encountered or all the characters are consumed.
                     cursor.take_while_pred(&mut buf, &|c: char| c.is_digit(kind.base()))?;
 
+                    // A member access on an integer literal such as `0.toFixed()` is lexed as the
+                    // number `0.` followed by an identifier, which is not allowed.
+                    if buf.ends_with('.') {
+                        check_member_access_after_integer(&buf, cursor)?;
+                    }
+
                     // The non-digit character at this point must be an 'e' or 'E' to indicate an Exponent Part.
                     // Another '.' or 'n' is not allowed.
                     match cursor.peek()? {
@@ -349,9 +391,11 @@ impl<R> Tokenizer<R> for NumberLiteral {
             }
         };
 
-        Ok(Token::new(
-            TokenKind::NumericLiteral(num),
-            Span::new(start_pos, cursor.pos()),
-        ))
+        let span = Span::new(start_pos, cursor.pos());
+        if legacy_octal {
+            cursor.report_deprecation(DiagnosticKind::LegacyOctalLiteral, span);
+        }
+
+        Ok(Token::new(TokenKind::NumericLiteral(num), span))
     }
 }
@@ -5,7 +5,11 @@ use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{Position, Span},
-        lexer::{Token, TokenKind},
+        diagnostic::DiagnosticKind,
+        lexer::{
+            error::{NON_OCTAL_DECIMAL_ESCAPE_IN_STRICT_MODE, OCTAL_ESCAPE_IN_STRICT_MODE},
+            Token, TokenKind,
+        },
     },
 };
 use std::{
@@ -83,14 +87,56 @@ impl<R> Tokenizer<R> for StringLiteral {
                         ))
                     })?;
 
-                    if escape != '\n' {
+                    // A line continuation joins the lines, `\r\n` is read as a single `\r`.
+                    if !matches!(escape, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
                         match escape {
                             'n' => buf.push('\n' as u16),
                             'r' => buf.push('\r' as u16),
                             't' => buf.push('\t' as u16),
                             'b' => buf.push('\x08' as u16),
                             'f' => buf.push('\x0c' as u16),
-                            '0' => buf.push('\0' as u16),
+                            '0' if !cursor.next_is_pred(&|ch: char| ch.is_ascii_digit())? => {
+                                buf.push('\0' as u16)
+                            }
+                            '0'..='7' => {
+                                // LegacyOctalEscapeSequence
+                                if cursor.strict_early_errors() {
+                                    return Err(Error::syntax(
+                                        OCTAL_ESCAPE_IN_STRICT_MODE,
+                                        next_chr_start,
+                                    ));
+                                }
+
+                                // Escapes starting with 0 to 3 take up to three digits, and the
+                                // others up to two, so the value is at most 0o377.
+                                let max_len = if escape <= '3' { 3 } else { 2 };
+                                let mut code_point =
+                                    escape.to_digit(8).expect("octal digit vanished");
+                                for _ in 1..max_len {
+                                    if !cursor.next_is_pred(&|ch: char| ch.is_digit(8))? {
+                                        break;
+                                    }
+                                    let digit = cursor.next_char()?.expect("octal digit vanished");
+                                    code_point =
+                                        code_point * 8 + digit.to_digit(8).expect("octal digit");
+                                }
+
+                                buf.push(code_point as u16);
+                                cursor.report_deprecation(
+                                    DiagnosticKind::LegacyOctalEscape,
+                                    Span::new(next_chr_start, cursor.pos()),
+                                );
+                            }
+                            '8' | '9' => {
+                                // NonOctalDecimalEscapeSequence
+                                if cursor.strict_early_errors() {
+                                    return Err(Error::syntax(
+                                        NON_OCTAL_DECIMAL_ESCAPE_IN_STRICT_MODE,
+                                        next_chr_start,
+                                    ));
+                                }
+                                buf.push(escape as u16);
+                            }
                             'x' => {
                                 let mut code_point_utf8_bytes = [0u8; 2];
                                 cursor.fill_bytes(&mut code_point_utf8_bytes)?;
@@ -160,9 +206,9 @@ impl<R> Tokenizer<R> for StringLiteral {
                             ch => {
                                 let details = format!(
                                     "invalid escape sequence `{}` at line {}, column {}",
+                                    ch,
                                     next_chr_start.line_number(),
                                     next_chr_start.column_number(),
-                                    ch
                                 );
                                 return Err(Error::syntax(details, cursor.pos()));
                             }
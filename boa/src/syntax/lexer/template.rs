@@ -5,20 +5,23 @@ use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{Position, Span},
-        lexer::{Token, TokenKind},
+        lexer::{error::UNTERMINATED_TEMPLATE_LITERAL, token::TemplateString, Token, TokenKind},
     },
 };
 use std::io::{self, ErrorKind, Read};
 
 /// Template literal lexing.
 ///
-/// Expects: Initial ` to already be consumed by cursor.
+/// Expects: Initial ` (or the closing } of a substitution) to already be consumed by cursor.
+///
+/// Lexes a template chunk up to the closing ` or the next `${`, producing a
+/// `TemplateNoSubstitution` or a `TemplateMiddle` token respectively.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-template-literals
+/// [spec]: https://tc39.es/ecma262/#sec-template-literal-lexical-components
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
 #[derive(Debug, Clone, Copy)]
 pub(super) struct TemplateLiteral;
@@ -30,23 +33,204 @@ impl<R> Tokenizer<R> for TemplateLiteral {
     {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Lexing");
 
-        let mut buf = String::new();
-        loop {
-            match cursor.next_char()? {
-                None => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "Unterminated template literal",
-                    )));
+        let mut raw = String::new();
+        // `None` once an invalid escape sequence has been found.
+        let mut cooked: Option<Vec<u16>> = Some(Vec::new());
+
+        let substitution = loop {
+            let next_chr = cursor.next_char()?.ok_or_else(|| {
+                Error::from(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    UNTERMINATED_TEMPLATE_LITERAL,
+                ))
+            })?;
+
+            match next_chr {
+                '`' => break false,
+                '$' if cursor.next_is('{')? => break true,
+                '\\' => {
+                    raw.push('\\');
+                    if !Self::lex_escape(cursor, &mut raw, &mut cooked)? {
+                        cooked = None;
+                    }
+                }
+                // Line terminators are normalized to `\n` in both the raw and cooked strings.
+                '\r' => {
+                    let _ = cursor.next_is('\n')?;
+                    raw.push('\n');
+                    push_char(&mut cooked, '\n');
+                }
+                ch => {
+                    raw.push(ch);
+                    push_char(&mut cooked, ch);
                 }
-                Some('`') => break,                 // Template literal finished.
-                Some(next_ch) => buf.push(next_ch), // TODO when there is an expression inside the literal
+            }
+        };
+
+        // As with string literals, strings are stored as UTF-8, so a lone surrogate escape becomes
+        // a replacement character of the same length.
+        let cooked = cooked.map(|buf| String::from_utf16_lossy(&buf));
+        let template_string = TemplateString::new(raw, cooked);
+        let kind = if substitution {
+            TokenKind::template_middle(template_string)
+        } else {
+            TokenKind::template_no_substitution(template_string)
+        };
+
+        Ok(Token::new(kind, Span::new(start_pos, cursor.pos())))
+    }
+}
+
+impl TemplateLiteral {
+    /// Lexes an escape sequence, assuming that the `\` has already been consumed.
+    ///
+    /// The source text is appended to `raw` and the escaped value to `cooked`. Returns `false`
+    /// if the escape sequence is not valid, in which case the character that made it invalid is
+    /// not consumed.
+    fn lex_escape<R>(
+        cursor: &mut Cursor<R>,
+        raw: &mut String,
+        cooked: &mut Option<Vec<u16>>,
+    ) -> Result<bool, Error>
+    where
+        R: Read,
+    {
+        let escape = cursor.next_char()?.ok_or_else(|| {
+            Error::from(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "unterminated escape sequence in template literal",
+            ))
+        })?;
+
+        match escape {
+            // A line continuation, `\r\n` is read as a single line terminator.
+            '\r' => {
+                let _ = cursor.next_is('\n')?;
+                raw.push('\n');
+            }
+            '\n' | '\u{2028}' | '\u{2029}' => raw.push(escape),
+            'n' | 'r' | 't' | 'b' | 'f' | 'v' => {
+                raw.push(escape);
+                let escaped = match escape {
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'b' => '\x08',
+                    'f' => '\x0c',
+                    _ => '\x0b',
+                };
+                push_char(cooked, escaped);
+            }
+            '0' if !cursor.next_is_pred(&|ch: char| ch.is_ascii_digit())? => {
+                raw.push(escape);
+                push_char(cooked, '\0');
+            }
+            // Octal and non-octal decimal escapes are not allowed in templates.
+            '0'..='9' => {
+                raw.push(escape);
+                return Ok(false);
+            }
+            'x' => {
+                raw.push(escape);
+                match Self::lex_hex_digits(cursor, raw, 2)? {
+                    Some(code_point) => push_code_point(cooked, code_point),
+                    None => return Ok(false),
+                }
+            }
+            'u' => {
+                raw.push(escape);
+                if cursor.next_is('{')? {
+                    raw.push('{');
+                    let mut code_point: u32 = 0;
+                    let mut digits = 0;
+                    loop {
+                        match cursor.peek()? {
+                            Some('}') if digits > 0 => {
+                                let _ = cursor.next_char()?;
+                                raw.push('}');
+                                break;
+                            }
+                            Some(ch) if ch.is_ascii_hexdigit() => {
+                                let _ = cursor.next_char()?;
+                                raw.push(ch);
+                                digits += 1;
+                                code_point = code_point
+                                    .saturating_mul(16)
+                                    .saturating_add(ch.to_digit(16).expect("hex digit"));
+                            }
+                            _ => return Ok(false),
+                        }
+                    }
+
+                    if code_point > 0x10_FFFF {
+                        return Ok(false);
+                    }
+                    push_code_point(cooked, code_point);
+                } else {
+                    match Self::lex_hex_digits(cursor, raw, 4)? {
+                        Some(code_point) => push_code_point(cooked, code_point),
+                        None => return Ok(false),
+                    }
+                }
+            }
+            // Any other character, including ` and $, escapes itself.
+            ch => {
+                raw.push(ch);
+                push_char(cooked, ch);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Lexes exactly `count` hexadecimal digits, returning their value.
+    ///
+    /// Returns `None` without consuming it at the first character that is not a hexadecimal
+    /// digit.
+    fn lex_hex_digits<R>(
+        cursor: &mut Cursor<R>,
+        raw: &mut String,
+        count: usize,
+    ) -> Result<Option<u32>, Error>
+    where
+        R: Read,
+    {
+        let mut code_point = 0;
+        for _ in 0..count {
+            match cursor.peek()? {
+                Some(ch) if ch.is_ascii_hexdigit() => {
+                    let _ = cursor.next_char()?;
+                    raw.push(ch);
+                    code_point = code_point * 16 + ch.to_digit(16).expect("hex digit");
+                }
+                _ => return Ok(None),
             }
         }
 
-        Ok(Token::new(
-            TokenKind::template_literal(buf),
-            Span::new(start_pos, cursor.pos()),
-        ))
+        Ok(Some(code_point))
+    }
+}
+
+/// Appends a character to the cooked string, if it is still valid.
+fn push_char(cooked: &mut Option<Vec<u16>>, ch: char) {
+    if let Some(buf) = cooked {
+        let mut code_units = [0u16; 2];
+        buf.extend_from_slice(ch.encode_utf16(&mut code_units));
+    }
+}
+
+/// Appends a code point to the cooked string, if it is still valid.
+///
+/// Code points above `0xFFFF` are encoded as a surrogate pair.
+fn push_code_point(cooked: &mut Option<Vec<u16>>, code_point: u32) {
+    if let Some(buf) = cooked {
+        if code_point <= 0xFFFF {
+            buf.push(code_point as u16);
+        } else {
+            let cu1 = ((code_point - 0x1_0000) / 0x400 + 0xD800) as u16;
+            let cu2 = ((code_point - 0x1_0000) % 0x400 + 0xDC00) as u16;
+            buf.push(cu1);
+            buf.push(cu2);
+        }
     }
 }
@@ -2,7 +2,7 @@
 #![allow(clippy::indexing_slicing)]
 
 use super::regex::RegExpFlags;
-use super::token::Numeric;
+use super::token::{Numeric, TemplateString};
 use super::*;
 use super::{Error, Position};
 use crate::syntax::ast::Keyword;
@@ -67,6 +67,37 @@ fn check_string() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_string_legacy_escapes() {
+    let s = r#"'\0' '\07' '\101' '\1010' '\477' '\08' '\8\9'"#;
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::string_literal("\0"),
+        TokenKind::string_literal("\x07"),
+        TokenKind::string_literal("A"),
+        TokenKind::string_literal("A0"),
+        TokenKind::string_literal("'7"),
+        TokenKind::string_literal("\08"),
+        TokenKind::string_literal("89"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_string_legacy_escapes_strict() {
+    for s in &[r#"'\07'"#, r#"'\08'"#, r#"'\8'"#] {
+        let mut lexer = Lexer::new(s.as_bytes());
+        lexer.set_strict_mode(true);
+        assert!(lexer.next().is_err(), "{} should not lex in strict mode", s);
+    }
+
+    let mut lexer = Lexer::new(&br#"'\0'"#[..]);
+    lexer.set_strict_mode(true);
+    expect_tokens(&mut lexer, &[TokenKind::string_literal("\0")]);
+}
+
 #[test]
 fn check_template_literal_simple() {
     let s = "`I'm a template literal`";
@@ -74,7 +105,53 @@ fn check_template_literal_simple() {
 
     assert_eq!(
         lexer.next().unwrap().unwrap().kind(),
-        &TokenKind::template_literal("I'm a template literal")
+        &TokenKind::template_no_substitution(TemplateString::new(
+            "I'm a template literal",
+            Some("I'm a template literal")
+        ))
+    );
+}
+
+#[test]
+fn check_template_literal_raw_and_cooked() {
+    let s = "`a\\n\\u{41}\\x42\\`\r\nb`";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::template_no_substitution(TemplateString::new(
+            "a\\n\\u{41}\\x42\\`\nb",
+            Some("a\nAB`\nb")
+        ))
+    );
+}
+
+#[test]
+fn check_template_literal_substitution() {
+    let s = "`a${";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    assert_eq!(
+        lexer.next().unwrap().unwrap().kind(),
+        &TokenKind::template_middle(TemplateString::new("a", Some("a")))
+    );
+}
+
+#[test]
+fn check_template_literal_invalid_escape() {
+    let s = r#"`\unicode and \u{55}` `\01` `\xg`"#;
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    expect_tokens(
+        &mut lexer,
+        &[
+            TokenKind::template_no_substitution(TemplateString::new(
+                r#"\unicode and \u{55}"#,
+                None::<&str>,
+            )),
+            TokenKind::template_no_substitution(TemplateString::new(r#"\01"#, None::<&str>)),
+            TokenKind::template_no_substitution(TemplateString::new(r#"\xg"#, None::<&str>)),
+        ],
     );
 }
 
@@ -446,6 +523,30 @@ fn number_followed_by_dot() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn number_followed_by_identifier() {
+    let mut lexer = Lexer::new(&b"0.toFixed()"[..]);
+
+    let err = lexer.next().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Syntax Error: unexpected identifier after number; did you mean (0).toFixed()? at position: 1:3"
+    );
+}
+
+#[test]
+fn number_followed_by_space_and_dot() {
+    let mut lexer = Lexer::new(&b"0 .toFixed"[..]);
+
+    let expected = [
+        TokenKind::numeric_literal(0),
+        TokenKind::Punctuator(Punctuator::Dot),
+        TokenKind::identifier("toFixed"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn regex_literal() {
     let mut lexer = Lexer::new(&b"/(?:)/"[..]);
@@ -701,4 +802,158 @@ mod carriage_return {
         expect_tokens_with_lines(2, "-\n\r3");
         expect_tokens_with_lines(3, "-\r\n\n\r3");
     }
+
+    #[test]
+    fn single_line_comment_ends_at_separators() {
+        expect_tokens_with_lines(1, "-// comment\u{2028}3");
+        expect_tokens_with_lines(1, "-// comment\u{2029}3");
+        expect_tokens_with_lines(1, "-// comment\r3");
+        expect_tokens_with_lines(1, "-// comment\r\n3");
+    }
+
+    #[test]
+    fn multi_line_comment_with_separators() {
+        let mut lexer = Lexer::new("- /* a\u{2028}b */ 3".as_bytes());
+
+        let expected = [
+            TokenKind::Punctuator(Punctuator::Sub),
+            TokenKind::LineTerminator,
+            TokenKind::NumericLiteral(Numeric::Integer(3)),
+        ];
+
+        expect_tokens(&mut lexer, &expected);
+    }
+
+    #[test]
+    fn windows_line_numbers() {
+        let mut lexer = Lexer::new(&b"x\r\ny\r\n"[..]);
+
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (1, 2)));
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 2), (2, 1)));
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 1), (2, 2)));
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 2), (3, 1)));
+        assert!(lexer.next().unwrap().is_none());
+    }
+
+    #[test]
+    fn string_line_continuation() {
+        let mut lexer = Lexer::new(&b"'a\\\r\nb' 'c\\\rd' 'e\\\nf'"[..]);
+
+        let expected = [
+            TokenKind::string_literal("ab"),
+            TokenKind::string_literal("cd"),
+            TokenKind::string_literal("ef"),
+        ];
+
+        expect_tokens(&mut lexer, &expected);
+    }
+
+    #[test]
+    fn string_line_continuation_line_numbers() {
+        let mut lexer = Lexer::new(&b"'a\\\r\nb' x"[..]);
+
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((1, 1), (2, 3)));
+        assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 4), (2, 5)));
+    }
+}
+
+#[test]
+fn identifier_start_characters() {
+    assert!(is_identifier_start('a'));
+    assert!(is_identifier_start('Z'));
+    assert!(is_identifier_start('$'));
+    assert!(is_identifier_start('_'));
+    assert!(is_identifier_start('ä'));
+    assert!(is_identifier_start('π'));
+    assert!(is_identifier_start('中'));
+
+    assert!(!is_identifier_start('1'));
+    assert!(!is_identifier_start('-'));
+    assert!(!is_identifier_start(' '));
+    assert!(!is_identifier_start('\u{200D}'));
+    assert!(!is_identifier_start('٣'));
+}
+
+#[test]
+fn identifier_part_characters() {
+    assert!(is_identifier_part('a'));
+    assert!(is_identifier_part('$'));
+    assert!(is_identifier_part('_'));
+    assert!(is_identifier_part('7'));
+    assert!(is_identifier_part('ä'));
+    assert!(is_identifier_part('٣'));
+    assert!(is_identifier_part('\u{200C}'));
+    assert!(is_identifier_part('\u{200D}'));
+
+    assert!(!is_identifier_part('-'));
+    assert!(!is_identifier_part('.'));
+    assert!(!is_identifier_part(' '));
+}
+
+#[test]
+fn identifier_with_unicode_and_dollar() {
+    let mut lexer = Lexer::new(&b"a$b \xCF\x80x _\xE2\x80\x8D"[..]);
+
+    let expected = [
+        TokenKind::identifier("a$b"),
+        TokenKind::identifier("πx"),
+        TokenKind::identifier("_\u{200D}"),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+/// A reader that yields a single byte on each call to `read`.
+struct OneByteReader<'a>(&'a [u8]);
+
+impl Read for OneByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match (self.0.split_first(), buf.first_mut()) {
+            (Some((&byte, rest)), Some(slot)) => {
+                *slot = byte;
+                self.0 = rest;
+                Ok(1)
+            }
+            _ => Ok(0),
+        }
+    }
+}
+
+fn collect_tokens<R>(mut lexer: Lexer<R>) -> Vec<Token>
+where
+    R: Read,
+{
+    let mut tokens = Vec::new();
+    while let Some(token) = lexer.next().unwrap() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+#[test]
+fn one_byte_reader_produces_same_tokens() {
+    let s = "let café = 'π ∑ 😀' + 1.5e3;\n/* comment */ café >>>= `tpl`;";
+
+    let expected = collect_tokens(Lexer::new(s.as_bytes()));
+    let actual = collect_tokens(Lexer::new(OneByteReader(s.as_bytes())));
+
+    assert!(!expected.is_empty());
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn source_larger_than_read_buffer() {
+    // The multi-byte characters make some of them straddle the chunk boundaries.
+    let s = "'😀ab'; ".repeat(1500);
+
+    let tokens = collect_tokens(Lexer::new(s.as_bytes()));
+
+    assert_eq!(tokens.len(), 3000);
+    for token in tokens.chunks(2) {
+        assert_eq!(token[0].kind(), &TokenKind::string_literal("😀ab"));
+        assert_eq!(
+            token[1].kind(),
+            &TokenKind::Punctuator(Punctuator::Semicolon)
+        );
+    }
 }
@@ -93,6 +93,50 @@ impl From<BigInt> for Numeric {
     }
 }
 
+/// The raw and cooked strings of a template literal chunk.
+///
+/// The raw string is the source text of the chunk, where the escape sequences are kept as
+/// written. The cooked string is the string value of the chunk, which is `None` if the chunk
+/// contains an invalid escape sequence. This is only allowed in tagged templates.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-templatestrings
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Debug)]
+pub struct TemplateString {
+    raw: Box<str>,
+    cooked: Option<Box<str>>,
+}
+
+impl TemplateString {
+    /// Creates a new template string from its raw and cooked strings.
+    #[inline]
+    pub fn new<R, C>(raw: R, cooked: Option<C>) -> Self
+    where
+        R: Into<Box<str>>,
+        C: Into<Box<str>>,
+    {
+        Self {
+            raw: raw.into(),
+            cooked: cooked.map(Into::into),
+        }
+    }
+
+    /// Gets the raw string of the chunk.
+    #[inline]
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Gets the cooked string of the chunk, if it has no invalid escape sequences.
+    #[inline]
+    pub fn cooked(&self) -> Option<&str> {
+        self.cooked.as_deref()
+    }
+}
+
 /// Represents the type of Token and the data it has inside.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug)]
@@ -125,7 +169,11 @@ pub enum TokenKind {
     /// A string literal.
     StringLiteral(Box<str>),
 
-    TemplateLiteral(Box<str>),
+    /// A template literal without substitutions, or the last chunk of a template literal.
+    TemplateNoSubstitution(TemplateString),
+
+    /// A chunk of a template literal that is followed by a substitution.
+    TemplateMiddle(TemplateString),
 
     /// A regular expression, consisting of body and flags.
     RegularExpressionLiteral(Box<str>, RegExpFlags),
@@ -206,12 +254,14 @@ impl TokenKind {
         Self::StringLiteral(lit.into())
     }
 
-    /// Creates a `TemplateLiteral` token type.
-    pub fn template_literal<S>(lit: S) -> Self
-    where
-        S: Into<Box<str>>,
-    {
-        Self::TemplateLiteral(lit.into())
+    /// Creates a `TemplateNoSubstitution` token type.
+    pub fn template_no_substitution(template_string: TemplateString) -> Self {
+        Self::TemplateNoSubstitution(template_string)
+    }
+
+    /// Creates a `TemplateMiddle` token type.
+    pub fn template_middle(template_string: TemplateString) -> Self {
+        Self::TemplateMiddle(template_string)
     }
 
     /// Creates a `RegularExpressionLiteral` token kind.
@@ -247,7 +297,10 @@ impl Display for TokenKind {
             Self::NumericLiteral(Numeric::BigInt(ref num)) => write!(f, "{}n", num),
             Self::Punctuator(ref punc) => write!(f, "{}", punc),
             Self::StringLiteral(ref lit) => write!(f, "{}", lit),
-            Self::TemplateLiteral(ref lit) => write!(f, "{}", lit),
+            Self::TemplateNoSubstitution(ref template_string) => {
+                write!(f, "{}", template_string.raw())
+            }
+            Self::TemplateMiddle(ref template_string) => write!(f, "{}", template_string.raw()),
             Self::RegularExpressionLiteral(ref body, ref flags) => write!(f, "/{}/{}", body, flags),
             Self::LineTerminator => write!(f, "line terminator"),
             Self::Comment => write!(f, "comment"),
@@ -1,6 +1,7 @@
 //! Syntactical analysis, such as Abstract Syntax Tree (AST), Parsing and Lexing
 
 pub mod ast;
+pub mod diagnostic;
 pub mod lexer;
 pub mod parser;
 
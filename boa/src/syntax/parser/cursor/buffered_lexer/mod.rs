@@ -1,6 +1,7 @@
 use crate::{
     profiler::BoaProfiler,
     syntax::{
+        diagnostic::Diagnostic,
         lexer::{InputElement, Lexer, Position, Token, TokenKind},
         parser::error::ParseError,
     },
@@ -11,7 +12,7 @@ use std::io::Read;
 mod tests;
 
 /// The maximum number of tokens which can be peeked ahead.
-const MAX_PEEK_SKIP: usize = 2;
+const MAX_PEEK_SKIP: usize = 3;
 
 /// The fixed size of the buffer used for storing values that are peeked ahead.
 ///
@@ -39,14 +40,7 @@ where
     fn from(lexer: Lexer<R>) -> Self {
         Self {
             lexer,
-            peeked: [
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-                None::<Token>,
-            ],
+            peeked: Default::default(),
             read_index: 0,
             write_index: 0,
         }
@@ -82,6 +76,18 @@ where
         self.lexer.lex_slash_token(start).map_err(|e| e.into())
     }
 
+    /// Lexes the rest of a template literal after the closing `}` of a substitution has been
+    /// consumed.
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        let _timer = BoaProfiler::global().start_event("cursor::lex_template()", "Parsing");
+        debug_assert_eq!(
+            self.read_index, self.write_index,
+            "tokens after the closing `}}` of a substitution have already been lexed"
+        );
+        self.lexer.lex_template(start).map_err(|e| e.into())
+    }
+
     #[inline]
     pub(super) fn strict_mode(&self) -> bool {
         self.lexer.strict_mode()
@@ -92,6 +98,21 @@ where
         self.lexer.set_strict_mode(strict_mode)
     }
 
+    #[inline]
+    pub(super) fn set_early_errors(&mut self, early_errors: bool) {
+        self.lexer.set_early_errors(early_errors)
+    }
+
+    #[inline]
+    pub(super) fn set_report_deprecations(&mut self, report_deprecations: bool) {
+        self.lexer.set_report_deprecations(report_deprecations)
+    }
+
+    #[inline]
+    pub(super) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.lexer.take_diagnostics()
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -1,14 +1,18 @@
 //! Cursor implementation for the parser.
 mod buffered_lexer;
 
-use super::ParseError;
+use super::{ParseError, ParserOptions};
 use crate::syntax::{
-    ast::Punctuator,
-    lexer::{InputElement, Lexer, Position, Token, TokenKind},
+    ast::{Punctuator, Span},
+    diagnostic::{Diagnostic, DiagnosticKind},
+    lexer::{Error as LexError, InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
 use std::io::Read;
 
+#[cfg(test)]
+mod tests;
+
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
 pub(super) enum SemicolonResult<'s> {
@@ -16,12 +20,35 @@ pub(super) enum SemicolonResult<'s> {
     NotFound(&'s Token),
 }
 
+/// A label of a statement enclosing the current position.
+#[derive(Debug)]
+pub(super) struct Label {
+    name: Box<str>,
+    /// Whether the label denotes an iteration statement, which `continue` can target.
+    iteration: bool,
+    /// Whether the labelled statement is itself a labelled statement, as the `a` of `a: b: ;`.
+    chained: bool,
+}
+
 /// Token cursor.
 ///
 /// This internal structure gives basic testable operations to the parser.
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    options: ParserOptions,
+    /// Whether a `break` statement without a label is allowed, inside loops and `switch` statements.
+    allow_break: bool,
+    /// Whether a `continue` statement is allowed, inside loops.
+    allow_continue: bool,
+    /// Labels of the statements enclosing the current position, in the current function.
+    labels: Vec<Label>,
+    /// Position of the opening parenthesis of an arrow function parameter list still waiting for its body.
+    arrow_parameters_start: Option<Position>,
+    /// Diagnostics reported by the parser, without the ones of the lexer.
+    diagnostics: Vec<Diagnostic>,
+    /// Number of object and array literals enclosing the current position.
+    literal_depth: usize,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +60,13 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            options: ParserOptions::default(),
+            allow_break: false,
+            allow_continue: false,
+            labels: Vec::new(),
+            arrow_parameters_start: None,
+            diagnostics: Vec::new(),
+            literal_depth: 0,
         }
     }
 
@@ -46,11 +80,20 @@ where
         self.buffered_lexer.lex_regex(start)
     }
 
+    #[inline]
+    pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
+        self.buffered_lexer.lex_template(start)
+    }
+
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
         self.buffered_lexer.next(true)
     }
 
+    /// Peeks the `skip_n`th token after the next one, skipping line terminators.
+    ///
+    /// Up to 3 tokens can be skipped. The peeked tokens stay buffered, so they are neither
+    /// consumed nor lexed again by a later `next()`.
     #[inline]
     pub(super) fn peek(&mut self, skip_n: usize) -> Result<Option<&Token>, ParseError> {
         self.buffered_lexer.peek(skip_n, true)
@@ -66,6 +109,158 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Returns the options of the parser.
+    #[inline]
+    pub(super) fn options(&self) -> ParserOptions {
+        self.options
+    }
+
+    #[inline]
+    pub(super) fn set_options(&mut self, options: ParserOptions) {
+        self.buffered_lexer
+            .set_early_errors(options.has_early_errors());
+        self.buffered_lexer
+            .set_report_deprecations(options.has_deprecation_diagnostics());
+        self.options = options
+    }
+
+    /// Reports the use of a deprecated legacy feature, if deprecation diagnostics are enabled.
+    #[inline]
+    pub(super) fn report_deprecation(&mut self, kind: DiagnosticKind, span: Span) {
+        if self.options.has_deprecation_diagnostics() {
+            self.diagnostics.push(Diagnostic::new(kind, span));
+        }
+    }
+
+    /// Takes the diagnostics reported so far by the lexer and the parser, in source order.
+    pub(super) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        let mut diagnostics = self.buffered_lexer.take_diagnostics();
+        diagnostics.append(&mut self.diagnostics);
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span().start());
+        diagnostics
+    }
+
+    /// Parses an object or array literal starting at `start`, one level deeper in the literal
+    /// nesting.
+    ///
+    /// Fails without parsing anything if this goes over the maximum literal depth of the options.
+    pub(super) fn parse_nested_literal<T, F>(
+        &mut self,
+        start: Position,
+        parse: F,
+    ) -> Result<T, ParseError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, ParseError>,
+    {
+        if let Some(max_depth) = self.options.get_max_literal_depth() {
+            if self.literal_depth >= max_depth {
+                return Err(ParseError::general(
+                    "object or array literal nested too deeply",
+                    start,
+                ));
+            }
+        }
+
+        self.literal_depth += 1;
+        let result = parse(self);
+        self.literal_depth -= 1;
+        result
+    }
+
+    /// Returns whether a `break` statement without a label is allowed at the current position.
+    #[inline]
+    pub(super) fn allow_break(&self) -> bool {
+        self.allow_break
+    }
+
+    #[inline]
+    pub(super) fn set_allow_break(&mut self, allow_break: bool) {
+        self.allow_break = allow_break
+    }
+
+    /// Returns whether a `continue` statement is allowed at the current position.
+    #[inline]
+    pub(super) fn allow_continue(&self) -> bool {
+        self.allow_continue
+    }
+
+    #[inline]
+    pub(super) fn set_allow_continue(&mut self, allow_continue: bool) {
+        self.allow_continue = allow_continue
+    }
+
+    /// Enters a statement with the given label.
+    ///
+    /// If the statement is an iteration statement, so are the statements with the labels chained
+    /// right before this one. A label can not be used twice in nested statements.
+    pub(super) fn push_label(
+        &mut self,
+        name: Box<str>,
+        iteration: bool,
+        chained: bool,
+        position: Position,
+    ) -> Result<(), ParseError> {
+        if self.options.has_early_errors() && self.find_label(&name).is_some() {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("Label '{}' has already been declared", name).into(),
+                position,
+            )));
+        }
+
+        if iteration {
+            for label in self.labels.iter_mut().rev() {
+                if !label.chained {
+                    break;
+                }
+                label.iteration = true;
+            }
+        }
+        self.labels.push(Label {
+            name,
+            iteration,
+            chained,
+        });
+        Ok(())
+    }
+
+    /// Leaves the statement with the innermost label.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.labels.pop();
+    }
+
+    /// Returns whether the label of an enclosing statement denotes an iteration statement, or
+    /// `None` if no enclosing statement in the current function has this label.
+    pub(super) fn find_label(&self, name: &str) -> Option<bool> {
+        self.labels
+            .iter()
+            .rev()
+            .find(|label| label.name.as_ref() == name)
+            .map(|label| label.iteration)
+    }
+
+    /// Takes the labels of the enclosing statements, which can not be used inside of a function.
+    #[inline]
+    pub(super) fn take_labels(&mut self) -> Vec<Label> {
+        std::mem::take(&mut self.labels)
+    }
+
+    #[inline]
+    pub(super) fn set_labels(&mut self, labels: Vec<Label>) {
+        self.labels = labels
+    }
+
+    /// Takes the position of the arrow function parameter list that was just parsed, if any.
+    #[inline]
+    pub(super) fn take_arrow_parameters_start(&mut self) -> Option<Position> {
+        self.arrow_parameters_start.take()
+    }
+
+    #[inline]
+    pub(super) fn set_arrow_parameters_start(&mut self, start: Position) {
+        self.arrow_parameters_start = Some(start)
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
@@ -89,10 +284,13 @@ where
     /// [spec]: https://tc39.es/ecma262/#sec-automatic-semicolon-insertion
     #[inline]
     pub(super) fn peek_semicolon(&mut self) -> Result<SemicolonResult<'_>, ParseError> {
+        if self.line_terminator_before_next()? {
+            return Ok(SemicolonResult::Found(self.buffered_lexer.peek(0, false)?));
+        }
+
         match self.buffered_lexer.peek(0, false)? {
             Some(tk) => match tk.kind() {
                 TokenKind::Punctuator(Punctuator::Semicolon)
-                | TokenKind::LineTerminator
                 | TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     Ok(SemicolonResult::Found(Some(tk)))
                 }
@@ -109,14 +307,15 @@ where
     /// [spec]: https://tc39.es/ecma262/#sec-automatic-semicolon-insertion
     #[inline]
     pub(super) fn expect_semicolon(&mut self, context: &'static str) -> Result<(), ParseError> {
+        let line_terminator = self.line_terminator_before_next()?;
         match self.peek_semicolon()? {
-            SemicolonResult::Found(Some(tk)) => match *tk.kind() {
-                TokenKind::Punctuator(Punctuator::Semicolon) | TokenKind::LineTerminator => {
+            SemicolonResult::Found(Some(tk)) => {
+                // Consume the semicolon, or the line terminator that ends the statement.
+                if line_terminator || tk.kind() == &TokenKind::Punctuator(Punctuator::Semicolon) {
                     let _ = self.buffered_lexer.next(false)?;
-                    Ok(())
                 }
-                _ => Ok(()),
-            },
+                Ok(())
+            }
             SemicolonResult::Found(None) => Ok(()),
             SemicolonResult::NotFound(tk) => Err(ParseError::expected(
                 vec![TokenKind::Punctuator(Punctuator::Semicolon)],
@@ -126,26 +325,39 @@ where
         }
     }
 
-    /// It will make sure that the peeked token (skipping n tokens) is not a line terminator.
+    /// Returns whether there is a line terminator between the current token and the next one.
+    ///
+    /// This is the "no LineTerminator here" check of the restricted productions. Multi-line
+    /// comments that contain a line terminator count as a line terminator.
     ///
-    /// It expects that the token stream does not end here.
+    /// More information:
+    ///  - [ECMAScript specification][spec]
     ///
-    /// This is just syntatic sugar for a .peek(skip_n) call followed by a check that the result is not a line terminator or None.
+    /// [spec]: https://tc39.es/ecma262/#sec-rules-of-automatic-semicolon-insertion
+    #[inline]
+    pub(super) fn line_terminator_before_next(&mut self) -> Result<bool, ParseError> {
+        Ok(matches!(
+            self.buffered_lexer.peek(0, false)?,
+            Some(tk) if tk.kind() == &TokenKind::LineTerminator
+        ))
+    }
+
+    /// Returns an error if there is a line terminator before the next token, for the
+    /// restricted productions that do not allow one.
     #[inline]
-    pub(super) fn peek_expect_no_lineterminator(
+    pub(super) fn expect_no_line_terminator(
         &mut self,
-        skip_n: usize,
         context: &'static str,
-    ) -> Result<&Token, ParseError> {
-        if let Some(t) = self.buffered_lexer.peek(skip_n, false)? {
-            if t.kind() == &TokenKind::LineTerminator {
-                Err(ParseError::unexpected(t.clone(), context))
-            } else {
-                Ok(t)
-            }
-        } else {
-            Err(ParseError::AbruptEnd)
+    ) -> Result<(), ParseError> {
+        if self.line_terminator_before_next()? {
+            let line_terminator = self
+                .buffered_lexer
+                .peek(0, false)?
+                .expect("line terminator token disappeared");
+            return Err(ParseError::unexpected(line_terminator.clone(), context));
         }
+
+        Ok(())
     }
 
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
@@ -0,0 +1,108 @@
+use super::Cursor;
+use crate::syntax::{ast::Punctuator, lexer::TokenKind};
+
+/// Advances a cursor over `src` past its first token, and checks whether a line terminator
+/// precedes the second one.
+fn line_terminator_after_first_token(src: &str) -> bool {
+    let mut cursor = Cursor::new(src.as_bytes());
+    cursor
+        .next()
+        .unwrap()
+        .expect("the source should have a first token");
+
+    cursor.line_terminator_before_next().unwrap()
+}
+
+#[test]
+fn line_terminator_before_next_whitespace() {
+    assert!(!line_terminator_after_first_token("a b"));
+    assert!(!line_terminator_after_first_token("a \t b"));
+    assert!(line_terminator_after_first_token("a\nb"));
+    assert!(line_terminator_after_first_token("a \r\n b"));
+    assert!(line_terminator_after_first_token("a\u{2028}b"));
+    assert!(line_terminator_after_first_token("a\n\n\nb"));
+}
+
+#[test]
+fn line_terminator_before_next_comments() {
+    assert!(!line_terminator_after_first_token("a /* comment */ b"));
+    assert!(line_terminator_after_first_token("a /* multi\nline */ b"));
+    assert!(line_terminator_after_first_token("a // comment\nb"));
+    assert!(line_terminator_after_first_token("a /* comment */\nb"));
+}
+
+#[test]
+fn line_terminator_before_next_end_of_input() {
+    assert!(!line_terminator_after_first_token("a"));
+    assert!(!line_terminator_after_first_token("a /* comment */"));
+}
+
+#[test]
+fn line_terminator_before_next_does_not_consume() {
+    let mut cursor = Cursor::new(&b"a\nb"[..]);
+    cursor.next().unwrap().expect("a token expected");
+
+    assert!(cursor.line_terminator_before_next().unwrap());
+    assert!(cursor.line_terminator_before_next().unwrap());
+    assert_eq!(
+        cursor.next().unwrap().expect("b token expected").kind(),
+        &TokenKind::identifier("b")
+    );
+    assert!(!cursor.line_terminator_before_next().unwrap());
+}
+
+/// Returns the kind of the token peeked at offset `skip_n`.
+fn peek_kind(cursor: &mut Cursor<&[u8]>, skip_n: usize) -> TokenKind {
+    cursor
+        .peek(skip_n)
+        .unwrap()
+        .expect("a token expected")
+        .kind()
+        .clone()
+}
+
+#[test]
+fn peek_multiple_tokens() {
+    let mut cursor = Cursor::new(&b"get\nx(\n) {}"[..]);
+
+    assert_eq!(peek_kind(&mut cursor, 0), TokenKind::identifier("get"));
+    assert_eq!(peek_kind(&mut cursor, 1), TokenKind::identifier("x"));
+    assert_eq!(
+        peek_kind(&mut cursor, 2),
+        TokenKind::Punctuator(Punctuator::OpenParen)
+    );
+    assert_eq!(
+        peek_kind(&mut cursor, 3),
+        TokenKind::Punctuator(Punctuator::CloseParen)
+    );
+    // Peeking again in any order returns the same tokens.
+    assert_eq!(peek_kind(&mut cursor, 1), TokenKind::identifier("x"));
+    assert_eq!(peek_kind(&mut cursor, 0), TokenKind::identifier("get"));
+
+    assert_eq!(
+        cursor.next().unwrap().expect("get token expected").kind(),
+        &TokenKind::identifier("get")
+    );
+    assert_eq!(peek_kind(&mut cursor, 0), TokenKind::identifier("x"));
+    assert_eq!(
+        peek_kind(&mut cursor, 2),
+        TokenKind::Punctuator(Punctuator::CloseParen)
+    );
+    assert_eq!(
+        cursor.next().unwrap().expect("x token expected").kind(),
+        &TokenKind::identifier("x")
+    );
+}
+
+#[test]
+fn peek_past_end_of_input() {
+    let mut cursor = Cursor::new(&b"a b"[..]);
+
+    assert_eq!(peek_kind(&mut cursor, 1), TokenKind::identifier("b"));
+    assert!(cursor.peek(2).unwrap().is_none());
+    assert_eq!(
+        cursor.next().unwrap().expect("a token expected").kind(),
+        &TokenKind::identifier("a")
+    );
+    assert!(cursor.peek(1).unwrap().is_none());
+}
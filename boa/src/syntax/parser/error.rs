@@ -1,8 +1,20 @@
 //! Error and result implementation for the parser.
 
-use crate::syntax::ast::{position::Position, Node};
-use crate::syntax::lexer::{Error as LexError, Token, TokenKind};
-use std::fmt;
+use crate::syntax::ast::{
+    position::{Position, Span},
+    Node,
+};
+use crate::syntax::lexer::{
+    error::{
+        IMPLICIT_OCTAL_IN_STRICT_MODE, NON_OCTAL_DECIMAL_ESCAPE_IN_STRICT_MODE,
+        OCTAL_ESCAPE_IN_STRICT_MODE, UNEXPECTED_CHARACTER, UNTERMINATED_TEMPLATE_LITERAL,
+    },
+    Error as LexError, Token, TokenKind,
+};
+use std::{
+    fmt::{self, Write},
+    io,
+};
 
 /// Result of a parsing operation.
 pub type ParseResult = Result<Node, ParseError>;
@@ -23,6 +35,19 @@ impl From<LexError> for ParseError {
     }
 }
 
+/// The style of the messages of parsing errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Boa's own messages, which name what was being parsed and where the error is.
+    Boa,
+    /// Messages phrased like the ones of the V8 engine, such as `Unexpected token ')'`, for
+    /// comparing the output with Node.js or Chromium.
+    ///
+    /// Only the common errors are translated. Any other error keeps Boa's message, without the
+    /// position.
+    V8,
+}
+
 /// `ParseError` is an enum which represents errors encounted during parsing an expression
 #[derive(Debug)]
 pub enum ParseError {
@@ -101,6 +126,138 @@ impl ParseError {
     pub(super) fn unimplemented(message: &'static str, position: Position) -> Self {
         Self::Unimplemented { message, position }
     }
+
+    /// Gets the span of the source code that caused the error, if known.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Expected { found, .. } | Self::Unexpected { found, .. } => Some(found.span()),
+            Self::General { position, .. }
+            | Self::Unimplemented { position, .. }
+            | Self::Lex {
+                err: LexError::Syntax(_, position),
+            } => Some(Span::new(*position, *position)),
+            Self::AbruptEnd | Self::Lex { .. } => None,
+        }
+    }
+
+    /// Gets the message of the error in the given format.
+    ///
+    /// The `Display` implementation uses [`MessageFormat::Boa`].
+    pub fn message(&self, format: MessageFormat) -> String {
+        match format {
+            MessageFormat::Boa => self.to_string(),
+            MessageFormat::V8 => self.v8_message(),
+        }
+    }
+
+    /// Gets the message of the error as the V8 engine phrases it.
+    fn v8_message(&self) -> String {
+        match self {
+            Self::Expected { found, .. } | Self::Unexpected { found, .. } => match found.kind() {
+                TokenKind::EOF => "Unexpected end of input".to_owned(),
+                TokenKind::Identifier(ident) => format!("Unexpected identifier '{}'", ident),
+                TokenKind::NumericLiteral(_) => "Unexpected number".to_owned(),
+                TokenKind::StringLiteral(_) => "Unexpected string".to_owned(),
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    "Unexpected template string".to_owned()
+                }
+                kind => format!("Unexpected token '{}'", kind),
+            },
+            Self::AbruptEnd => "Unexpected end of input".to_owned(),
+            Self::Lex {
+                err: LexError::IO(err),
+            } if err.kind() == io::ErrorKind::UnexpectedEof => {
+                if err.to_string() == UNTERMINATED_TEMPLATE_LITERAL {
+                    UNTERMINATED_TEMPLATE_LITERAL.to_owned()
+                } else {
+                    "Invalid or unexpected token".to_owned()
+                }
+            }
+            Self::Lex {
+                err: LexError::Syntax(message, _),
+            } => match message.as_ref() {
+                IMPLICIT_OCTAL_IN_STRICT_MODE => {
+                    "Octal literals are not allowed in strict mode.".to_owned()
+                }
+                OCTAL_ESCAPE_IN_STRICT_MODE => {
+                    "Octal escape sequences are not allowed in strict mode.".to_owned()
+                }
+                NON_OCTAL_DECIMAL_ESCAPE_IN_STRICT_MODE => {
+                    "\\8 and \\9 are not allowed in strict mode.".to_owned()
+                }
+                message if message.starts_with(UNEXPECTED_CHARACTER) => {
+                    "Invalid or unexpected token".to_owned()
+                }
+                message => message.to_owned(),
+            },
+            Self::General { message, .. } => (*message).to_owned(),
+            Self::Unimplemented { message, .. } => format!("{} not yet implemented", message),
+            Self::Lex { err } => err.to_string(),
+        }
+    }
+
+    /// Renders the error together with a code frame of the given source, pointing at the
+    /// offending code with carets.
+    ///
+    /// The source must be the same code that was given to the parser.
+    ///
+    /// ```text
+    /// unexpected token ';', primary expression at line 2, col 9
+    ///  --> 2:9
+    ///   |
+    /// 2 | let a = ;
+    ///   |         ^
+    /// ```
+    pub fn render(&self, source: &str) -> String {
+        let mut rendered = self.to_string();
+
+        let span = match self.span() {
+            Some(span) => span,
+            None => return rendered,
+        };
+        let start = span.start();
+        let line = match source.lines().nth(start.line_number() as usize - 1) {
+            Some(line) => line,
+            None => return rendered,
+        };
+
+        // Underline the whole token if it is on a single line. Empty spans and spans covering
+        // several lines get a single caret at their start.
+        let width = if !span.is_empty() && span.end().line_number() == start.line_number() {
+            span.end()
+                .column_number()
+                .saturating_sub(start.column_number())
+                .max(1)
+        } else {
+            1
+        };
+
+        // Keep tabs in the padding, so the carets line up with the source line. An empty span can
+        // point past the last character of the line, e.g. where a token is missing.
+        let mut padding: String = line
+            .chars()
+            .take(start.column_number() as usize - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let padding_len = padding.chars().count();
+        padding.extend((padding_len..start.column_number() as usize - 1).map(|_| ' '));
+
+        let line_number = start.line_number().to_string();
+        let gutter = " ".repeat(line_number.len());
+        let _ = write!(
+            rendered,
+            "\n{gutter}--> {}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            start.line_number(),
+            start.column_number(),
+            line_number,
+            line,
+            padding,
+            "^".repeat(width as usize),
+            gutter = gutter
+        );
+
+        rendered
+    }
 }
 
 impl fmt::Display for ParseError {
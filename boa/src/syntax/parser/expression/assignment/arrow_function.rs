@@ -17,7 +17,7 @@ use crate::{
         },
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::FunctionBody,
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -71,23 +71,12 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ArrowFunction", "Parsing");
 
-        let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
-        let params = if let TokenKind::Punctuator(Punctuator::OpenParen) = &next_token.kind() {
-            // CoverParenthesizedExpressionAndArrowParameterList
-            cursor.expect(Punctuator::OpenParen, "arrow function")?;
-
-            let params = FormalParameters::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            cursor.expect(Punctuator::CloseParen, "arrow function")?;
-            params
-        } else {
-            let param = BindingIdentifier::new(self.allow_yield, self.allow_await)
-                .parse(cursor)
-                .context("arrow function")?;
-            Box::new([FormalParameter::new(param, None, false)])
-        };
-
-        cursor.peek_expect_no_lineterminator(0, "arrow function")?;
+        let param = BindingIdentifier::new(self.allow_yield, self.allow_await)
+            .parse(cursor)
+            .context("arrow function")?;
+        let params: Box<[FormalParameter]> = Box::new([FormalParameter::new(param, None, false)]);
 
+        cursor.expect_no_line_terminator("arrow function")?;
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
         let body = ConciseBody::new(self.allow_in).parse(cursor)?;
         Ok(ArrowFunctionDecl::new(params, body))
@@ -96,13 +85,13 @@ where
 
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
 #[derive(Debug, Clone, Copy)]
-struct ConciseBody {
+pub(super) struct ConciseBody {
     allow_in: AllowIn,
 }
 
 impl ConciseBody {
     /// Creates a new `ConcideBody` parser.
-    fn new<I>(allow_in: I) -> Self
+    pub(super) fn new<I>(allow_in: I) -> Self
     where
         I: Into<AllowIn>,
     {
@@ -126,11 +115,15 @@ where
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
-            )
-            .into()])),
+            _ => {
+                let mut body = StatementList::from(vec![Return::new(
+                    ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                    None,
+                )
+                .into()]);
+                body.set_strict(cursor.strict_mode());
+                Ok(body)
+            }
         }
     }
 }
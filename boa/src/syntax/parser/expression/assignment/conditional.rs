@@ -72,8 +72,10 @@ where
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Question) {
                 cursor.next()?.expect("? character vanished"); // Consume the token.
+
+                // `in` is always allowed in the consequent, as the `:` ends it unambiguously.
                 let then_clause =
-                    AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
                 cursor.expect(Punctuator::Colon, "conditional expression")?;
 
@@ -11,13 +11,16 @@ mod arrow_function;
 mod conditional;
 mod exponentiation;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
-use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
+use self::{
+    arrow_function::{ArrowFunction, ConciseBody},
+    conditional::ConditionalExpression,
+};
+use crate::syntax::lexer::{Error as LexError, InputElement, Token, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            node::{ArrowFunctionDecl, Assign, BinOp, Node},
+            Keyword, Position, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
@@ -88,82 +91,13 @@ where
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
             | TokenKind::Keyword(Keyword::Await) => {
-                if let Ok(tok) = cursor.peek_expect_no_lineterminator(1, "assignment expression") {
-                    if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
-                        return ArrowFunction::new(
-                            self.allow_in,
-                            self.allow_yield,
-                            self.allow_await,
-                        )
+                // The arrow function parser checks that there is no line terminator before `=>`.
+                if let Some(TokenKind::Punctuator(Punctuator::Arrow)) =
+                    cursor.peek(1)?.map(Token::kind)
+                {
+                    return ArrowFunction::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor)
                         .map(Node::ArrowFunctionDecl);
-                    }
-                }
-            }
-
-            // (a,b)=>{} or (a,b) or (Expression)
-            TokenKind::Punctuator(Punctuator::OpenParen) => {
-                if let Some(next_token) = cursor.peek(1)? {
-                    match *next_token.kind() {
-                        TokenKind::Punctuator(Punctuator::CloseParen) => {
-                            // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
-                            // otherwise it is an expression of the form (b).
-                            if let Some(t) = cursor.peek(2)? {
-                                if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
-                                    return ArrowFunction::new(
-                                        self.allow_in,
-                                        self.allow_yield,
-                                        self.allow_await,
-                                    )
-                                    .parse(cursor)
-                                    .map(Node::ArrowFunctionDecl);
-                                }
-                            }
-                        }
-                        TokenKind::Punctuator(Punctuator::Spread) => {
-                            return ArrowFunction::new(
-                                self.allow_in,
-                                self.allow_yield,
-                                self.allow_await,
-                            )
-                            .parse(cursor)
-                            .map(Node::ArrowFunctionDecl);
-                        }
-                        TokenKind::Identifier(_) => {
-                            if let Some(t) = cursor.peek(2)? {
-                                match *t.kind() {
-                                    TokenKind::Punctuator(Punctuator::Comma) => {
-                                        // This must be an argument list and therefore (a, b) => {}
-                                        return ArrowFunction::new(
-                                            self.allow_in,
-                                            self.allow_yield,
-                                            self.allow_await,
-                                        )
-                                        .parse(cursor)
-                                        .map(Node::ArrowFunctionDecl);
-                                    }
-                                    TokenKind::Punctuator(Punctuator::CloseParen) => {
-                                        // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
-                                        // otherwise it is an expression of the form (b).
-                                        if let Some(t) = cursor.peek(2)? {
-                                            if t.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
-                                            {
-                                                return ArrowFunction::new(
-                                                    self.allow_in,
-                                                    self.allow_yield,
-                                                    self.allow_await,
-                                                )
-                                                .parse(cursor)
-                                                .map(Node::ArrowFunctionDecl);
-                                            }
-                                        }
-                                    }
-                                    _ => {}
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
                 }
             }
 
@@ -172,16 +106,35 @@ where
 
         cursor.set_goal(InputElement::Div);
 
+        let start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
+        // (a,b)=>{}
+        // A parenthesized list followed by `=>` is parsed as arrow function parameters. It is
+        // only valid if it is the whole left hand side, so `a + (b) => c` is a syntax error.
+        if let Some(arrow_start) = cursor.take_arrow_parameters_start() {
+            let arrow = cursor.expect(Punctuator::Arrow, "arrow function")?;
+            return match lhs {
+                Node::ArrowFunctionDecl(head) if arrow_start == start => {
+                    let body = ConciseBody::new(self.allow_in).parse(cursor)?;
+                    Ok(ArrowFunctionDecl::new(head.params().to_vec(), body).into())
+                }
+                _ => Err(ParseError::lex(LexError::Syntax(
+                    "Malformed arrow function parameter list".into(),
+                    arrow.span().start(),
+                ))),
+            };
+        }
+
         // Review if we are trying to assign to an invalid left hand side expression.
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0)?.cloned() {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if is_assignable(&lhs) || !cursor.options().has_early_errors() {
+                        check_strict_assignment_target(&lhs, cursor, tok.span().start())?;
                         lhs = Assign::new(lhs, self.parse(cursor)?).into();
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
@@ -192,7 +145,8 @@ where
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
                     cursor.next()?.expect("token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
+                    if is_assignable(&lhs) || !cursor.options().has_early_errors() {
+                        check_strict_assignment_target(&lhs, cursor, tok.span().start())?;
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor)?;
 
@@ -214,8 +168,44 @@ where
 
 /// Returns true if as per spec[spec] the node can be assigned a value.
 ///
+/// Only identifiers and property accesses are simple assignment targets. Parenthesized targets
+/// such as `(a.b)` are already unwrapped by the parser, while sequences such as `(a, b)` are not
+/// valid targets.
+///
 /// [spec]: https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
 #[inline]
 pub(crate) fn is_assignable(node: &Node) -> bool {
-    !matches!(node, Node::Const(_) | Node::ArrayDecl(_))
+    matches!(
+        node,
+        Node::Identifier(_) | Node::GetConstField(_) | Node::GetField(_)
+    )
+}
+
+/// Checks that an assignment target is not `eval` or `arguments` in strict mode code.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-assignmenttargettype
+pub(crate) fn check_strict_assignment_target<R>(
+    node: &Node,
+    cursor: &Cursor<R>,
+    position: Position,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    match node {
+        Node::Identifier(ident)
+            if cursor.options().has_early_errors()
+                && cursor.strict_mode()
+                && (ident.as_ref() == "eval" || ident.as_ref() == "arguments") =>
+        {
+            Err(ParseError::lex(LexError::Syntax(
+                format!("assignment to '{}' not allowed in strict mode", ident).into(),
+                position,
+            )))
+        }
+        _ => Ok(()),
+    }
 }
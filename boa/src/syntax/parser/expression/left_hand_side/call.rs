@@ -7,7 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions
 //! [spec]: https://tc39.es/ecma262/#prod-CallExpression
 
-use super::arguments::Arguments;
+use super::{arguments::Arguments, template::TaggedTemplateLiteral};
 use crate::{
     syntax::{
         ast::{
@@ -112,6 +112,10 @@ where
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
         }
@@ -5,7 +5,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#prod-MemberExpression
 
-use super::arguments::Arguments;
+use super::{arguments::Arguments, template::TaggedTemplateLiteral};
 use crate::{
     syntax::{
         ast::{
@@ -15,6 +15,7 @@ use crate::{
             },
             Keyword, Punctuator,
         },
+        diagnostic::DiagnosticKind,
         lexer::TokenKind,
         parser::{
             expression::{primary::PrimaryExpression, Expression},
@@ -66,7 +67,15 @@ where
         {
             let _ = cursor.next().expect("new keyword disappeared");
             let lhs = self.parse(cursor)?;
-            let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            // The member accesses after `new` are all part of the constructor, so without an
+            // argument list this is `new MemberExpression`, which is the same as calling the
+            // constructor with no arguments.
+            let args = match cursor.peek(0)? {
+                Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?
+                }
+                _ => Box::new([]),
+            };
             let call_node = Call::new(lhs, args);
 
             Node::from(New::from(call_node))
@@ -82,6 +91,16 @@ where
 
                     match token.kind() {
                         TokenKind::Identifier(name) => {
+                            if name.as_ref() == "caller" {
+                                if let Node::Identifier(ref object) = lhs {
+                                    if object.as_ref() == "arguments" {
+                                        cursor.report_deprecation(
+                                            DiagnosticKind::ArgumentsCaller,
+                                            token.span(),
+                                        );
+                                    }
+                                }
+                            }
                             lhs = GetConstField::new(lhs, name.clone()).into()
                         }
                         TokenKind::Keyword(kw) => {
@@ -105,6 +124,10 @@ where
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
                     lhs = GetField::new(lhs, idx).into();
                 }
+                TokenKind::TemplateNoSubstitution(_) | TokenKind::TemplateMiddle(_) => {
+                    lhs = TaggedTemplateLiteral::new(self.allow_yield, self.allow_await, lhs)
+                        .parse(cursor)?;
+                }
                 _ => break,
             }
         }
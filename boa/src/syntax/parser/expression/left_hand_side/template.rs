@@ -0,0 +1,93 @@
+//! Tagged template parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+//! [spec]: https://tc39.es/ecma262/#sec-tagged-templates
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{
+            node::{Node, TaggedTemplate},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+};
+use std::io::Read;
+
+/// Parses a tagged template.
+///
+/// Unlike in template literals, chunks with invalid escape sequences are allowed, and get an
+/// `undefined` cooked string.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+/// [spec]: https://tc39.es/ecma262/#prod-MemberExpression
+#[derive(Debug, Clone)]
+pub(super) struct TaggedTemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    tag: Node,
+}
+
+impl TaggedTemplateLiteral {
+    /// Creates a new `TaggedTemplateLiteral` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, tag: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            tag,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TaggedTemplateLiteral
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
+
+        let mut raws = Vec::new();
+        let mut cookeds = Vec::new();
+        let mut exprs = Vec::new();
+
+        let mut tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        loop {
+            match tok.kind() {
+                TokenKind::TemplateMiddle(template_string) => {
+                    raws.push(template_string.raw().into());
+                    cookeds.push(template_string.cooked().map(Into::into));
+                    exprs.push(
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
+                    );
+                    let close = cursor.expect(Punctuator::CloseBlock, "tagged template")?;
+                    tok = cursor.lex_template(close.span().start())?;
+                }
+                TokenKind::TemplateNoSubstitution(template_string) => {
+                    raws.push(template_string.raw().into());
+                    cookeds.push(template_string.cooked().map(Into::into));
+                    return Ok(TaggedTemplate::new(self.tag, raws, cookeds, exprs).into());
+                }
+                _ => return Err(ParseError::unexpected(tok.clone(), "tagged template")),
+            }
+        }
+    }
+}
@@ -25,7 +25,8 @@ use crate::{
     profiler::BoaProfiler,
     syntax::ast::{
         node::{BinOp, Node},
-        Keyword, Punctuator,
+        op::{self, NumOp},
+        Const, Keyword, Punctuator,
     },
 };
 
@@ -47,6 +48,20 @@ impl PartialEq<Punctuator> for Keyword {
     }
 }
 
+/// Creates the node for the binary operation `lhs <op> rhs`.
+///
+/// If `fold_strings` is `true` and both operands of an addition are string literals, they are
+/// folded into a single string literal instead, so `"a" + "b"` produces the same node as `"ab"`.
+fn binary_operation(op: op::BinOp, lhs: Node, rhs: Node, fold_strings: bool) -> Node {
+    if fold_strings && op == op::BinOp::Num(NumOp::Add) {
+        if let (Node::Const(Const::String(l)), Node::Const(Const::String(r))) = (&lhs, &rhs) {
+            return Const::from(format!("{}{}", l, r)).into();
+        }
+    }
+
+    BinOp::new(op, lhs, rhs).into()
+}
+
 /// Generates an expression parser for a number of expressions whose production rules are of the following pattern.
 /// <TargetExpression>[allowed_identifiers]
 ///     => <InnerExpression>[?allowed_identifiers]
@@ -80,19 +95,23 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
                 match *tok.kind() {
                     TokenKind::Punctuator(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = binary_operation(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                            rhs,
+                            cursor.options().has_fold_string_concat(),
+                        );
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = binary_operation(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                            rhs,
+                            cursor.options().has_fold_string_concat(),
+                        );
                     }
                     _ => break
                 }
@@ -86,7 +86,11 @@ where
                         .parse(cursor)?,
                 );
             }
-            cursor.next_if(Punctuator::Comma)?;
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBracket, "array literal")?;
+                break;
+            }
         }
 
         Ok(elements.into())
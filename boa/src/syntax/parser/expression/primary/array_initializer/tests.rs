@@ -1,8 +1,11 @@
 // ! Tests for array initializer parsing.
 
 use crate::syntax::{
-    ast::{node::ArrayDecl, Const},
-    parser::tests::check_parser,
+    ast::{
+        node::{ArrayDecl, Identifier, Spread},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks an empty array.
@@ -106,3 +109,25 @@ fn check_combined_empty_str() {
         .into()],
     );
 }
+
+/// Checks an array mixing spread elements, regular elements and elisions.
+#[test]
+fn check_spread_elements() {
+    check_parser(
+        "[...a, 1, , ...b]",
+        vec![ArrayDecl::from(vec![
+            Spread::new(Identifier::from("a")).into(),
+            Const::from(1).into(),
+            Const::Undefined.into(),
+            Spread::new(Identifier::from("b")).into(),
+        ])
+        .into()],
+    );
+}
+
+/// Checks that elements must be separated by commas.
+#[test]
+fn check_missing_comma() {
+    check_invalid("[1 2]");
+    check_invalid("[...a b]");
+}
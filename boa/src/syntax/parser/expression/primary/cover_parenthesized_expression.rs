@@ -0,0 +1,206 @@
+//! Parenthesized expression and arrow function parameter list parsing.
+//!
+//! More information:
+//!  - [ECMAScript specification][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{ArrowFunctionDecl, BinOp, FormalParameter, Node, StatementList},
+            op, Punctuator,
+        },
+        lexer::{Error as LexError, InputElement, Position, TokenKind},
+        parser::{
+            expression::AssignmentExpression, statement::BindingIdentifier, AllowAwait, AllowYield,
+            Cursor, ParseError, ParseResult, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses a parenthesized expression or the parameter list of an arrow function.
+///
+/// Both start with a parenthesized list, so the list is parsed as a sequence of expressions
+/// first. If the closing parenthesis is followed by `=>` on the same line, the expressions are
+/// reinterpreted as formal parameters and an arrow function head without a body is returned.
+/// The body is then parsed by the enclosing `AssignmentExpression`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CoverParenthesizedExpressionAndArrowParameterList {
+    start: Position,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl CoverParenthesizedExpressionAndArrowParameterList {
+    /// Creates a new `CoverParenthesizedExpressionAndArrowParameterList` parser.
+    ///
+    /// The `start` position is the position of the already consumed opening parenthesis.
+    pub(super) fn new<Y, A>(start: Position, allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            start,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for CoverParenthesizedExpressionAndArrowParameterList
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event(
+            "CoverParenthesizedExpressionAndArrowParameterList",
+            "Parsing",
+        );
+
+        let mut expressions = Vec::new();
+        let mut rest = None;
+        // A parenthesized element is never a valid parameter, as in `((a)) => a`.
+        let mut parenthesized = false;
+
+        loop {
+            cursor.set_goal(InputElement::RegExp);
+            parenthesized |= matches!(
+                cursor.peek(0)?,
+                Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen)
+            );
+            match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+                // `()` is only valid as the parameter list of an arrow function.
+                TokenKind::Punctuator(Punctuator::CloseParen) if expressions.is_empty() => {
+                    cursor.next()?.expect(") token vanished");
+                    break;
+                }
+                TokenKind::Punctuator(Punctuator::Spread) => {
+                    cursor.next()?.expect("... token vanished");
+                    let param =
+                        BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    rest = Some(FormalParameter::new(param, None, true));
+                    cursor.expect(Punctuator::CloseParen, "rest parameter")?;
+                    break;
+                }
+                _ => {
+                    expressions.push(
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor)?,
+                    );
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseParen, "parenthesized expression")?;
+                        break;
+                    }
+                }
+            }
+        }
+
+        let is_arrow = !cursor.line_terminator_before_next()?
+            && matches!(
+                cursor.peek(0)?,
+                Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Arrow)
+            );
+
+        if is_arrow {
+            if parenthesized {
+                return Err(self.malformed());
+            }
+
+            let mut params: Vec<FormalParameter> = Vec::with_capacity(expressions.len() + 1);
+            for param in expressions
+                .into_iter()
+                .map(|expression| self.formal_parameter(expression, cursor))
+                .chain(rest.map(Ok))
+            {
+                let param = param?;
+                if cursor.options().has_early_errors()
+                    && params.iter().any(|p| p.name() == param.name())
+                {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!(
+                            "duplicate parameter '{}' not allowed in arrow function",
+                            param.name()
+                        )
+                        .into(),
+                        self.start,
+                    )));
+                }
+                params.push(param);
+            }
+
+            cursor.set_arrow_parameters_start(self.start);
+            return Ok(ArrowFunctionDecl::new(params, StatementList::from(Vec::new())).into());
+        }
+
+        if expressions.is_empty() || rest.is_some() {
+            let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            return Err(ParseError::expected(
+                vec![TokenKind::Punctuator(Punctuator::Arrow)],
+                next_token,
+                "arrow function",
+            ));
+        }
+
+        let mut expressions = expressions.into_iter();
+        let first = expressions.next().expect("empty parenthesized expression");
+        Ok(expressions.fold(first, |lhs, rhs| {
+            BinOp::new(op::BinOp::Comma, lhs, rhs).into()
+        }))
+    }
+}
+
+impl CoverParenthesizedExpressionAndArrowParameterList {
+    /// Reinterprets an expression of the parenthesized list as a formal parameter.
+    ///
+    /// Only identifiers, optionally with an initializer, are valid parameters.
+    fn formal_parameter<R>(
+        &self,
+        expression: Node,
+        cursor: &Cursor<R>,
+    ) -> Result<FormalParameter, ParseError>
+    where
+        R: Read,
+    {
+        let (name, init) = match expression {
+            Node::Identifier(ident) => (ident, None),
+            Node::Assign(assign) => match assign.lhs() {
+                Node::Identifier(ident) => (ident.clone(), Some(assign.rhs().clone())),
+                _ => return Err(self.malformed()),
+            },
+            _ => return Err(self.malformed()),
+        };
+
+        // `eval` and `arguments` can be referenced in strict mode, but not bound.
+        if cursor.options().has_early_errors()
+            && cursor.strict_mode()
+            && (name.as_ref() == "eval" || name.as_ref() == "arguments")
+        {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("binding '{}' not allowed in strict mode", name.as_ref()).into(),
+                self.start,
+            )));
+        }
+
+        Ok(FormalParameter::new(name.as_ref(), init, false))
+    }
+
+    /// Creates the error for a parenthesized list that is not a valid parameter list.
+    fn malformed(&self) -> ParseError {
+        ParseError::lex(LexError::Syntax(
+            "Malformed arrow function parameter list".into(),
+            self.start,
+        ))
+    }
+}
@@ -9,24 +9,29 @@
 
 mod array_initializer;
 mod async_function_expression;
+mod cover_parenthesized_expression;
 mod function_expression;
 mod object_initializer;
+mod template;
 #[cfg(test)]
 mod tests;
 
 use self::{
-    array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    array_initializer::ArrayLiteral,
+    async_function_expression::AsyncFunctionExpression,
+    cover_parenthesized_expression::CoverParenthesizedExpressionAndArrowParameterList,
+    function_expression::FunctionExpression,
+    object_initializer::ObjectLiteral,
+    template::{cooked_string, TemplateLiteral},
 };
-use super::Expression;
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
+            node::{Call, Identifier, New, Node, TemplateElement, TemplateLit},
             Const, Keyword, Punctuator,
         },
-        lexer::{token::Numeric, InputElement, TokenKind},
+        lexer::{token::Numeric, Error as LexError, InputElement, TokenKind},
         parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
 };
@@ -82,28 +87,44 @@ where
                 .parse(cursor)
                 .map(Node::from),
             TokenKind::Punctuator(Punctuator::OpenParen) => {
-                cursor.set_goal(InputElement::RegExp);
-                let expr =
-                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "primary expression")?;
-                Ok(expr)
+                CoverParenthesizedExpressionAndArrowParameterList::new(
+                    tok.span().start(),
+                    self.allow_yield,
+                    self.allow_await,
+                )
+                .parse(cursor)
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 cursor.set_goal(InputElement::RegExp);
-                ArrayLiteral::new(self.allow_yield, self.allow_await)
-                    .parse(cursor)
-                    .map(Node::ArrayDecl)
+                cursor.parse_nested_literal(tok.span().start(), |cursor| {
+                    ArrayLiteral::new(self.allow_yield, self.allow_await)
+                        .parse(cursor)
+                        .map(Node::ArrayDecl)
+                })
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 cursor.set_goal(InputElement::RegExp);
-                Ok(ObjectLiteral::new(self.allow_yield, self.allow_await)
-                    .parse(cursor)?
-                    .into())
+                cursor.parse_nested_literal(tok.span().start(), |cursor| {
+                    Ok(ObjectLiteral::new(self.allow_yield, self.allow_await)
+                        .parse(cursor)?
+                        .into())
+                })
             }
             TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
             TokenKind::NullLiteral => Ok(Const::Null.into()),
             TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
             TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
+            TokenKind::TemplateNoSubstitution(template_string) => {
+                let cooked = cooked_string(template_string, tok.span().start())?;
+                Ok(TemplateLit::new(vec![TemplateElement::String(cooked.into())]).into())
+            }
+            TokenKind::TemplateMiddle(template_string) => TemplateLiteral::new(
+                self.allow_yield,
+                self.allow_await,
+                tok.span().start(),
+                template_string.clone(),
+            )
+            .parse(cursor),
             TokenKind::NumericLiteral(Numeric::Integer(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::Rational(num)) => Ok(Const::from(*num).into()),
             TokenKind::NumericLiteral(Numeric::BigInt(num)) => Ok(Const::from(num.clone()).into()),
@@ -132,6 +153,27 @@ where
                     Err(ParseError::unexpected(tok, "regular expression literal"))
                 }
             }
+            // `yield` and `await` are only reserved in strict mode code, as in `BindingIdentifier`.
+            TokenKind::Keyword(Keyword::Yield) if !self.allow_yield.0 => {
+                if cursor.strict_mode() {
+                    Err(ParseError::lex(LexError::Syntax(
+                        "yield is only valid in generator functions".into(),
+                        tok.span().start(),
+                    )))
+                } else {
+                    Ok(Identifier::from(Keyword::Yield.as_str()).into())
+                }
+            }
+            TokenKind::Keyword(Keyword::Await) if !self.allow_await.0 => {
+                if cursor.strict_mode() {
+                    Err(ParseError::lex(LexError::Syntax(
+                        "await is only valid in async functions".into(),
+                        tok.span().start(),
+                    )))
+                } else {
+                    Ok(Identifier::from(Keyword::Await.as_str()).into())
+                }
+            }
             _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
         }
     }
@@ -28,6 +28,9 @@ use std::io::Read;
 
 /// Parses an object literal.
 ///
+/// All property definitions are kept in source order, even when several of them share a key.
+/// Which one takes effect is decided when the object is created.
+///
 /// More information:
 ///  - [MDN documentation][mdn]
 ///  - [ECMAScript specification][spec]
@@ -152,11 +155,9 @@ where
             // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
-            .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
-        {
+        let next_is_paren = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Punctuator(Punctuator::OpenParen);
+        if next_is_paren || ["get", "set"].contains(&prop_name.as_str()) {
             return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
                 .parse(cursor);
         }
@@ -204,8 +205,13 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
+        // `get` and `set` followed by `(` are the names of ordinary methods.
+        let is_accessor = ["get", "set"].contains(&self.identifier.as_str())
+            && cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+                != &TokenKind::Punctuator(Punctuator::OpenParen);
+
         let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set" => {
+            idn @ "get" | idn @ "set" if is_accessor => {
                 let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::OpenParen),
@@ -229,10 +235,17 @@ where
                             "setter functions must have one argument",
                         ));
                     }
+                    if params[0].is_rest_param() {
+                        return Err(ParseError::unexpected(
+                            first_param,
+                            "setter functions must not have a rest parameter",
+                        ));
+                    }
                     (MethodDefinitionKind::Set, prop_name, params)
                 }
             }
             prop_name => {
+                cursor.expect(Punctuator::OpenParen, "method definition")?;
                 let params = FormalParameters::new(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "method definition")?;
                 (
@@ -1,12 +1,12 @@
 use crate::syntax::{
     ast::{
         node::{
-            ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, MethodDefinitionKind, Object,
-            PropertyDefinition,
+            ConditionalOp, ConstDecl, ConstDeclList, FormalParameter, FunctionExpr, GetConstField,
+            Identifier, MethodDefinitionKind, Object, PropertyDefinition, Return,
         },
         Const,
     },
-    parser::tests::check_parser,
+    parser::{tests::check_parser, Parser},
 };
 
 /// Checks object literal parsing.
@@ -31,6 +31,66 @@ fn check_object_literal() {
     );
 }
 
+/// Checks that the contextual keywords `async`, `get` and `set` can be keys of data properties.
+#[test]
+fn check_object_contextual_keyword_keys() {
+    let object_properties = vec![
+        PropertyDefinition::property("async", Const::from(1)),
+        PropertyDefinition::property("get", Const::from(2)),
+        PropertyDefinition::property("set", Const::from(3)),
+    ];
+
+    check_parser(
+        "const x = {
+            async: 1,
+            get: 2,
+            set: 3,
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that the contextual keywords `async`, `get` and `set` can be names of methods.
+#[test]
+fn check_object_contextual_keyword_methods() {
+    let object_properties = vec![
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "async",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "get",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "set",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            async() {},
+            get() {},
+            set() {},
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
 /// Tests short function syntax.
 #[test]
 fn check_object_short_function() {
@@ -87,6 +147,44 @@ fn check_object_short_function_arguments() {
     );
 }
 
+/// Checks that `arguments` is an identifier reference inside of a method body.
+#[test]
+fn check_object_method_arguments() {
+    let object_properties = vec![PropertyDefinition::method_definition(
+        MethodDefinitionKind::Ordinary,
+        "m",
+        FunctionExpr::new(
+            None,
+            vec![],
+            vec![Return::new(
+                GetConstField::new(Identifier::from("arguments"), "length"),
+                None,
+            )
+            .into()],
+        ),
+    )];
+
+    check_parser(
+        "const x = {
+            m() { return arguments.length; }
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that `arguments` can also be referenced in a method body in strict mode code.
+#[test]
+fn check_object_method_arguments_strict() {
+    let js = "const x = { m() { return arguments.length; } };";
+
+    assert!(Parser::new(js.as_bytes(), true).parse_all().is_ok());
+}
+
 #[test]
 fn check_object_getter() {
     let object_properties = vec![
@@ -140,3 +238,155 @@ fn check_object_setter() {
         .into()],
     );
 }
+
+#[test]
+fn check_object_duplicate_keys_kept_in_order() {
+    let object_properties = vec![
+        PropertyDefinition::property("x", Const::from(1)),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Get,
+            "x",
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Set,
+            "x",
+            FunctionExpr::new(None, vec![FormalParameter::new("v", None, false)], vec![]),
+        ),
+        PropertyDefinition::property("x", Const::from(2)),
+    ];
+
+    check_parser(
+        "const x = {
+            x: 1,
+            get x() {},
+            set x(v) {},
+            x: 2
+        };
+        ",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an object literal whose only property is a spread.
+#[test]
+fn check_object_lone_spread() {
+    check_parser(
+        "const x = { ...y };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(vec![PropertyDefinition::spread_object(
+                Identifier::from("y"),
+            )])),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that a chained conditional as a property value groups to the right.
+#[test]
+fn check_object_nested_conditional_value() {
+    let object_properties = vec![
+        PropertyDefinition::property(
+            "a",
+            ConditionalOp::new(
+                Identifier::from("x"),
+                Const::from(1),
+                ConditionalOp::new(Identifier::from("y"), Const::from(2), Const::from(3)),
+            ),
+        ),
+        PropertyDefinition::property("b", Const::from(4)),
+    ];
+
+    check_parser(
+        "const o = { a: x ? 1 : y ? 2 : 3, b: 4 };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "o",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks an object method with a rest parameter.
+#[test]
+fn check_object_method_rest_parameter() {
+    let object_properties = vec![PropertyDefinition::method_definition(
+        MethodDefinitionKind::Ordinary,
+        "m",
+        FunctionExpr::new(
+            None,
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("rest", None, true),
+            ],
+            vec![],
+        ),
+    )];
+
+    check_parser(
+        "const x = { m(a, ...rest) {} };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
+
+/// Checks that a rest parameter must be the last parameter of an object method.
+#[test]
+fn check_object_method_rest_parameter_not_last() {
+    let js = "const x = { m(...a, b) {} };";
+
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert!(error
+        .to_string()
+        .contains("rest parameter must be the last formal parameter"));
+}
+
+/// Checks that a setter cannot have a rest parameter.
+#[test]
+fn check_object_setter_rest_parameter() {
+    let js = "const x = { set a(...v) {} };";
+
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert!(error
+        .to_string()
+        .contains("setter functions must not have a rest parameter"));
+}
+
+/// Checks that a setter parameter can have a default value.
+#[test]
+fn check_object_setter_default_parameter() {
+    let object_properties = vec![PropertyDefinition::method_definition(
+        MethodDefinitionKind::Set,
+        "a",
+        FunctionExpr::new(
+            None,
+            vec![FormalParameter::new(
+                "v",
+                Some(Const::from(1).into()),
+                false,
+            )],
+            vec![],
+        ),
+    )];
+
+    check_parser(
+        "const x = { set a(v = 1) {} };",
+        vec![ConstDeclList::from(vec![ConstDecl::new(
+            "x",
+            Some(Object::from(object_properties)),
+        )])
+        .into()],
+    );
+}
@@ -0,0 +1,116 @@
+//! Template literal parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+//! [spec]: https://tc39.es/ecma262/#sec-template-literals
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    profiler::BoaProfiler,
+    syntax::{
+        ast::{
+            node::{Node, TemplateElement, TemplateLit},
+            Position, Punctuator,
+        },
+        lexer::{token::TemplateString, Error as LexError, TokenKind},
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+};
+use std::io::Read;
+
+/// Parses a template literal with substitutions.
+///
+/// Expects the first chunk of the template, up to the first `${`, to already be consumed.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals
+/// [spec]: https://tc39.es/ecma262/#prod-TemplateLiteral
+#[derive(Debug, Clone)]
+pub(super) struct TemplateLiteral {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    start: Position,
+    first: TemplateString,
+}
+
+impl TemplateLiteral {
+    /// Creates a new `TemplateLiteral` parser.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        start: Position,
+        first: TemplateString,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            start,
+            first,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for TemplateLiteral
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Parsing");
+
+        let mut elements = vec![TemplateElement::String(
+            cooked_string(&self.first, self.start)?.into(),
+        )];
+
+        loop {
+            let expr = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+            elements.push(TemplateElement::Expr(expr));
+
+            let close = cursor.expect(Punctuator::CloseBlock, "template literal")?;
+            let chunk_start = close.span().start();
+            let tok = cursor.lex_template(chunk_start)?;
+            match tok.kind() {
+                TokenKind::TemplateMiddle(template_string) => elements.push(
+                    TemplateElement::String(cooked_string(template_string, chunk_start)?.into()),
+                ),
+                TokenKind::TemplateNoSubstitution(template_string) => {
+                    elements.push(TemplateElement::String(
+                        cooked_string(template_string, chunk_start)?.into(),
+                    ));
+                    return Ok(TemplateLit::new(elements).into());
+                }
+                _ => return Err(ParseError::unexpected(tok.clone(), "template literal")),
+            }
+        }
+    }
+}
+
+/// Gets the cooked string of a template chunk, which must not have invalid escape sequences
+/// outside of tagged templates.
+pub(super) fn cooked_string(
+    template_string: &TemplateString,
+    start: Position,
+) -> Result<&str, ParseError> {
+    template_string.cooked().ok_or_else(|| {
+        ParseError::lex(LexError::Syntax(
+            "invalid escape sequence in template literal".into(),
+            start,
+        ))
+    })
+}
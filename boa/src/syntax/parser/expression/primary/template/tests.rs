@@ -0,0 +1,76 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, BinOp, Identifier, TaggedTemplate, TemplateElement, TemplateLit,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_template_literal() {
+    check_parser(
+        "`a${b + 1}c${d}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(
+                BinOp::new(NumOp::Add, Identifier::from("b"), Const::from(1)).into(),
+            ),
+            TemplateElement::String("c".into()),
+            TemplateElement::Expr(Identifier::from("d").into()),
+            TemplateElement::String("".into()),
+        ])
+        .into()],
+    );
+}
+
+#[test]
+fn check_template_literal_no_substitution() {
+    check_parser(
+        "`a\\n\\`b`",
+        vec![TemplateLit::new(vec![TemplateElement::String("a\n`b".into())]).into()],
+    );
+}
+
+#[test]
+fn check_nested_template_literal() {
+    check_parser(
+        "`a${`b${c}`}`",
+        vec![TemplateLit::new(vec![
+            TemplateElement::String("a".into()),
+            TemplateElement::Expr(
+                TemplateLit::new(vec![
+                    TemplateElement::String("b".into()),
+                    TemplateElement::Expr(Identifier::from("c").into()),
+                    TemplateElement::String("".into()),
+                ])
+                .into(),
+            ),
+            TemplateElement::String("".into()),
+        ])
+        .into()],
+    );
+}
+
+/// Checks that invalid escape sequences are only allowed in tagged templates.
+#[test]
+fn check_template_literal_invalid_escape() {
+    check_invalid("`\\unicode`");
+    check_invalid("`a${b}\\01`");
+}
+
+#[test]
+fn check_tagged_template() {
+    check_parser(
+        "a.tag`x\\n${1}\\unicode`",
+        vec![TaggedTemplate::new(
+            GetConstField::new(Identifier::from("a"), "tag"),
+            vec!["x\\n".into(), "\\unicode".into()],
+            vec![Some("x\n".into()), None],
+            vec![Const::from(1).into()],
+        )
+        .into()],
+    );
+}
@@ -1,10 +1,16 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, NumOp},
+    ast::op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{
+            field::GetConstField, Assign, BinOp, ConditionalOp, Identifier, Node, StatementList,
+            UnaryOp,
+        },
         Const,
     },
-    parser::tests::check_parser,
+    parser::{
+        tests::{check_invalid, check_invalid_message, check_parser},
+        Parser, ParserOptions,
+    },
 };
 
 /// Checks numeric operations
@@ -193,6 +199,29 @@ fn check_assign_operations() {
     );
 }
 
+/// Checks that the right hand side of `**=` is a full, right-associative assignment expression.
+#[test]
+fn check_exponentiation_assign_precedence() {
+    check_parser(
+        "a **= b ** c",
+        vec![BinOp::new(
+            AssignOp::Exp,
+            Identifier::from("a"),
+            BinOp::new(NumOp::Exp, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+    check_parser(
+        "a **= b **= c",
+        vec![BinOp::new(
+            AssignOp::Exp,
+            Identifier::from("a"),
+            BinOp::new(AssignOp::Exp, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+}
+
 #[test]
 fn check_relational_operations() {
     check_parser(
@@ -236,3 +265,276 @@ fn check_relational_operations() {
         vec![BinOp::new(CompOp::In, Identifier::from("p"), Identifier::from("o")).into()],
     );
 }
+
+/// Parses the given JavaScript with string concatenation folding enabled.
+fn parse_folding(js: &str) -> StatementList {
+    let mut parser = Parser::new(js.as_bytes(), false);
+    parser.set_options(ParserOptions::default().fold_string_concat(true));
+    parser.parse_all().expect("failed to parse")
+}
+
+/// Checks that string literal concatenations are folded when enabled.
+#[test]
+fn check_string_concat_folding() {
+    assert_eq!(
+        parse_folding("\"a\" + \"b\""),
+        StatementList::from(vec![Const::from("ab").into()])
+    );
+    assert_eq!(
+        parse_folding("\"a\" + \"b\" + \"c\""),
+        StatementList::from(vec![Const::from("abc").into()])
+    );
+
+    // Folding is opt-in.
+    check_parser(
+        "\"a\" + \"b\"",
+        vec![BinOp::new(NumOp::Add, Const::from("a"), Const::from("b")).into()],
+    );
+}
+
+/// Checks that additions with non-literal operands are not folded.
+#[test]
+fn check_string_concat_no_folding() {
+    assert_eq!(
+        parse_folding("\"a\" + x"),
+        StatementList::from(vec![BinOp::new(
+            NumOp::Add,
+            Const::from("a"),
+            Identifier::from("x")
+        )
+        .into()])
+    );
+    assert_eq!(
+        parse_folding("\"a\" - \"b\""),
+        StatementList::from(vec![BinOp::new(
+            NumOp::Sub,
+            Const::from("a"),
+            Const::from("b")
+        )
+        .into()])
+    );
+}
+
+/// Checks that a postfix operator on the next line is parsed as a prefix operator of a new statement.
+#[test]
+fn check_postfix_line_terminator_asi() {
+    check_parser(
+        "a
+        ++b",
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::IncrementPre, Identifier::from("b")).into(),
+        ],
+    );
+    check_parser(
+        "a
+        --b",
+        vec![
+            Identifier::from("a").into(),
+            UnaryOp::new(op::UnaryOp::DecrementPre, Identifier::from("b")).into(),
+        ],
+    );
+}
+
+/// Checks that update expressions require a simple assignment target.
+#[test]
+fn check_invalid_update_targets() {
+    check_invalid("5++");
+    check_invalid("(a + b)++");
+    check_invalid("--5");
+    check_invalid("++(a + b)");
+    check_invalid("a()--");
+}
+
+/// Checks that parenthesized identifiers and property accesses are valid update targets.
+#[test]
+fn check_valid_update_targets() {
+    check_parser(
+        "(a)++",
+        vec![UnaryOp::new(op::UnaryOp::IncrementPost, Identifier::from("a")).into()],
+    );
+    check_parser(
+        "--a.b",
+        vec![UnaryOp::new(
+            op::UnaryOp::DecrementPre,
+            GetConstField::new(Identifier::from("a"), "b"),
+        )
+        .into()],
+    );
+}
+
+/// Checks that parenthesized identifiers and property accesses are valid assignment targets.
+#[test]
+fn check_parenthesized_assignment_targets() {
+    check_parser(
+        "(a) = 1",
+        vec![Assign::new(Identifier::from("a"), Const::from(1)).into()],
+    );
+    check_parser(
+        "(a.b) = 1",
+        vec![Assign::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            Const::from(1),
+        )
+        .into()],
+    );
+}
+
+/// Checks that sequences and other expressions are not valid assignment targets.
+#[test]
+fn check_invalid_assignment_targets() {
+    check_invalid("(a, b) = 1");
+    check_invalid("a + 1 = 2");
+    check_invalid("(a + 1) = 2");
+    check_invalid("a() = 1");
+    check_invalid("a + 1 += 2");
+}
+
+/// Checks that conditional expressions nest to the right and take assignments in both branches.
+#[test]
+fn check_conditional_operations() {
+    check_parser(
+        "a ? b : c ? d : e",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            Identifier::from("b"),
+            ConditionalOp::new(
+                Identifier::from("c"),
+                Identifier::from("d"),
+                Identifier::from("e"),
+            ),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ? b ? c : d : e",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            ConditionalOp::new(
+                Identifier::from("b"),
+                Identifier::from("c"),
+                Identifier::from("d"),
+            ),
+            Identifier::from("e"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ? b = 1 : c = 2",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            Assign::new(Identifier::from("b"), Const::from(1)),
+            Assign::new(Identifier::from("c"), Const::from(2)),
+        )
+        .into()],
+    );
+    check_parser(
+        "a || b ? c : d",
+        vec![ConditionalOp::new(
+            BinOp::new(LogOp::Or, Identifier::from("a"), Identifier::from("b")),
+            Identifier::from("c"),
+            Identifier::from("d"),
+        )
+        .into()],
+    );
+
+    // `in` is allowed in the consequent even where it is not allowed in the expression.
+    assert!(
+        Parser::new(&b"for (var x = a ? b in c : d;;) {}"[..], false)
+            .parse_all()
+            .is_ok()
+    );
+}
+
+/// Checks that a conditional expression requires its `:`.
+#[test]
+fn check_conditional_missing_colon() {
+    check_invalid_message(
+        "a ? b, c : d",
+        "expected token ':', got ',' in conditional expression at line 1, col 6",
+    );
+    check_invalid("a ? b");
+    check_invalid("a ? b c");
+}
+
+/// Checks that `yield` and `await` are rejected in strict mode code outside of generator and
+/// async functions.
+#[test]
+fn check_misplaced_yield_and_await() {
+    check_invalid_message(
+        "'use strict'; yield;",
+        "Syntax Error: yield is only valid in generator functions at position: 1:15",
+    );
+    check_invalid_message(
+        "'use strict'; a = yield;",
+        "Syntax Error: yield is only valid in generator functions at position: 1:19",
+    );
+    check_invalid_message(
+        "'use strict'; await;",
+        "Syntax Error: await is only valid in async functions at position: 1:15",
+    );
+    check_invalid_message(
+        "function f() { 'use strict'; a = await; }",
+        "Syntax Error: await is only valid in async functions at position: 1:34",
+    );
+}
+
+/// Checks that `yield` and `await` are identifier references in sloppy mode code.
+#[test]
+fn check_yield_and_await_identifier_references() {
+    check_parser(
+        "a = yield; await;",
+        vec![
+            Assign::new(Identifier::from("a"), Identifier::from("yield")).into(),
+            Identifier::from("await").into(),
+        ],
+    );
+}
+
+/// Returns the precedence of the single expression statement of the given JavaScript.
+fn precedence_of(js: &str) -> u8 {
+    let statements = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse");
+    statements.statements()[0].precedence()
+}
+
+/// Checks the precedences of expressions, from the tightest to the loosest.
+#[test]
+fn check_precedence() {
+    let ordered = [
+        "a",
+        "a.b",
+        "a++",
+        "!a",
+        "a ** b",
+        "a * b",
+        "a + b",
+        "a << b",
+        "a < b",
+        "a == b",
+        "a & b",
+        "a ^ b",
+        "a | b",
+        "a && b",
+        "a || b",
+        "a ? b : c",
+        "a = b",
+        "a, b",
+    ];
+    for pair in ordered.windows(2) {
+        assert!(
+            precedence_of(pair[0]) > precedence_of(pair[1]),
+            "`{}` should bind tighter than `{}`",
+            pair[0],
+            pair[1]
+        );
+    }
+
+    assert_eq!(precedence_of("a * b"), precedence_of("a % b"));
+    assert_eq!(precedence_of("a = b"), precedence_of("a += b"));
+    assert_eq!(precedence_of("a = b"), precedence_of("(a) => b"));
+    assert_eq!(precedence_of("a, b"), Node::COMMA_PRECEDENCE);
+    assert_eq!(precedence_of("1"), Node::PRIMARY_PRECEDENCE);
+    assert_eq!(precedence_of("if (a) b;"), Node::STATEMENT_PRECEDENCE);
+}
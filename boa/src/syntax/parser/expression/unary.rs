@@ -68,7 +68,7 @@ where
                 cursor.next()?.expect("Delete keyword vanished"); // Consume the token.
                 let val = self.parse(cursor)?;
 
-                if cursor.strict_mode() {
+                if cursor.options().has_early_errors() && cursor.strict_mode() {
                     if let Node::Identifier(_) = val {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Delete <variable> statements not allowed in strict mode".into(),
@@ -5,12 +5,15 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-update-expressions
 
-use super::left_hand_side::LeftHandSideExpression;
+use super::{
+    assignment::{check_strict_assignment_target, is_assignable},
+    left_hand_side::LeftHandSideExpression,
+};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{node, op::UnaryOp, Node, Punctuator},
-        lexer::TokenKind,
+        lexer::{Error as LexError, TokenKind},
         parser::{
             expression::unary::UnaryExpression, AllowAwait, AllowYield, Cursor, ParseError,
             ParseResult, TokenParser,
@@ -56,38 +59,49 @@ where
         let _timer = BoaProfiler::global().start_event("UpdateExpression", "Parsing");
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
-        match tok.kind() {
-            TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
-            }
-            TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+        let op = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPre),
+            TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPre),
+            _ => None,
+        };
+        if let Some(op) = op {
+            cursor.next()?.expect("update operator token disappeared");
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let target = UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if cursor.options().has_early_errors() && !is_assignable(&target) {
+                return Err(ParseError::lex(LexError::Syntax(
+                    "Invalid left-hand side expression in prefix operation".into(),
+                    position,
+                )));
             }
-            _ => {}
+            check_strict_assignment_target(&target, cursor, position)?;
+            return Ok(node::UnaryOp::new(op, target).into());
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        // Postfix operators are restricted productions, so a line terminator between the operand
+        // and the operator means that a semicolon is automatically inserted.
+        if cursor.line_terminator_before_next()? {
+            return Ok(lhs);
+        }
+
         if let Some(tok) = cursor.peek(0)? {
-            match tok.kind() {
-                TokenKind::Punctuator(Punctuator::Inc) => {
-                    cursor.next()?.expect("Punctuator::Inc token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
-                }
-                TokenKind::Punctuator(Punctuator::Dec) => {
-                    cursor.next()?.expect("Punctuator::Dec token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
+            let op = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Inc) => Some(UnaryOp::IncrementPost),
+                TokenKind::Punctuator(Punctuator::Dec) => Some(UnaryOp::DecrementPost),
+                _ => None,
+            };
+            if let Some(op) = op {
+                let tok = cursor.next()?.expect("update operator token disappeared");
+                if cursor.options().has_early_errors() && !is_assignable(&lhs) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid left-hand side expression in postfix operation".into(),
+                        tok.span().start(),
+                    )));
                 }
-                _ => {}
+                check_strict_assignment_target(&lhs, cursor, tok.span().start())?;
+                return Ok(node::UnaryOp::new(op, lhs).into());
             }
         }
 
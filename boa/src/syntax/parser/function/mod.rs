@@ -16,7 +16,7 @@ use crate::{
             node::{self},
             Punctuator,
         },
-        lexer::{InputElement, TokenKind},
+        lexer::{Error as LexError, InputElement, TokenKind},
         parser::{
             expression::Initializer,
             statement::{BindingIdentifier, StatementList},
@@ -76,6 +76,7 @@ where
         loop {
             let mut rest_param = false;
 
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let next_param = match cursor.peek(0)? {
                 Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Spread) => {
                     rest_param = true;
@@ -84,6 +85,20 @@ where
                 _ => FormalParameter::new(self.allow_yield, self.allow_await).parse(cursor)?,
             };
 
+            if cursor.options().has_early_errors()
+                && cursor.strict_mode()
+                && params.iter().any(|param| param.name() == next_param.name())
+            {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!(
+                        "duplicate parameter '{}' not allowed in strict mode",
+                        next_param.name()
+                    )
+                    .into(),
+                    position,
+                )));
+            }
+
             params.push(next_param);
 
             if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
@@ -261,12 +276,16 @@ where
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
 
         let global_strict_mode = cursor.strict_mode();
+        let allow_break = cursor.allow_break();
+        let allow_continue = cursor.allow_continue();
         if let Some(tk) = cursor.peek(0)? {
             match tk.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
+                    let mut stmlist = node::StatementList::from(Vec::new());
+                    stmlist.set_strict(global_strict_mode);
+                    return Ok(stmlist);
                 }
-                TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
+                TokenKind::StringLiteral(string) => {
                     if string == &"use strict".into() {
                         cursor.set_strict_mode(true);
                     }
@@ -275,11 +294,24 @@ where
             }
         }
 
-        let stmlist =
-            StatementList::new(self.allow_yield, self.allow_await, true, true, true).parse(cursor);
+        // Loops, switch statements and labelled statements outside of the function can not be
+        // jumped out of from inside it.
+        cursor.set_allow_break(false);
+        cursor.set_allow_continue(false);
+        let labels = cursor.take_labels();
+
+        let stmlist = StatementList::new(self.allow_yield, self.allow_await, true, true)
+            .parse(cursor)
+            .map(|mut stmlist| {
+                stmlist.set_strict(cursor.strict_mode());
+                stmlist
+            });
 
         // Reset strict mode back to the global scope.
         cursor.set_strict_mode(global_strict_mode);
+        cursor.set_allow_break(allow_break);
+        cursor.set_allow_continue(allow_continue);
+        cursor.set_labels(labels);
         stmlist
     }
 }
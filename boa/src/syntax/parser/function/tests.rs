@@ -1,9 +1,10 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, FormalParameter, FunctionDecl, Identifier, Node, Return,
+        ArrowFunctionDecl, BinOp, Call, ConditionalOp, FormalParameter, FunctionDecl, Identifier,
+        Node, Return,
     },
-    ast::op::NumOp,
-    parser::tests::check_parser,
+    ast::{op, op::NumOp, Const},
+    parser::tests::{check_invalid, check_invalid_message, check_parser},
 };
 
 /// Checks basic function declaration parsing.
@@ -125,6 +126,19 @@ fn check_arrow() {
     );
 }
 
+/// Checks an arrow function with a single parenthesized parameter.
+#[test]
+fn check_arrow_single_parenthesized_param() {
+    check_parser(
+        "(a) => { return a; }",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new("a", None, false)],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
 /// Checks an arrow function with expression return and automatic semicolon insertion
 #[test]
 fn check_arrow_semicolon_insertion() {
@@ -176,3 +190,162 @@ fn check_arrow_empty_return_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks an arrow function with a conditional expression as its concise body.
+#[test]
+fn check_arrow_conditional_body() {
+    check_parser(
+        "x => a ? b : c",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new("x", None, false)],
+            vec![Return::new(
+                ConditionalOp::new(
+                    Identifier::from("a"),
+                    Identifier::from("b"),
+                    Identifier::from("c"),
+                ),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that an arrow function in the alternative of a conditional concise body is part of
+/// the conditional, so the outer arrow function's body is the whole conditional.
+#[test]
+fn check_arrow_nested_in_conditional_body() {
+    check_parser(
+        "x => a ? b : c => d",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new("x", None, false)],
+            vec![Return::new(
+                ConditionalOp::new(
+                    Identifier::from("a"),
+                    Identifier::from("b"),
+                    ArrowFunctionDecl::new(
+                        vec![FormalParameter::new("c", None, false)],
+                        vec![Return::new(Identifier::from("d"), None).into()],
+                    ),
+                ),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a parenthesized list followed by `=>` is parsed as arrow function parameters.
+#[test]
+fn check_arrow_cover_parameters() {
+    check_parser(
+        "(a,b)=>a",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("b", None, false),
+            ],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+    check_parser(
+        "(a = 1) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new(
+                "a",
+                Some(Const::from(1).into()),
+                false,
+            )],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a parenthesized list not followed by `=>` is parsed as an expression.
+#[test]
+fn check_arrow_cover_parenthesized_expression() {
+    check_parser("(a)", vec![Identifier::from("a").into()]);
+    check_parser(
+        "(a, b)(c)",
+        vec![Call::new(
+            BinOp::new(
+                op::BinOp::Comma,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            vec![Identifier::from("c").into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that only a parenthesized list of identifiers and initializers can be an arrow
+/// function head.
+#[test]
+fn check_arrow_cover_invalid_head() {
+    check_invalid_message(
+        "(a+b) => 1",
+        "Syntax Error: Malformed arrow function parameter list at position: 1:1",
+    );
+    check_invalid("x + (b) => c");
+    check_invalid("(a)\n=> a");
+    check_invalid("(...a)");
+    check_invalid("()");
+    check_invalid_message(
+        "((a)) => 1",
+        "Syntax Error: Malformed arrow function parameter list at position: 1:1",
+    );
+    check_invalid("(a, (b)) => 1");
+    check_invalid("((a) = 1) => 1");
+}
+
+/// Checks that the parameters of an arrow function can not be repeated, even in sloppy mode code.
+#[test]
+fn check_arrow_duplicate_parameters() {
+    check_invalid_message(
+        "(a, a) => 1",
+        "Syntax Error: duplicate parameter 'a' not allowed in arrow function at position: 1:1",
+    );
+    check_invalid("(a, b = 1, a) => 1");
+    check_invalid("(a, ...a) => 1");
+    check_parser(
+        "(a, b = (a)) => 1",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("b", Some(Identifier::from("a").into()), false),
+            ],
+            vec![Return::new(Const::from(1), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a line terminator is only disallowed between an arrow function parameter and `=>`.
+#[test]
+fn check_arrow_line_terminator() {
+    check_parser(
+        "\na => a",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new("a", None, false)],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+    check_parser(
+        "a =>\na",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new("a", None, false)],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+    check_invalid_message(
+        "a\n=> a",
+        "unexpected token 'line terminator', arrow function at line 1, col 2",
+    );
+}
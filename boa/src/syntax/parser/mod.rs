@@ -4,12 +4,23 @@ mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod options;
 mod statement;
 #[cfg(test)]
 mod tests;
 
-pub use self::error::{ParseError, ParseResult};
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+pub use self::{
+    error::{MessageFormat, ParseError, ParseResult},
+    options::ParserOptions,
+};
+use crate::syntax::{
+    ast::{
+        node::{Node, StatementList},
+        Punctuator,
+    },
+    diagnostic::Diagnostic,
+    lexer::TokenKind,
+};
 
 use cursor::Cursor;
 
@@ -98,12 +109,49 @@ impl<R> Parser<R> {
         Self { cursor }
     }
 
+    /// Sets the options used for the rest of the parsing.
+    pub fn set_options(&mut self, options: ParserOptions)
+    where
+        R: Read,
+    {
+        self.cursor.set_options(options);
+    }
+
+    /// Takes the diagnostics reported while parsing so far, in source order.
+    ///
+    /// Diagnostics are only reported for the features enabled in the
+    /// [options](struct.ParserOptions.html) of the parser, and never stop the parsing.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic>
+    where
+        R: Read,
+    {
+        self.cursor.take_diagnostics()
+    }
+
     pub fn parse_all(&mut self) -> Result<StatementList, ParseError>
     where
         R: Read,
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses a single statement or declaration, leaving the rest of the input untouched.
+    ///
+    /// Returns the parsed node along with whether there is more input left to parse, so that the
+    /// remaining statements can be parsed by later calls.
+    pub fn parse_statement(&mut self) -> Result<(Node, bool), ParseError>
+    where
+        R: Read,
+    {
+        let node =
+            statement::StatementListItem::new(false, false, false).parse(&mut self.cursor)?;
+
+        // Semicolons that end the statement are not part of the remaining input.
+        while self.cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+
+        let more = self.cursor.peek(0)?.is_some();
+        Ok((node, more))
+    }
 }
 
 /// Parses a full script.
@@ -125,14 +173,16 @@ where
         match cursor.peek(0)? {
             Some(tok) => {
                 match tok.kind() {
-                    TokenKind::StringLiteral(string) | TokenKind::TemplateLiteral(string) => {
+                    TokenKind::StringLiteral(string) => {
                         if string.as_ref() == "use strict" {
                             cursor.set_strict_mode(true);
                         }
                     }
                     _ => {}
                 }
-                ScriptBody.parse(cursor)
+                let mut statement_list = ScriptBody.parse(cursor)?;
+                statement_list.set_strict(cursor.strict_mode());
+                Ok(statement_list)
             }
             None => Ok(StatementList::from(Vec::new())),
         }
@@ -155,6 +205,6 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        self::statement::StatementList::new(false, false, false, false, true).parse(cursor)
+        self::statement::StatementList::new(false, false, false, false).parse(cursor)
     }
 }
@@ -0,0 +1,97 @@
+//! Options to configure the parser.
+
+/// Options that change how the parser handles its input.
+///
+/// The default options give a parser that follows the specification, with all early errors
+/// enabled and no transformations of the parsed code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserOptions {
+    early_errors: bool,
+    fold_string_concat: bool,
+    deprecation_diagnostics: bool,
+    max_literal_depth: Option<usize>,
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self {
+            early_errors: true,
+            fold_string_concat: false,
+            deprecation_diagnostics: false,
+            max_literal_depth: None,
+        }
+    }
+}
+
+impl ParserOptions {
+    /// Enables or disables the early error checks.
+    ///
+    /// When disabled, an AST is returned even for code with redeclarations of lexical bindings or
+    /// labels, `const` declarations without an initializer, arrow functions with duplicate
+    /// parameters, invalid assignment targets, `break` and `continue` statements outside of a loop
+    /// or with an undefined label, and for strict mode code with duplicate parameters, `delete` of
+    /// identifiers, bindings of or assignments to `eval` and `arguments`, reserved words, `with`
+    /// statements or legacy octal literals and escapes. Syntax errors are still reported. This is
+    /// enabled by default.
+    #[inline]
+    pub fn early_errors(mut self, early_errors: bool) -> Self {
+        self.early_errors = early_errors;
+        self
+    }
+
+    /// Enables or disables the folding of string literal concatenations at parse time.
+    ///
+    /// When enabled, `"a" + "b" + "c"` is parsed as the single literal `"abc"`. Additions with any
+    /// operand that is not a string literal are left untouched. This is disabled by default.
+    #[inline]
+    pub fn fold_string_concat(mut self, fold_string_concat: bool) -> Self {
+        self.fold_string_concat = fold_string_concat;
+        self
+    }
+
+    /// Enables or disables the diagnostics for deprecated legacy features.
+    ///
+    /// When enabled, `with` statements, legacy octal literals and escape sequences, and accesses
+    /// to `arguments.caller` are still accepted in sloppy mode, but they are also reported as
+    /// diagnostics of the parser. This is disabled by default.
+    #[inline]
+    pub fn deprecation_diagnostics(mut self, deprecation_diagnostics: bool) -> Self {
+        self.deprecation_diagnostics = deprecation_diagnostics;
+        self
+    }
+
+    /// Sets the maximum nesting depth of object and array literals, or removes the limit.
+    ///
+    /// Parsing `[[[1]]]` needs a depth of 3, and a literal nested any deeper than the limit is a
+    /// syntax error. This allows bounding the parsing of untrusted JSON-like inputs. There is no
+    /// limit by default.
+    #[inline]
+    pub fn max_literal_depth(mut self, max_literal_depth: Option<usize>) -> Self {
+        self.max_literal_depth = max_literal_depth;
+        self
+    }
+
+    /// Returns whether the early error checks are enabled.
+    #[inline]
+    pub fn has_early_errors(self) -> bool {
+        self.early_errors
+    }
+
+    /// Returns whether string literal concatenations are folded.
+    #[inline]
+    pub fn has_fold_string_concat(self) -> bool {
+        self.fold_string_concat
+    }
+
+    /// Returns whether deprecated legacy features are reported as diagnostics.
+    #[inline]
+    pub fn has_deprecation_diagnostics(self) -> bool {
+        self.deprecation_diagnostics
+    }
+
+    /// Returns the maximum nesting depth of object and array literals, if there is one.
+    #[inline]
+    pub fn get_max_literal_depth(self) -> Option<usize> {
+        self.max_literal_depth
+    }
+}
@@ -78,17 +78,12 @@ where
             }
         }
 
-        let statement_list = StatementList::new(
-            self.allow_yield,
-            self.allow_await,
-            self.allow_return,
-            true,
-            true,
-        )
-        .parse(cursor)
-        .map(node::Block::from)?;
+        let mut statement_list =
+            StatementList::new(self.allow_yield, self.allow_await, self.allow_return, true)
+                .parse(cursor)?;
+        statement_list.set_strict(cursor.strict_mode());
         cursor.expect(Punctuator::CloseBlock, "block")?;
 
-        Ok(statement_list)
+        Ok(statement_list.into())
     }
 }
@@ -12,7 +12,7 @@ mod tests;
 
 use super::LabelIdentifier;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Break, Keyword, Punctuator},
@@ -62,7 +62,10 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("BreakStatement", "Parsing");
-        cursor.expect(Keyword::Break, "break statement")?;
+        let break_start = cursor
+            .expect(Keyword::Break, "break statement")?
+            .span()
+            .start();
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -72,9 +75,25 @@ where
                 _ => {}
             }
 
+            // Labelled statements can be broken out of anywhere inside them, so only unlabelled
+            // `break` statements need an enclosing loop or `switch`.
+            if cursor.options().has_early_errors() && !cursor.allow_break() {
+                return Err(ParseError::lex(LexError::Syntax(
+                    "Illegal break statement outside of a loop or switch".into(),
+                    break_start,
+                )));
+            }
+
             None
         } else {
+            let label_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if cursor.options().has_early_errors() && cursor.find_label(&label).is_none() {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Undefined label '{}'", label).into(),
+                    label_start,
+                )));
+            }
             cursor.expect_semicolon("break statement")?;
 
             Some(label)
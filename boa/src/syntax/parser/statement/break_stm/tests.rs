@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Break, Node, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid_message, check_parser},
 };
 
 #[test]
@@ -42,7 +42,7 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
         vec![WhileLoop::new(
@@ -68,7 +68,7 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
         vec![WhileLoop::new(
@@ -82,7 +82,7 @@ fn new_line_block() {
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
         vec![WhileLoop::new(
@@ -93,7 +93,7 @@ fn reserved_label() {
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
         vec![WhileLoop::new(
@@ -131,3 +131,59 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks that an unlabelled `break` needs an enclosing loop or `switch`.
+#[test]
+fn misplaced_break() {
+    check_invalid_message(
+        "break;",
+        "Syntax Error: Illegal break statement outside of a loop or switch at position: 1:1",
+    );
+    check_invalid_message(
+        "if (true) { break; }",
+        "Syntax Error: Illegal break statement outside of a loop or switch at position: 1:13",
+    );
+    check_invalid_message(
+        "while (true) { function f() { break; } }",
+        "Syntax Error: Illegal break statement outside of a loop or switch at position: 1:31",
+    );
+}
+
+/// Checks that a labelled `break` is allowed in a labelled block outside of any loop.
+#[test]
+fn labelled_break_in_block() {
+    check_parser(
+        "label: { break label; }",
+        vec![Block::from(vec![Break::new("label").into()]).into()],
+    );
+}
+
+/// Checks that a labelled `break` needs an enclosing statement with its label in the same function.
+#[test]
+fn undefined_break_label() {
+    check_invalid_message(
+        "while (true) { break foo; }",
+        "Syntax Error: Undefined label 'foo' at position: 1:22",
+    );
+    check_invalid_message(
+        "foo: { } break foo;",
+        "Syntax Error: Undefined label 'foo' at position: 1:16",
+    );
+    check_invalid_message(
+        "l: for (;;) { (function () { break l; })(); }",
+        "Syntax Error: Undefined label 'l' at position: 1:36",
+    );
+}
+
+/// Checks that a label can not be reused by a nested statement.
+#[test]
+fn duplicate_label() {
+    check_invalid_message(
+        "a: { a: { } }",
+        "Syntax Error: Label 'a' has already been declared at position: 1:6",
+    );
+    check_parser(
+        "a: { } a: { }",
+        vec![Block::from(vec![]).into(), Block::from(vec![]).into()],
+    );
+}
@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Continue, Keyword, Punctuator},
@@ -61,7 +61,17 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ContinueStatement", "Parsing");
-        cursor.expect(Keyword::Continue, "continue statement")?;
+        let continue_start = cursor
+            .expect(Keyword::Continue, "continue statement")?
+            .span()
+            .start();
+
+        if cursor.options().has_early_errors() && !cursor.allow_continue() {
+            return Err(ParseError::lex(LexError::Syntax(
+                "Illegal continue statement outside of a loop".into(),
+                continue_start,
+            )));
+        }
 
         let label = if let SemicolonResult::Found(tok) = cursor.peek_semicolon()? {
             match tok {
@@ -73,7 +83,29 @@ where
 
             None
         } else {
+            let label_start = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if cursor.options().has_early_errors() {
+                match cursor.find_label(&label) {
+                    Some(true) => {}
+                    Some(false) => {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            format!(
+                                "Illegal continue statement: '{}' does not denote an iteration statement",
+                                label
+                            )
+                            .into(),
+                            label_start,
+                        )));
+                    }
+                    None => {
+                        return Err(ParseError::lex(LexError::Syntax(
+                            format!("Undefined label '{}'", label).into(),
+                            label_start,
+                        )));
+                    }
+                }
+            }
             cursor.expect_semicolon("continue statement")?;
 
             Some(label)
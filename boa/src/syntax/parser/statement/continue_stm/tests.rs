@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Continue, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid_message, check_parser},
 };
 
 #[test]
@@ -38,7 +38,7 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test
         }",
         vec![WhileLoop::new(
@@ -64,7 +64,7 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test;
         }",
         vec![WhileLoop::new(
@@ -78,7 +78,7 @@ fn new_line_block() {
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             continue await;
         }",
         vec![WhileLoop::new(
@@ -89,7 +89,7 @@ fn reserved_label() {
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             continue yield;
         }",
         vec![WhileLoop::new(
@@ -127,3 +127,46 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Checks that `continue` needs an enclosing loop, even when labelled.
+#[test]
+fn misplaced_continue() {
+    check_invalid_message(
+        "continue;",
+        "Syntax Error: Illegal continue statement outside of a loop at position: 1:1",
+    );
+    check_invalid_message(
+        "label: { continue label; }",
+        "Syntax Error: Illegal continue statement outside of a loop at position: 1:10",
+    );
+    check_invalid_message(
+        "switch (a) { case 1: continue; }",
+        "Syntax Error: Illegal continue statement outside of a loop at position: 1:22",
+    );
+    check_invalid_message(
+        "while (true) { function f() { continue; } }",
+        "Syntax Error: Illegal continue statement outside of a loop at position: 1:31",
+    );
+}
+
+/// Checks that a labelled `continue` needs an enclosing iteration statement with its label in the
+/// same function.
+#[test]
+fn continue_label_targets() {
+    check_invalid_message(
+        "while (true) { continue foo; }",
+        "Syntax Error: Undefined label 'foo' at position: 1:25",
+    );
+    check_invalid_message(
+        "l: for (;;) { (function () { while (true) continue l; })(); }",
+        "Syntax Error: Undefined label 'l' at position: 1:52",
+    );
+    check_invalid_message(
+        "a: { while (true) continue a; }",
+        "Syntax Error: Illegal continue statement: 'a' does not denote an iteration statement at position: 1:28",
+    );
+    check_parser(
+        "a: b: while (true) continue a;",
+        vec![WhileLoop::new(Const::from(true), Continue::new("a")).into()],
+    );
+}
@@ -7,7 +7,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{
@@ -156,7 +156,7 @@ where
                     .parse(cursor)?;
 
             if self.is_const {
-                if self.const_init_required {
+                if self.const_init_required && cursor.options().has_early_errors() {
                     if init.is_some() {
                         const_decls.push(ConstDecl::new(ident, init));
                     } else {
@@ -245,7 +245,14 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        if cursor.options().has_early_errors() && ident.as_ref() == "let" {
+            return Err(ParseError::lex(LexError::Syntax(
+                "let is disallowed as a lexically bound name".into(),
+                position,
+            )));
+        }
 
         let init = if let Some(t) = cursor.peek(0)? {
             if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/do...while
 //! [spec]: https://tc39.es/ecma262/#sec-do-while-statement
 
+use super::parse_loop_body;
 use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
@@ -65,8 +66,10 @@ where
         let _timer = BoaProfiler::global().start_event("DoWhileStatement", "Parsing");
         cursor.expect(Keyword::Do, "do while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = parse_loop_body(
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return),
+            cursor,
+        )?;
 
         let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
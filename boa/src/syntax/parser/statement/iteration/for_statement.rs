@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/for
 //! [spec]: https://tc39.es/ecma262/#sec-for-statement
 
+use super::parse_loop_body;
 use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
@@ -98,11 +99,29 @@ where
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
                 let _ = cursor.next();
+
+                let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                if tok.kind() == &TokenKind::Punctuator(Punctuator::CloseParen) {
+                    return Err(ParseError::general(
+                        "expected iterable expression after 'of'",
+                        tok.span().start(),
+                    ));
+                }
+
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+
+                match cursor.peek(0)? {
+                    Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) => {
+                        return Err(ParseError::general("unexpected 'of'", tok.span().start()));
+                    }
+                    _ => {}
+                }
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
-                let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
-                    .parse(cursor)?;
+                let body = parse_loop_body(
+                    Statement::new(self.allow_yield, self.allow_await, self.allow_return),
+                    cursor,
+                )?;
                 return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
             }
             _ => {}
@@ -129,8 +148,10 @@ where
             Some(step)
         };
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = parse_loop_body(
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return),
+            cursor,
+        )?;
 
         // TODO: do not encapsulate the `for` in a block just to have an inner scope.
         Ok(ForLoop::new(init, cond, step, body).into())
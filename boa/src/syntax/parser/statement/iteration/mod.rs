@@ -8,3 +8,27 @@ pub(super) use self::{
     do_while_statement::DoWhileStatement, for_statement::ForStatement,
     while_statement::WhileStatement,
 };
+
+use super::Statement;
+use crate::syntax::{
+    ast::Node,
+    parser::{Cursor, ParseError, TokenParser},
+};
+use std::io::Read;
+
+/// Parses the body of an iteration statement, in which `break` and `continue` are allowed.
+fn parse_loop_body<R>(body: Statement, cursor: &mut Cursor<R>) -> Result<Node, ParseError>
+where
+    R: Read,
+{
+    let allow_break = cursor.allow_break();
+    let allow_continue = cursor.allow_continue();
+    cursor.set_allow_break(true);
+    cursor.set_allow_continue(true);
+
+    let body = body.parse(cursor);
+
+    cursor.set_allow_break(allow_break);
+    cursor.set_allow_continue(allow_continue);
+    body
+}
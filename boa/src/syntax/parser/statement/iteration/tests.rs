@@ -7,7 +7,7 @@ use crate::syntax::{
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid_message, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -91,6 +91,22 @@ fn check_do_while_semicolon_insertion_no_space() {
     );
 }
 
+// Checks automatic semicolon insertion after a do-while with a single statement as its body.
+#[test]
+fn check_do_while_statement_body_semicolon_insertion() {
+    check_parser(
+        "do x(); while(c) y()",
+        vec![
+            DoWhileLoop::new(
+                Call::new(Identifier::from("x"), vec![]),
+                Identifier::from("c"),
+            )
+            .into(),
+            Call::new(Identifier::from("y"), vec![]).into(),
+        ],
+    );
+}
+
 /// Checks parsing of a while statement which is seperated out with line terminators.
 #[test]
 fn while_spaces() {
@@ -136,3 +152,21 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks that a for-of statement without an iterable is reported as such.
+#[test]
+fn check_for_of_missing_iterable() {
+    check_invalid_message(
+        "for (const x of) {}",
+        "expected iterable expression after 'of' at line 1, col 16",
+    );
+}
+
+/// Checks that a second `of` in a for-of statement is reported as such.
+#[test]
+fn check_for_of_extra_of() {
+    check_invalid_message(
+        "for (const x of y of z) {}",
+        "unexpected 'of' at line 1, col 19",
+    );
+}
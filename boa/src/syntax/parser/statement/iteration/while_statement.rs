@@ -1,3 +1,4 @@
+use super::parse_loop_body;
 use crate::{
     syntax::{
         ast::{node::WhileLoop, Keyword, Punctuator},
@@ -62,8 +63,10 @@ where
 
         cursor.expect(Punctuator::CloseParen, "while statement")?;
 
-        let body =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+        let body = parse_loop_body(
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return),
+            cursor,
+        )?;
 
         Ok(WhileLoop::new(cond, body))
     }
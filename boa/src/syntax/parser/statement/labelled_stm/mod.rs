@@ -4,7 +4,8 @@ use super::{LabelIdentifier, Statement};
 use crate::{
     syntax::ast::Node,
     syntax::{
-        ast::Punctuator,
+        ast::{Keyword, Punctuator},
+        lexer::{Token, TokenKind},
         parser::{
             cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
@@ -49,10 +50,29 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
         cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
+
+        let iteration = matches!(
+            cursor.peek(0)?.map(Token::kind),
+            Some(TokenKind::Keyword(Keyword::For))
+                | Some(TokenKind::Keyword(Keyword::While))
+                | Some(TokenKind::Keyword(Keyword::Do))
+        );
+        let chained = matches!(
+            cursor.peek(0)?.map(Token::kind),
+            Some(TokenKind::Identifier(_))
+        ) && matches!(
+            cursor.peek(1)?.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Colon))
+        );
+        cursor.push_label(name.clone(), iteration, chained, position)?;
+
+        let stmt =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor);
+        cursor.pop_label();
+        let mut stmt = stmt?;
 
         set_label_for_node(&mut stmt, name);
         Ok(stmt)
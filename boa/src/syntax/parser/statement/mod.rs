@@ -20,6 +20,7 @@ mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with;
 
 use self::{
     block::BlockStatement,
@@ -34,19 +35,21 @@ use self::{
     throw::ThrowStatement,
     try_stm::TryStatement,
     variable::VariableStatement,
+    with::WithStatement,
 };
 
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
 use crate::{
     syntax::{
-        ast::{node, Keyword, Node, Punctuator},
-        lexer::{Error as LexError, InputElement, TokenKind},
+        ast::{node, Keyword, Node, Position, Punctuator},
+        lexer::{Error as LexError, InputElement, Token, TokenKind},
         parser::expression::await_expr::AwaitExpression,
     },
     BoaProfiler,
 };
 use labelled_stm::LabelledStatement;
+use rustc_hash::FxHashMap;
 
 use std::io::Read;
 
@@ -108,12 +111,34 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Statement", "Parsing");
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
+
+        // `await` and `yield` are labels outside of async functions and generators.
+        if matches!(
+            cursor.peek(0)?.map(Token::kind),
+            Some(TokenKind::Keyword(Keyword::Await)) | Some(TokenKind::Keyword(Keyword::Yield))
+        ) {
+            cursor.set_goal(InputElement::Div);
+            if matches!(
+                cursor.peek(1)?.map(Token::kind),
+                Some(TokenKind::Punctuator(Punctuator::Colon))
+            ) {
+                return LabelledStatement::new(
+                    self.allow_yield,
+                    self.allow_await,
+                    self.allow_return,
+                )
+                .parse(cursor);
+            }
+        }
+
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -145,7 +170,10 @@ where
                         .parse(cursor)
                         .map(Node::from)
                 } else {
-                    Err(ParseError::unexpected(tok.clone(), "statement"))
+                    Err(ParseError::lex(LexError::Syntax(
+                        "Illegal return statement outside of a function".into(),
+                        tok.span().start(),
+                    )))
                 }
             }
             TokenKind::Keyword(Keyword::Break) => {
@@ -168,6 +196,11 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Switch) => {
                 SwitchStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -219,7 +252,6 @@ pub(super) struct StatementList {
     allow_await: AllowAwait,
     allow_return: AllowReturn,
     break_when_closingbraces: bool,
-    in_block: bool,
 }
 
 impl StatementList {
@@ -229,7 +261,6 @@ impl StatementList {
         allow_await: A,
         allow_return: R,
         break_when_closingbraces: bool,
-        in_block: bool,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -241,7 +272,6 @@ impl StatementList {
             allow_await: allow_await.into(),
             allow_return: allow_return.into(),
             break_when_closingbraces,
-            in_block,
         }
     }
 
@@ -265,6 +295,7 @@ impl StatementList {
         R: Read,
     {
         let mut items = Vec::new();
+        let mut declared_names = FxHashMap::default();
 
         loop {
             if let Some(token) = cursor.peek(0)? {
@@ -275,13 +306,13 @@ impl StatementList {
                 return Err(ParseError::AbruptEnd);
             }
 
-            let item = StatementListItem::new(
-                self.allow_yield,
-                self.allow_await,
-                self.allow_return,
-                self.in_block,
-            )
-            .parse(cursor)?;
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let item =
+                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
+            if cursor.options().has_early_errors() {
+                declare_names(&item, &mut declared_names, position)?;
+            }
 
             items.push(item);
 
@@ -304,6 +335,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut declared_names = FxHashMap::default();
 
         loop {
             match cursor.peek(0)? {
@@ -324,13 +356,13 @@ where
                 _ => {}
             }
 
-            let item = StatementListItem::new(
-                self.allow_yield,
-                self.allow_await,
-                self.allow_return,
-                self.in_block,
-            )
-            .parse(cursor)?;
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let item =
+                StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)?;
+            if cursor.options().has_early_errors() {
+                declare_names(&item, &mut declared_names, position)?;
+            }
             items.push(item);
 
             // move the cursor forward for any consecutive semicolon.
@@ -343,6 +375,109 @@ where
     }
 }
 
+/// Records the names declared by a statement list item, checking for redeclarations.
+///
+/// `declared_names` maps every name declared so far in the statement list to whether it was
+/// lexically declared. A lexical declaration may not share its name with any other declaration,
+/// while `var` and function declarations may only be repeated among themselves. The `var`
+/// declarations of nested statements count too, as they are scoped to the enclosing function.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+fn declare_names(
+    item: &Node,
+    declared_names: &mut FxHashMap<Box<str>, bool>,
+    position: Position,
+) -> Result<(), ParseError> {
+    let (names, lexical): (Vec<&str>, bool) = match item {
+        Node::LetDeclList(list) => (list.as_ref().iter().map(|d| d.name()).collect(), true),
+        Node::ConstDeclList(list) => (list.as_ref().iter().map(|d| d.name()).collect(), true),
+        Node::FunctionDecl(decl) => (vec![decl.name()], false),
+        Node::AsyncFunctionDecl(decl) => (decl.name().into_iter().collect(), false),
+        _ => {
+            let mut names = Vec::new();
+            var_declared_names(item, &mut names);
+            (names, false)
+        }
+    };
+
+    for name in names {
+        match declared_names.get(name) {
+            Some(&declared_lexical) if lexical || declared_lexical => {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Identifier '{}' has already been declared", name).into(),
+                    position,
+                )));
+            }
+            Some(_) => {}
+            None => {
+                declared_names.insert(name.into(), lexical);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects the names of the `var` declarations of a statement and of the statements nested in
+/// it, without entering functions.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-vardeclarednames
+fn var_declared_names<'a>(node: &'a Node, names: &mut Vec<&'a str>) {
+    let statements = |list: &'a [Node], names: &mut Vec<&'a str>| {
+        for node in list {
+            var_declared_names(node, names);
+        }
+    };
+
+    match node {
+        Node::VarDeclList(list) => names.extend(list.as_ref().iter().map(|d| d.name())),
+        Node::Block(block) => statements(block.statements(), names),
+        Node::If(node) => {
+            var_declared_names(node.body(), names);
+            if let Some(else_node) = node.else_node() {
+                var_declared_names(else_node, names);
+            }
+        }
+        Node::WhileLoop(node) => var_declared_names(node.expr(), names),
+        Node::DoWhileLoop(node) => var_declared_names(node.body(), names),
+        Node::ForLoop(node) => {
+            if let Some(init) = node.init() {
+                var_declared_names(init, names);
+            }
+            var_declared_names(node.body(), names);
+        }
+        Node::ForOfLoop(node) => {
+            var_declared_names(node.variable(), names);
+            var_declared_names(node.body(), names);
+        }
+        Node::Try(node) => {
+            statements(node.block().statements(), names);
+            if let Some(catch) = node.catch() {
+                statements(catch.block().statements(), names);
+            }
+            if let Some(finally) = node.finally() {
+                statements(finally.statements(), names);
+            }
+        }
+        Node::Switch(node) => {
+            for case in node.cases() {
+                statements(case.body().statements(), names);
+            }
+            if let Some(default) = node.default() {
+                statements(default, names);
+            }
+        }
+        Node::With(node) => var_declared_names(node.body(), names),
+        _ => {}
+    }
+}
+
 /// Statement list item parsing
 ///
 /// A statement list item can either be an statement or a declaration.
@@ -354,16 +489,15 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(super) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
-    in_block: bool,
 }
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(super) fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -373,7 +507,6 @@ impl StatementListItem {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             allow_return: allow_return.into(),
-            in_block,
         }
     }
 }
@@ -386,20 +519,13 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementListItem", "Parsing");
-        let strict_mode = cursor.strict_mode();
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
-            TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
-                if strict_mode && self.in_block {
-                    return Err(ParseError::lex(LexError::Syntax(
-                        "Function declaration in blocks not allowed in strict mode".into(),
-                        tok.span().start(),
-                    )));
-                }
-                Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
-            }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -458,9 +584,20 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            // `eval` and `arguments` can be referenced in strict mode, but not bound.
+            TokenKind::Identifier(ref s)
+                if cursor.options().has_early_errors()
+                    && cursor.strict_mode()
+                    && (s.as_ref() == "eval" || s.as_ref() == "arguments") =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!("binding '{}' not allowed in strict mode", s).into(),
+                    next_token.span().start(),
+                )))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
-                if cursor.strict_mode() {
+                if cursor.options().has_early_errors() && cursor.strict_mode() {
                     Err(ParseError::lex(LexError::Syntax(
                         "yield keyword in binding identifier not allowed in strict mode".into(),
                         next_token.span().start(),
@@ -469,8 +606,19 @@ where
                     Ok(k.as_str().into())
                 }
             }
+            // `let` is only reserved in strict mode code.
+            TokenKind::Keyword(k @ Keyword::Let) => {
+                if cursor.options().has_early_errors() && cursor.strict_mode() {
+                    Err(ParseError::lex(LexError::Syntax(
+                        "let keyword in binding identifier not allowed in strict mode".into(),
+                        next_token.span().start(),
+                    )))
+                } else {
+                    Ok(k.as_str().into())
+                }
+            }
             TokenKind::Keyword(k @ Keyword::Await) if !self.allow_await.0 => {
-                if cursor.strict_mode() {
+                if cursor.options().has_early_errors() && cursor.strict_mode() {
                     Err(ParseError::lex(LexError::Syntax(
                         "await keyword in binding identifier not allowed in strict mode".into(),
                         next_token.span().start(),
@@ -1 +1,40 @@
+use crate::syntax::{
+    ast::{
+        node::{FunctionDecl, Node, Return},
+        Const,
+    },
+    parser::tests::{check_invalid_message, check_parser},
+};
 
+/// Checks that a line terminator after `return` ends the statement.
+#[test]
+fn check_return_line_terminator_asi() {
+    check_parser(
+        "function f() {
+            return
+            5
+        }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![
+                Return::new::<Node, Option<_>, Option<_>>(None, None).into(),
+                Const::from(5).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// Checks that `return` is only allowed inside functions.
+#[test]
+fn check_return_outside_function() {
+    check_invalid_message(
+        "return 1;",
+        "Syntax Error: Illegal return statement outside of a function at position: 1:1",
+    );
+    check_invalid_message(
+        "while (true) { return; }",
+        "Syntax Error: Illegal return statement outside of a function at position: 1:16",
+    );
+}
@@ -68,10 +68,22 @@ where
 
         cursor.expect(Punctuator::CloseParen, "switch statement")?;
 
-        let (cases, default) =
-            CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
-
-        Ok(Switch::new(condition, cases, default))
+        // An unlabelled `break` statement is allowed inside the case block.
+        let allow_break = cursor.allow_break();
+        cursor.set_allow_break(true);
+        let case_block =
+            CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor);
+        cursor.set_allow_break(allow_break);
+        let (cases, default) = case_block?;
+
+        Ok(match default {
+            Some((position, default)) => {
+                let mut switch = Switch::new(condition, cases, Some(default));
+                switch.set_default_position(position);
+                switch
+            }
+            None => Switch::new::<_, _, node::StatementList>(condition, cases, None),
+        })
     }
 }
 
@@ -108,7 +120,8 @@ impl<R> TokenParser<R> for CaseBlock
 where
     R: Read,
 {
-    type Output = (Box<[node::Case]>, Option<node::StatementList>);
+    /// The cases, and the default clause with the number of cases that come before it.
+    type Output = (Box<[node::Case]>, Option<(usize, node::StatementList)>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         cursor.expect(Punctuator::OpenBlock, "switch case block")?;
@@ -125,14 +138,14 @@ where
 
                     cursor.expect(Punctuator::Colon, "switch case block")?;
 
-                    let statement_list = StatementList::new(
+                    let mut statement_list = StatementList::new(
                         self.allow_yield,
                         self.allow_await,
                         self.allow_return,
                         true,
-                        false,
                     )
                     .parse_generalised(cursor, &CASE_BREAK_TOKENS)?;
+                    statement_list.set_strict(cursor.strict_mode());
 
                     cases.push(node::Case::new(cond, statement_list));
                 }
@@ -147,16 +160,16 @@ where
 
                     cursor.expect(Punctuator::Colon, "switch default block")?;
 
-                    let statement_list = StatementList::new(
+                    let mut statement_list = StatementList::new(
                         self.allow_yield,
                         self.allow_await,
                         self.allow_return,
                         true,
-                        false,
                     )
                     .parse_generalised(cursor, &CASE_BREAK_TOKENS)?;
+                    statement_list.set_strict(cursor.strict_mode());
 
-                    default = Some(statement_list);
+                    default = Some((cases.len(), statement_list));
                 }
                 Some(token) if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     break
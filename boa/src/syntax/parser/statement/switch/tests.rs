@@ -185,3 +185,22 @@ fn check_seperated_switch() {
         ],
     );
 }
+
+/// Checks that the position of a default clause between cases is kept.
+#[test]
+fn check_switch_default_between_cases() {
+    let mut switch = Switch::new(
+        Identifier::from("a"),
+        vec![
+            Case::new(Const::from(1), vec![Identifier::from("b").into()]),
+            Case::new(Const::from(2), vec![Identifier::from("d").into()]),
+        ],
+        Some(vec![Identifier::from("c").into()]),
+    );
+    switch.set_default_position(1);
+
+    check_parser(
+        "switch (a) { case 1: b; default: c; case 2: d; }",
+        vec![switch.into()],
+    );
+}
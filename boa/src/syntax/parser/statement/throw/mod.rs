@@ -50,7 +50,7 @@ where
         let _timer = BoaProfiler::global().start_event("ThrowStatement", "Parsing");
         cursor.expect(Keyword::Throw, "throw statement")?;
 
-        cursor.peek_expect_no_lineterminator(0, "throw statement")?;
+        cursor.expect_no_line_terminator("throw statement")?;
 
         let expr = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.peek(0)? {
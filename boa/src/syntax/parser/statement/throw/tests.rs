@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{node::Throw, Const},
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -10,3 +10,12 @@ fn check_throw_parsing() {
         vec![Throw::new(Const::from("error")).into()],
     );
 }
+
+/// Checks that a line terminator between `throw` and its expression is an error.
+#[test]
+fn check_throw_line_terminator() {
+    check_invalid(
+        "throw
+        'error';",
+    );
+}
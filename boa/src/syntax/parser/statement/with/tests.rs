@@ -0,0 +1,37 @@
+use crate::syntax::{
+    ast::node::{Block, GetConstField, Identifier, With},
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_with_parsing() {
+    check_parser(
+        "with (obj) { x; }",
+        vec![With::new(
+            Identifier::from("obj"),
+            Block::from(vec![Identifier::from("x").into()]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_single_statement_body() {
+    check_parser(
+        "with (a.b) c;",
+        vec![With::new(
+            GetConstField::new(Identifier::from("a"), "b"),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+}
+
+/// Checks that a `with` statement is rejected in strict mode code.
+#[test]
+fn check_with_strict_mode() {
+    check_invalid(
+        "'use strict';
+        with (obj) { x; }",
+    );
+}
@@ -1,14 +1,17 @@
 //! Tests for the parser.
 
-use super::Parser;
-use crate::syntax::ast::{
-    node::{
-        field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, FormalParameter,
-        FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return, StatementList, UnaryOp,
-        VarDecl, VarDeclList,
+use super::{MessageFormat, ParseError, Parser, ParserOptions};
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, ArrowFunctionDecl, Assign, BinOp, Call, ConditionalOp,
+            FormalParameter, FunctionDecl, Identifier, LetDecl, LetDeclList, New, Node, Return,
+            Spread, StatementList, UnaryOp, VarDecl, VarDeclList,
+        },
+        op::{self, CompOp, LogOp, NumOp},
+        Const, Position, Span,
     },
-    op::{self, CompOp, LogOp, NumOp},
-    Const,
+    diagnostic::{Diagnostic, DiagnosticKind},
 };
 
 /// Checks that the given JavaScript string gives the expected expression.
@@ -32,6 +35,15 @@ pub(super) fn check_invalid(js: &str) {
     assert!(Parser::new(js.as_bytes(), false).parse_all().is_err());
 }
 
+/// Checks that the given javascript string creates a parse error with the given message.
+#[track_caller]
+pub(super) fn check_invalid_message(js: &str, message: &str) {
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert_eq!(error.to_string(), message);
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
@@ -47,6 +59,112 @@ fn check_construct_call_precedence() {
     );
 }
 
+/// Checks that the arguments of `new` can be left out.
+#[test]
+fn check_construct_without_arguments() {
+    check_parser(
+        "new Foo",
+        vec![New::from(Call::new(Identifier::from("Foo"), vec![])).into()],
+    );
+    check_parser(
+        "new Foo.Bar",
+        vec![New::from(Call::new(
+            GetConstField::new(Identifier::from("Foo"), "Bar"),
+            vec![],
+        ))
+        .into()],
+    );
+    check_parser(
+        "new Foo.Bar()",
+        vec![New::from(Call::new(
+            GetConstField::new(Identifier::from("Foo"), "Bar"),
+            vec![],
+        ))
+        .into()],
+    );
+    check_parser(
+        "new new Foo",
+        vec![New::from(Call::new(
+            New::from(Call::new(Identifier::from("Foo"), vec![])),
+            vec![],
+        ))
+        .into()],
+    );
+}
+
+/// Checks the constructor and argument list of nested and member `new` expressions.
+#[test]
+fn check_construct_member_precedence() {
+    check_parser(
+        "new Foo(a, b)",
+        vec![New::from(Call::new(
+            Identifier::from("Foo"),
+            vec![Identifier::from("a").into(), Identifier::from("b").into()],
+        ))
+        .into()],
+    );
+    check_parser(
+        "new a.b.C()",
+        vec![New::from(Call::new(
+            GetConstField::new(GetConstField::new(Identifier::from("a"), "b"), "C"),
+            vec![],
+        ))
+        .into()],
+    );
+    check_parser(
+        "new new F()()",
+        vec![New::from(Call::new(
+            New::from(Call::new(Identifier::from("F"), vec![])),
+            vec![],
+        ))
+        .into()],
+    );
+    check_parser(
+        "new Foo().bar",
+        vec![
+            GetConstField::new(New::from(Call::new(Identifier::from("Foo"), vec![])), "bar").into(),
+        ],
+    );
+}
+
+/// Checks that each argument is a full assignment expression, with spreads in between.
+#[test]
+fn check_call_mixed_arguments() {
+    check_parser(
+        "f(a = 1, ...b, c ? d : e)",
+        vec![Call::new(
+            Identifier::from("f"),
+            vec![
+                Assign::new(Identifier::from("a"), Const::from(1)).into(),
+                Spread::new(Identifier::from("b")).into(),
+                ConditionalOp::new(
+                    Identifier::from("c"),
+                    Identifier::from("d"),
+                    Identifier::from("e"),
+                )
+                .into(),
+            ],
+        )
+        .into()],
+    );
+    check_parser(
+        "f(...a, b = c = 2, ...d)",
+        vec![Call::new(
+            Identifier::from("f"),
+            vec![
+                Spread::new(Identifier::from("a")).into(),
+                Assign::new(
+                    Identifier::from("b"),
+                    Assign::new(Identifier::from("c"), Const::from(2)),
+                )
+                .into(),
+                Spread::new(Identifier::from("d")).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
 #[test]
 fn assign_operator_precedence() {
     check_parser(
@@ -292,3 +410,471 @@ fn spread_in_arrow_function() {
         ],
     );
 }
+
+/// Checks that a rendered parse error points at the offending token with a code frame.
+#[test]
+fn render_error_code_frame() {
+    let js = "let a = 1;\nlet b = );";
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+
+    let rendered = error.render(js);
+    let frame: Vec<&str> = rendered.lines().skip(1).collect();
+    assert_eq!(
+        frame,
+        [" --> 2:9", "  |", "2 | let b = );", "  |         ^"]
+    );
+}
+
+/// Checks that an error with an empty span is rendered with a single caret, even past the end of
+/// the line.
+#[test]
+fn render_error_empty_span() {
+    let error = ParseError::general("missing semicolon", Position::new(1, 10));
+
+    let rendered = error.render("let a = 1\nlet b = 2;");
+    let frame: Vec<&str> = rendered.lines().skip(1).collect();
+    assert_eq!(
+        frame,
+        [" --> 1:10", "  |", "1 | let a = 1", "  |          ^"]
+    );
+}
+
+/// Checks the V8 style messages of common parsing errors.
+#[test]
+fn error_message_v8_format() {
+    let v8_message = |js: &str| {
+        Parser::new(js.as_bytes(), false)
+            .parse_all()
+            .expect_err("parsing should fail")
+            .message(MessageFormat::V8)
+    };
+
+    assert_eq!(v8_message(")"), "Unexpected token ')'");
+    assert_eq!(v8_message("var if;"), "Unexpected token 'if'");
+    assert_eq!(v8_message("a b"), "Unexpected identifier 'b'");
+    assert_eq!(v8_message("x = 5 6"), "Unexpected number");
+    assert_eq!(v8_message("x = 5 'a'"), "Unexpected string");
+    assert_eq!(v8_message("if ("), "Unexpected end of input");
+    assert_eq!(v8_message("var x = \"abc"), "Invalid or unexpected token");
+    assert_eq!(v8_message("a = @"), "Invalid or unexpected token");
+    assert_eq!(v8_message("1 = 2"), "Invalid left-hand side in assignment");
+    assert_eq!(v8_message("`abc"), "Unterminated template literal");
+    assert_eq!(
+        v8_message("'use strict'; 010"),
+        "Octal literals are not allowed in strict mode."
+    );
+    assert_eq!(
+        v8_message("'use strict'; '\\01'"),
+        "Octal escape sequences are not allowed in strict mode."
+    );
+    assert_eq!(
+        v8_message("'use strict'; '\\8'"),
+        "\\8 and \\9 are not allowed in strict mode."
+    );
+
+    // The Boa format is the same as the `Display` implementation.
+    let error = Parser::new(&b")"[..], false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert_eq!(error.message(MessageFormat::Boa), error.to_string());
+}
+
+/// Checks that redeclaring a lexical binding is an early error.
+#[test]
+fn check_lexical_redeclaration() {
+    check_invalid("let a; let a;");
+    check_invalid("const a = 1; let a;");
+    check_invalid("var a; let a;");
+    check_invalid("let a; var a;");
+    check_invalid("{ let a; const a = 1; }");
+    check_invalid("function f() { let a; let a; }");
+    check_invalid("let a; { var a; }");
+    check_invalid("{ var a; } let a;");
+    check_invalid("let a; if (true) { for (var a of []) {} }");
+    check_invalid("let a; function a() {}");
+
+    check_parser(
+        "var a; var a;",
+        vec![
+            VarDeclList::from(vec![VarDecl::new("a", None)]).into(),
+            VarDeclList::from(vec![VarDecl::new("a", None)]).into(),
+        ],
+    );
+    assert!(Parser::new(&b"let a; function f() { var a; }"[..], false)
+        .parse_all()
+        .is_ok());
+}
+
+/// Checks that early errors can be disabled, only leaving the grammar checks.
+#[test]
+fn check_early_errors_disabled() {
+    let js = "let a; let a;";
+
+    let mut parser = Parser::new(js.as_bytes(), false);
+    parser.set_options(ParserOptions::default().early_errors(false));
+    assert_eq!(
+        parser.parse_all().expect("failed to parse"),
+        StatementList::from(vec![
+            LetDeclList::from(vec![LetDecl::new("a", None)]).into(),
+            LetDeclList::from(vec![LetDecl::new("a", None)]).into(),
+        ])
+    );
+
+    for js in &[
+        "'use strict'; function f(a, a) {}",
+        "'use strict'; eval = 1;",
+        "'use strict'; arguments++;",
+        "'use strict'; var let = 1;",
+        "let let = 1;",
+        "'use strict'; delete a;",
+        "'use strict'; 010;",
+        "break;",
+        "continue;",
+        "while (true) { break foo; }",
+        "a: { a: { } }",
+        "1 = 2;",
+        "const a;",
+    ] {
+        let mut parser = Parser::new(js.as_bytes(), false);
+        parser.set_options(ParserOptions::default().early_errors(false));
+        assert!(parser.parse_all().is_ok(), "{} should parse", js);
+        assert!(Parser::new(js.as_bytes(), false).parse_all().is_err());
+    }
+
+    // Syntax errors are still reported.
+    let mut parser = Parser::new(&b"let a = ;"[..], false);
+    parser.set_options(ParserOptions::default().early_errors(false));
+    assert!(parser.parse_all().is_err());
+
+    // Early errors are enabled by default.
+    assert!(Parser::new(js.as_bytes(), false).parse_all().is_err());
+}
+
+/// Checks that `let` can be bound by `var` declarations in sloppy mode code only.
+#[test]
+fn check_let_binding_identifier() {
+    assert!(Parser::new(&b"var let = 1;"[..], false).parse_all().is_ok());
+    check_invalid("'use strict'; var let = 1;");
+    check_invalid("let let = 1;");
+    check_invalid("const let = 1;");
+}
+
+/// Checks that `eval` and `arguments` can be referenced, but not bound, in strict mode code.
+#[test]
+fn check_strict_eval_arguments_bindings() {
+    assert!(Parser::new(&b"arguments.length; eval;"[..], true)
+        .parse_all()
+        .is_ok());
+
+    check_invalid_message(
+        "'use strict'; var arguments;",
+        "Syntax Error: binding 'arguments' not allowed in strict mode at position: 1:19",
+    );
+    check_invalid_message(
+        "'use strict'; (function (eval) {});",
+        "Syntax Error: binding 'eval' not allowed in strict mode at position: 1:26",
+    );
+}
+
+/// Checks that `eval` and `arguments` can only be assigned to in sloppy mode code.
+#[test]
+fn check_strict_eval_arguments_assignment() {
+    for js in &[
+        "eval = 1;",
+        "arguments += 1;",
+        "arguments++;",
+        "--eval;",
+        "(function (eval, arguments) {});",
+    ] {
+        assert!(
+            Parser::new(js.as_bytes(), false).parse_all().is_ok(),
+            "{} should parse in sloppy mode",
+            js
+        );
+    }
+
+    check_invalid_message(
+        "'use strict'; eval = 1;",
+        "Syntax Error: assignment to 'eval' not allowed in strict mode at position: 1:20",
+    );
+    check_invalid_message(
+        "'use strict'; arguments += 1;",
+        "Syntax Error: assignment to 'arguments' not allowed in strict mode at position: 1:25",
+    );
+    check_invalid_message(
+        "'use strict'; arguments++;",
+        "Syntax Error: assignment to 'arguments' not allowed in strict mode at position: 1:24",
+    );
+    check_invalid_message(
+        "'use strict'; --eval;",
+        "Syntax Error: assignment to 'eval' not allowed in strict mode at position: 1:18",
+    );
+    check_invalid_message(
+        "(function () { 'use strict'; eval = 1; });",
+        "Syntax Error: assignment to 'eval' not allowed in strict mode at position: 1:35",
+    );
+    check_invalid_message(
+        "'use strict'; (function (a, arguments) {});",
+        "Syntax Error: binding 'arguments' not allowed in strict mode at position: 1:29",
+    );
+
+    // Properties named `eval` or `arguments` are not affected.
+    assert!(
+        Parser::new(&b"'use strict'; a.eval = 1; a.arguments++;"[..], false)
+            .parse_all()
+            .is_ok()
+    );
+}
+
+/// Checks that legacy octal literals and escapes are only allowed in sloppy mode code.
+#[test]
+fn check_strict_legacy_octal() {
+    check_parser("0755;", vec![Const::from(493).into()]);
+    check_parser("\"\\07\";", vec![Const::from("\u{7}").into()]);
+    check_parser("\"\\0\";", vec![Const::from("\0").into()]);
+
+    check_invalid_message(
+        "'use strict'; 0755;",
+        "Syntax Error: implicit octal literals are not allowed in strict mode at position: 1:15",
+    );
+    check_invalid_message(
+        "'use strict'; \"\\07\";",
+        "Syntax Error: octal escape sequences are not allowed in strict mode at position: 1:16",
+    );
+    check_invalid_message(
+        "'use strict'; \"\\8\";",
+        "Syntax Error: \\8 and \\9 are not allowed in strict mode at position: 1:16",
+    );
+    let mut expected = StatementList::from(vec![
+        Const::from("use strict").into(),
+        Const::from("\0").into(),
+    ]);
+    expected.set_strict(true);
+    assert_eq!(
+        Parser::new(&b"'use strict'; \"\\0\";"[..], false)
+            .parse_all()
+            .expect("failed to parse"),
+        expected
+    );
+}
+
+/// Parses the given code with deprecation diagnostics enabled, returning the diagnostics.
+#[track_caller]
+fn deprecation_diagnostics(js: &str) -> Vec<Diagnostic> {
+    let mut parser = Parser::new(js.as_bytes(), false);
+    parser.set_options(ParserOptions::default().deprecation_diagnostics(true));
+    parser.parse_all().expect("failed to parse");
+    parser.take_diagnostics()
+}
+
+/// Checks that `with` statements are reported when deprecation diagnostics are enabled.
+#[test]
+fn check_deprecation_with_statement() {
+    let diagnostics = deprecation_diagnostics("var o = {};\nwith (o) {}");
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::new(
+            DiagnosticKind::WithStatement,
+            Span::new(Position::new(2, 1), Position::new(2, 5)),
+        )]
+    );
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "'with' statements are deprecated at line 2, col 1"
+    );
+}
+
+/// Checks that legacy octal literals and escapes are reported when deprecation diagnostics are
+/// enabled, in source order.
+#[test]
+fn check_deprecation_legacy_octal() {
+    let diagnostics =
+        deprecation_diagnostics("var a = 0755;\nvar b = \"\\07\";\nvar c = 0o755 + 0;");
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic::new(
+                DiagnosticKind::LegacyOctalLiteral,
+                Span::new(Position::new(1, 9), Position::new(1, 13)),
+            ),
+            Diagnostic::new(
+                DiagnosticKind::LegacyOctalEscape,
+                Span::new(Position::new(2, 10), Position::new(2, 13)),
+            ),
+        ]
+    );
+}
+
+/// Checks that accesses to `arguments.caller` are reported when deprecation diagnostics are
+/// enabled.
+#[test]
+fn check_deprecation_arguments_caller() {
+    let kinds: Vec<_> =
+        deprecation_diagnostics("function f() { return arguments.caller; } o.caller;")
+            .iter()
+            .map(Diagnostic::kind)
+            .collect();
+    assert_eq!(kinds, vec![DiagnosticKind::ArgumentsCaller]);
+}
+
+/// Checks that deprecation diagnostics are disabled by default.
+#[test]
+fn check_deprecation_disabled_by_default() {
+    let mut parser = Parser::new(&b"with (o) { 0755; }"[..], false);
+    parser.parse_all().expect("failed to parse");
+    assert!(parser.take_diagnostics().is_empty());
+}
+
+/// Checks that parsing a single statement leaves the rest of the input to the parser.
+#[test]
+fn parse_statement_leaves_trailing_input() {
+    let mut parser = Parser::new("a; b;".as_bytes(), false);
+
+    let (first, more) = parser.parse_statement().expect("failed to parse");
+    assert_eq!(first, Node::from(Identifier::from("a")));
+    assert!(more);
+
+    assert_eq!(
+        parser.parse_all().expect("failed to parse"),
+        StatementList::from(vec![Identifier::from("b").into()])
+    );
+}
+
+/// Checks that parsing the last statement reports that there is no more input.
+#[test]
+fn parse_statement_reports_end_of_input() {
+    let mut parser = Parser::new("let x = 1;".as_bytes(), false);
+
+    let (_, more) = parser.parse_statement().expect("failed to parse");
+    assert!(!more);
+}
+
+/// Checks which statement lists report lexical declarations.
+#[test]
+fn check_has_lexical_declarations() {
+    let parse = |js: &str| {
+        Parser::new(js.as_bytes(), false)
+            .parse_all()
+            .expect("failed to parse")
+    };
+
+    assert!(parse("let x;").has_lexical_declarations());
+    assert!(parse("var y; const x = 1;").has_lexical_declarations());
+    assert!(parse("function f() {}").has_lexical_declarations());
+
+    assert!(!parse("var x;").has_lexical_declarations());
+    assert!(!parse("x = 1;").has_lexical_declarations());
+    // Declarations in nested blocks belong to the scope of that block.
+    assert!(!parse("var x; { let y; }").has_lexical_declarations());
+}
+
+/// Checks that printing the parsed code gives source code that parses to the same tree.
+fn check_round_trip(js: &str) {
+    let parse = |js: &str| {
+        Parser::new(js.as_bytes(), false)
+            .parse_all()
+            .expect("failed to parse")
+    };
+
+    let ast = parse(js);
+    let printed = ast.to_string();
+    assert_eq!(parse(&printed), ast, "printed source:\n{}", printed);
+}
+
+#[test]
+fn round_trip_object_literal() {
+    let js = "var o = { a: 1, \"b-c\": \"x\\\"y\\n\", get g() { return this.a; }, \
+              set g(v) { this.a = v; }, m(x, y) { return x + y; }, ...rest, nested: { } };";
+    check_round_trip(js);
+
+    let ast = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse");
+    assert_eq!(
+        ast.to_string(),
+        "var o = {\n    a: 1,\n    \"b-c\": \"x\\\"y\\n\",\n    get g() {\n        return this.a;\n    },\n    \
+         set g(v) {\n        this.a = v;\n    },\n    m(x, y) {\n        return x + y;\n    },\n    \
+         ...rest,\n    nested: {},\n};\n"
+    );
+}
+
+#[test]
+fn round_trip_calls_and_functions() {
+    check_round_trip("a.b(c, d(e, f(g)))[h].i(...j, k);");
+    check_round_trip("new (foo())(1); new a.b(c);");
+    check_round_trip("(function () {})(); ({}).toString();");
+    check_round_trip(
+        "let f = function named(a, b) { if (a) { return (a + 1) * 2; } else return b; };",
+    );
+    check_round_trip("const g = (x) => x + 1; (y => y)(1);");
+    check_round_trip("async function af() { await x; } (async function () {})();");
+}
+
+#[test]
+fn round_trip_operators() {
+    check_round_trip("(a + 1) * 2; a - (b - c); a - b - c; (a = 1) + 2; a = b = c;");
+    check_round_trip("x++; --y; typeof z; !a; -(-b); delete a.b; (-1) ** 2;");
+    check_round_trip("(a ? b : c) ? d : e; a ? b : c ? d : e; x = (1, 2);");
+    check_round_trip("var s = \"a\\u2028b\\x01\\\\\"; 10n; 1.5;");
+}
+
+#[test]
+fn round_trip_statements() {
+    check_round_trip("for (let i = 0; i < 10; i++) { while (i) { i--; } }");
+    check_round_trip("do { x = x - 1; } while (x > 0); label: for (;;) { break label; }");
+    check_round_trip("switch (x) { case 1: y = 2; break; default: y = 3; }");
+    check_round_trip("try { throw new Error(\"e\"); } catch (e) { e; } finally { f(); }");
+    check_round_trip("for (var k of [1, 2, 3]) k; if (a) {} else if (b) {} else {}");
+}
+
+#[test]
+fn round_trip_templates() {
+    check_round_trip("`a${b}c`; `x\\`y\\${z}\\\\`; `${`nested ${1}`}`;");
+    check_round_trip("tag`a\\n${b}\\unicode`; a.b`c`; f()`d`;");
+}
+
+#[test]
+fn to_indented_string_indents_nested_lines() {
+    let ast = Parser::new("if (a) { b; }".as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse");
+
+    assert_eq!(
+        ast.statements()[0].to_indented_string(1),
+        "if (a) {\n        b;\n    }"
+    );
+}
+
+/// Checks the limit on the nesting depth of object and array literals.
+#[test]
+fn check_max_literal_depth() {
+    let parse = |js: &str, max_depth| {
+        let mut parser = Parser::new(js.as_bytes(), false);
+        parser.set_options(ParserOptions::default().max_literal_depth(max_depth));
+        parser.parse_all()
+    };
+
+    let nested_arrays = format!("{}1{};", "[".repeat(100), "]".repeat(100));
+    assert!(parse(&nested_arrays, Some(100)).is_ok());
+
+    let error = parse(&nested_arrays, Some(99)).expect_err("parsing should fail");
+    assert_eq!(
+        error.to_string(),
+        "object or array literal nested too deeply at line 1, col 100"
+    );
+
+    // Objects and arrays share the same depth.
+    assert!(parse("({ a: [{ b: [] }] });", Some(4)).is_ok());
+    assert!(parse("({ a: [{ b: [] }] });", Some(3)).is_err());
+
+    // Sibling literals don't add up.
+    assert!(parse("[[1], [2], { a: 3 }];", Some(2)).is_ok());
+
+    // There is no limit by default.
+    let nested_arrays = format!("{}{};", "[".repeat(150), "]".repeat(150));
+    assert!(Parser::new(nested_arrays.as_bytes(), false)
+        .parse_all()
+        .is_ok());
+}
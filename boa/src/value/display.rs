@@ -48,16 +48,25 @@ macro_rules! print_obj_value {
     };
     (props of $obj:expr, $display_fn:ident, $indent:expr, $encounters:expr, $print_internals:expr) => {
         print_obj_value!(impl $obj, |(key, val)| {
-            let v = &val
-                // FIXME: handle accessor descriptors
-                .as_data_descriptor()
-                .unwrap()
-                .value();
+            let v = match val {
+                PropertyDescriptor::Data(data) => {
+                    $display_fn(&data.value(), $encounters, $indent.wrapping_add(4), $print_internals)
+                }
+                PropertyDescriptor::Accessor(accessor) => {
+                    match (accessor.getter().is_some(), accessor.setter().is_some()) {
+                        (true, true) => "[Getter/Setter]",
+                        (true, false) => "[Getter]",
+                        (false, true) => "[Setter]",
+                        (false, false) => "undefined",
+                    }
+                    .to_string()
+                }
+            };
 
             format!(
                 "{:>width$}: {}",
                 key,
-                $display_fn(v, $encounters, $indent.wrapping_add(4), $print_internals),
+                v,
                 width = $indent,
             )
         })
@@ -108,24 +117,35 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                             return String::from("[]");
                         }
 
-                        let arr = (0..len)
-                            .map(|i| {
-                                // Introduce recursive call to stringify any objects
-                                // which are part of the Array
-                                log_string_from(
-                                    &v.get_own_property(&i.into())
-                                        // TODO: do this in a better way "unwrap"
-                                        .unwrap()
+                        let mut elements = Vec::new();
+                        let mut holes = 0;
+                        for i in 0..len {
+                            match v.get_own_property(&i.into()) {
+                                Some(property) => {
+                                    if holes > 0 {
+                                        elements.push(display_holes(holes));
+                                        holes = 0;
+                                    }
+                                    // Introduce recursive call to stringify any objects
+                                    // which are part of the Array
+                                    elements.push(log_string_from(
                                         // FIXME: handle accessor descriptors
-                                        .as_data_descriptor()
-                                        .unwrap()
-                                        .value(),
-                                    print_internals,
-                                    false,
-                                )
-                            })
-                            .collect::<Vec<String>>()
-                            .join(", ");
+                                        &property
+                                            .as_data_descriptor()
+                                            .map(|property| property.value())
+                                            .unwrap_or_default(),
+                                        print_internals,
+                                        false,
+                                    ));
+                                }
+                                // Missing elements are shown as holes.
+                                None => holes += 1,
+                            }
+                        }
+                        if holes > 0 {
+                            elements.push(display_holes(holes));
+                        }
+                        let arr = elements.join(", ");
 
                         format!("[ {} ]", arr)
                     } else {
@@ -162,6 +182,22 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
                         format!("Map({})", size)
                     }
                 }
+                ObjectData::Set(ref set) => {
+                    if set.is_empty() {
+                        return String::from("Set(0)");
+                    }
+
+                    if print_children {
+                        let values = set
+                            .iter()
+                            .map(|value| log_string_from(value, print_internals, false))
+                            .collect::<Vec<String>>()
+                            .join(", ");
+                        format!("Set {{ {} }}", values)
+                    } else {
+                        format!("Set({})", set.len())
+                    }
+                }
                 _ => display_obj(&x, print_internals),
             }
         }
@@ -170,6 +206,15 @@ pub(crate) fn log_string_from(x: &Value, print_internals: bool, print_children:
     }
 }
 
+/// Displays a run of `count` missing array elements.
+fn display_holes(count: i32) -> String {
+    if count == 1 {
+        String::from("<1 empty item>")
+    } else {
+        format!("<{} empty items>", count)
+    }
+}
+
 /// A helper function for specifically printing object values
 pub(crate) fn display_obj(v: &Value, print_internals: bool) -> String {
     // A simple helper for getting the address of a value
@@ -23,16 +23,24 @@ struct RationalHashable(f64);
 impl PartialEq for RationalHashable {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Number::same_value(self.0, other.0)
+        Number::same_value_zero(self.0, other.0)
     }
 }
 
 impl Eq for RationalHashable {}
 
 impl Hash for RationalHashable {
+    /// Numbers that are equal under SameValueZero hash the same, so `+0` and `-0` share a hash,
+    /// as do all `NaN` values.
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        if self.0 == 0.0 {
+            0f64.to_bits().hash(state);
+        } else if self.0.is_nan() {
+            f64::NAN.to_bits().hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
     }
 }
 
@@ -43,7 +51,7 @@ impl Hash for Value {
             Self::Null => NullHashable.hash(state),
             Self::String(ref string) => string.hash(state),
             Self::Boolean(boolean) => boolean.hash(state),
-            Self::Integer(integer) => integer.hash(state),
+            Self::Integer(integer) => RationalHashable(f64::from(*integer)).hash(state),
             Self::BigInt(ref bigint) => bigint.hash(state),
             Self::Rational(rational) => RationalHashable(*rational).hash(state),
             Self::Symbol(ref symbol) => Hash::hash(symbol, state),
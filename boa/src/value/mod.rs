@@ -8,10 +8,11 @@ mod tests;
 use crate::{
     builtins::{
         number::{f64_to_int32, f64_to_uint32},
-        BigInt, Number,
+        typed_array::{canonical_numeric_index, TypedArray},
+        BigInt, Number, Proxy,
     },
     object::{GcObject, Object, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    property::{Attribute, DataDescriptor, PartialDescriptor, PropertyDescriptor, PropertyKey},
     BoaProfiler, Context, Result,
 };
 use gc::{Finalize, Trace};
@@ -500,6 +501,80 @@ impl Value {
         value
     }
 
+    /// Resolve the field in the value and get its value, calling the getter if the field is an
+    /// accessor property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver
+    pub fn get_field_with_accessors<K>(&self, key: K, context: &mut Context) -> Result<Self>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+        if let Self::Object(ref object) = self {
+            if object.is_proxy() {
+                return Proxy::get(object, key, self, context);
+            }
+            if object.is_typed_array() {
+                if let Some(index) = canonical_numeric_index(&key) {
+                    return Ok(TypedArray::get_element(object, index));
+                }
+            }
+        }
+
+        match self.get_property(key.clone()) {
+            Some(PropertyDescriptor::Accessor(ref desc)) => match desc.getter() {
+                Some(getter) => getter.call(self, &[], context),
+                None => Ok(Value::undefined()),
+            },
+            _ => Ok(self.get_field(key)),
+        }
+    }
+
+    /// Set the field in the value, calling the setter if the field is an accessor property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub fn set_field_with_accessors<K, V>(
+        &self,
+        key: K,
+        value: V,
+        context: &mut Context,
+    ) -> Result<Value>
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        let key = key.into();
+        let value = value.into();
+        if let Self::Object(ref object) = self {
+            if object.is_proxy() {
+                Proxy::set(object, key, value.clone(), self, context)?;
+                return Ok(value);
+            }
+            if object.is_typed_array() {
+                if let Some(index) = canonical_numeric_index(&key) {
+                    TypedArray::set_element(object, index, &value, context)?;
+                    return Ok(value);
+                }
+            }
+        }
+
+        match self.get_property(key.clone()) {
+            Some(PropertyDescriptor::Accessor(ref desc)) => {
+                if let Some(setter) = desc.setter() {
+                    setter.call(self, &[value.clone()], context)?;
+                }
+                Ok(value)
+            }
+            _ => Ok(self.set_field(key, value)),
+        }
+    }
+
     /// Set the kind of an object.
     #[inline]
     pub fn set_data(&self, data: ObjectData) {
@@ -656,7 +731,11 @@ impl Value {
                     ObjectData::String(string.clone()),
                 ));
                 // Make sure the correct length is set on our new string object
-                object.set("length".into(), string.chars().count().into());
+                object.insert_property(
+                    "length",
+                    string.encode_utf16().count(),
+                    Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+                );
                 Ok(object)
             }
             Value::Symbol(ref symbol) => {
@@ -856,7 +935,7 @@ impl Value {
     }
 
     #[inline]
-    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
+    pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PartialDescriptor> {
         if let Self::Object(ref object) = self {
             object.to_property_descriptor(context)
         } else {
@@ -419,6 +419,19 @@ fn display_array_string() {
     assert_eq!(value.display().to_string(), "[ \"Hello\" ]");
 }
 
+#[test]
+fn display_array_holes() {
+    let mut context = Context::new();
+
+    let value = forward_val(&mut context, "[1, , 3]").unwrap();
+    assert_eq!(value.display().to_string(), "[ 1, <1 empty item>, 3 ]");
+    let value = forward_val(&mut context, "[, , 3, , ]").unwrap();
+    assert_eq!(
+        value.display().to_string(),
+        "[ <2 empty items>, 3, <1 empty item> ]"
+    );
+}
+
 #[test]
 fn display_boolean_object() {
     let mut context = Context::new();
@@ -441,6 +454,23 @@ fn display_number_object() {
     assert_eq!(value.display().to_string(), "Number { 3.14 }")
 }
 
+#[test]
+fn display_accessor_properties() {
+    let mut context = Context::new();
+    let d_obj = r#"
+        let o = {};
+        Object.defineProperty(o, "both", { get() {}, set(v) {}, enumerable: true });
+        Object.defineProperty(o, "get", { get() {}, enumerable: true });
+        Object.defineProperty(o, "set", { set(v) {}, enumerable: true });
+        o
+    "#;
+    let value = forward_val(&mut context, d_obj).unwrap();
+    let display = value.display().to_string();
+    assert!(display.contains("both: [Getter/Setter]"));
+    assert!(display.contains("get: [Getter]"));
+    assert!(display.contains("set: [Setter]"));
+}
+
 #[test]
 fn display_negative_zero_object() {
     let mut context = Context::new();